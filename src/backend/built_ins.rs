@@ -1,6 +1,71 @@
-use std::collections::HashMap;
-use std::path::Path;
-use crate::backend::interpreter::DataType;
+use std::collections::{HashMap, HashSet};
+use std::path::{Path, PathBuf};
+use std::fs::{File, OpenOptions};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::io::{BufRead, BufReader, Write};
+use crate::backend::interpreter::{DataType, CallFrame};
+use crate::backend::json;
+use crate::backend::json::JsonValue;
+use crate::frontend::lexer;
+use crate::frontend::lexer::TokenKind;
+use crate::common::pakhi_error::PakhiErr;
+
+// Largest whole number f64 can store without losing precision, 2^53
+const MAX_EXACT_INTEGER: f64 = 9007199254740992.0;
+
+// Counts paths returned by _টেম্প-ফাইল, makes paths unique inside same process
+static TEMP_FILE_COUNTER: AtomicUsize = AtomicUsize::new(0);
+
+// Pseudo random number generator used by _র‍্যান্ডম and other random built-ins, xorshift64*.
+// Not suitable for anything security related
+pub(crate) struct Prng {
+    state: u64,
+}
+
+impl Prng {
+    pub(crate) fn new(seed: u64) -> Self {
+        // seed is mixed with splitmix64 so that close seeds don't produce similar sequences,
+        // state must never be zero otherwise xorshift always returns zero
+        let mut z = seed.wrapping_add(0x9E3779B97F4A7C15);
+        z = (z ^ (z >> 30)).wrapping_mul(0xBF58476D1CE4E5B9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94D049BB133111EB);
+        z ^= z >> 31;
+        Prng { state: if z == 0 { 0x9E3779B97F4A7C15 } else { z } }
+    }
+
+    // Seeds generator from current time and process id, used when program doesn't give a seed
+    pub(crate) fn from_time() -> Self {
+        let nanos = std::time::SystemTime::now().duration_since(std::time::UNIX_EPOCH)
+            .map(|d| d.as_nanos() as u64).unwrap_or(0);
+        Prng::new(nanos ^ ((std::process::id() as u64) << 32))
+    }
+
+    fn next_u64(&mut self) -> u64 {
+        let mut x = self.state;
+        x ^= x >> 12;
+        x ^= x << 25;
+        x ^= x >> 27;
+        self.state = x;
+        x.wrapping_mul(0x2545F4914F6CDD1D)
+    }
+
+    // Returns number from ০ up to but not including ১
+    pub(crate) fn next_f64(&mut self) -> f64 {
+        // top 53 bits fit exactly in f64 mantissa
+        (self.next_u64() >> 11) as f64 / (1u64 << 53) as f64
+    }
+
+    // Returns whole number from ০ up to but not including n
+    pub(crate) fn next_below(&mut self, n: usize) -> usize {
+        (self.next_u64() % n as u64) as usize
+    }
+}
+
+// File opened by _ফাইল-খোলো, reading is buffered so that large files can be read line by line
+pub(crate) enum FileHandle {
+    Reader(BufReader<File>),
+    Writer(File),
+}
 
 // Contains all built-in function and constant names
 pub struct BuiltInFunctionList {
@@ -13,7 +78,33 @@ impl BuiltInFunctionList {
         // this functions are built-in
         let function_list = vec!["_স্ট্রিং", "_সংখ্যা", "_লিস্ট-পুশ", "_লিস্ট-পপ", "_লিস্ট-লেন", "_রিড-লাইন", "_এরর",
                                  "_স্ট্রিং-স্প্লিট", "_স্ট্রিং-জয়েন", "_টাইপ", "_রিড-ফাইল", "_রাইট-ফাইল", "_ডিলিট-ফাইল",
-                                 "_নতুন-ডাইরেক্টরি", "_রিড-ডাইরেক্টরি", "_ডিলিট-ডাইরেক্টরি", "_ফাইল-নাকি-ডাইরেক্টরি"];
+                                 "_নতুন-ডাইরেক্টরি", "_রিড-ডাইরেক্টরি", "_রিড-ডাইরেক্টরি-বিস্তারিত", "_ডিলিট-ডাইরেক্টরি",
+                                 "_ডিলিট-ডাইরেক্টরি-খালি", "_ফাইল-নাকি-ডাইরেক্টরি",
+                                 "_জেসন-পার্স", "_জেসন-পার্স-নিরাপদ", "_মেমোরি-তথ্য", "_প্রিন্ট-সারণি",
+                                 "_স্ট্যাক-ট্রেস", "_ফাইল-খোলো", "_ফাইল-লাইন-পড়ো", "_ফাইল-লেখো", "_ফাইল-বন্ধ",
+                                 "_স্ট্রিং-ফরম্যাট-সংখ্যা", "_লিস্ট-পজিশন-ম্যাক্স", "_লিস্ট-পজিশন-মিন",
+                                 "_গণনা", "_লিস্ট-গ্রুপ", "_ইন্টারপোলেট",
+                                 "_লিস্ট-সব", "_লিস্ট-কোনো", "_মেমোআইজ",
+                                 "_লিস্ট-সাজাও-কী", "_ট্রাই-সংখ্যা", "_লিস্ট-পাও", "_রেকর্ড-পাও", "_রেকর্ড-আকার",
+                                 "_স্ট্রিং-উল্টা", "_স্ট্রিং-গণনা", "_স্ট্রিং-স্প্লিট-লাইন",
+                                 "_ফ্রিজ", "_ট্রাই-ইনডেক্স", "_রিড-লাইন-সব",
+                                 "_লিস্ট-প্রিঅ্যালোকেট", "_ইনস্পেক্ট",
+                                 "_লিস্ট-ম্যাক্স-দিয়ে", "_লিস্ট-মিন-দিয়ে",
+                                 "_লিস্ট-ফ্ল্যাট-ম্যাপ", "_টোকেনাইজ", "_ইভাল",
+                                 "_লিস্ট-পার্টিশন", "_স্ট্রিং-টেমপ্লেট",
+                                 "_লিস্ট-টেক", "_লিস্ট-ড্রপ", "_রিড-ফাইল-বাইট", "_রাইট-ফাইল-বাইট",
+                                 "_সিস্টেম-তথ্য", "_রেকর্ড-কী", "_সময়-পার্স", "_সময়-স্ট্রিং",
+                                 "_রেকর্ড-ভ্যালু", "_রেকর্ড-আছে", "_প্রসেস-আইডি",
+                                 "_হেক্স", "_হেক্স-ডিকোড", "_বাইনারি", "_বাইনারি-সংখ্যা", "_অক্টাল",
+                                 "_অক্টাল-সংখ্যা", "_স্ট্রিং-লেন", "_স্ট্রিং-সাব",
+                                 "_টার্মিনাল-রঙ", "_ক্লিয়ার-স্ক্রিন", "_কার্সর-অবস্থান",
+                                 "_লিস্ট-স্ক্যান", "_ওয়াক-ডাইরেক্টরি", "_টেম্প-ফাইল",
+                                 "_ডাইরেক্টরি-বদল", "_মড", "_ফ্লোর", "_সিল", "_রাউন্ড",
+                                 "_স্কয়ার-রুট", "_পাওয়ার", "_র‍্যান্ডম", "_রান্ডম-শাফল",
+                                 "_অ্যাবস", "_ম্যাক্স", "_মিন", "_র‍্যান্ডম-নির্বাচন", "_সিড",
+                                 "_ইউআরএল-কোয়েরি", "_লিস্ট-সমান-ভাগ",
+                                 "_ডিফল্ট", "_রেকর্ড-আপডেট",
+                                 "_বৈধ-ইউটিএফ৮", "_জিপ-দিয়ে"];
         for f_name in function_list {
             functions_map.insert(f_name.chars().collect(), f_name.to_string());
         }
@@ -34,13 +125,16 @@ impl BuiltInFunctionList {
     }
 
     // Converts DataType::Num to DataType::String
-    pub(crate) fn _to_string(arguments: Vec<DataType>) -> Result<DataType, String> {
+    pub(crate) fn _to_string(arguments: Vec<DataType>, ascii_digits: bool) -> Result<DataType, String> {
         if arguments.len() == 1 {
             let number = arguments[0].clone();
 
             if let DataType::Num(n) = number {
+                if ascii_digits {
+                    return Ok(DataType::String(n.to_string().into()));
+                }
                 let bn_num_string = BuiltInFunctionList::replace_en_with_bn_digit(n.to_string());
-                return Ok(DataType::String(bn_num_string));
+                Ok(DataType::String(bn_num_string.into()))
             } else {
                 return Err("Datatype must be Number for converting to string".to_string());
             }
@@ -48,13 +142,153 @@ impl BuiltInFunctionList {
         } else { return Err("Function requires one arguments".to_string()); }
     }
 
+    // Converts integer part of a number to string with grouped digits. By default digits are grouped
+    // in lakh-crore style (১০,০০,০০০), optional second argument sets fixed group size
+    // Wraps text with ANSI escape code of color and reset code. When no_color is true
+    // (NO_COLOR environment variable is set) text is returned as it is
+    pub(crate) fn _terminal_color(arguments: Vec<DataType>, no_color: bool) -> Result<DataType, String> {
+        if arguments.len() == 2 {
+            match (&arguments[0], &arguments[1]) {
+                (DataType::String(text), DataType::String(color)) => {
+                    let code = match &**color {
+                        "কালো" => 30,
+                        "লাল" => 31,
+                        "সবুজ" => 32,
+                        "হলুদ" => 33,
+                        "নীল" => 34,
+                        "বেগুনি" => 35,
+                        "আকাশি" => 36,
+                        "সাদা" => 37,
+                        _ => return Err(format!("_টার্মিনাল-রঙ() unknown color {}, supported colors are কালো, লাল, সবুজ, হলুদ, নীল, বেগুনি, আকাশি, সাদা", color)),
+                    };
+                    if no_color {
+                        return Ok(DataType::String(text.clone()));
+                    }
+                    Ok(DataType::String(format!("\x1b[{}m{}\x1b[0m", code, text).into()))
+                },
+                _ => Err("_টার্মিনাল-রঙ() function's both argument must be of type string".to_string()),
+            }
+        } else { Err("_টার্মিনাল-রঙ() function expects two argument".to_string())}
+    }
+
+    // Returns number of unicode scalar values in string, not bytes
+    pub(crate) fn _string_len(arguments: Vec<DataType>) -> Result<DataType, String> {
+        if arguments.len() == 1 {
+            match &arguments[0] {
+                DataType::String(s) => Ok(DataType::Num(s.chars().count() as f64)),
+                _ => Err("_স্ট্রিং-লেন() function's argument must be string".to_string()),
+            }
+        } else { Err("_স্ট্রিং-লেন() function expects one argument".to_string())}
+    }
+
+    // Returns length characters starting from character at start, indexes count unicode scalar values
+    pub(crate) fn _string_sub(arguments: Vec<DataType>) -> Result<DataType, String> {
+        if arguments.len() == 3 {
+            match (&arguments[0], &arguments[1], &arguments[2]) {
+                (DataType::String(s), DataType::Num(start), DataType::Num(length)) => {
+                    if *start < 0.0 || *length < 0.0 || start.fract() != 0.0 || length.fract() != 0.0 {
+                        return Err("_স্ট্রিং-সাব() start and length must be non negative whole number".to_string());
+                    }
+                    let chars: Vec<char> = s.chars().collect();
+                    // compared before casting, huge numbers would saturate and overflow as usize
+                    if start + length > chars.len() as f64 {
+                        return Err(format!("_স্ট্রিং-সাব() start + length {} is larger than string length {}",
+                                           BuiltInFunctionList::replace_en_with_bn_digit((start + length).to_string()),
+                                           BuiltInFunctionList::replace_en_with_bn_digit(chars.len().to_string())));
+                    }
+                    let (start, length) = (*start as usize, *length as usize);
+                    Ok(DataType::String(chars[start..start + length].iter().collect::<String>().into()))
+                },
+                _ => Err("_স্ট্রিং-সাব() function's arguments must be string, number and number".to_string()),
+            }
+        } else { Err("_স্ট্রিং-সাব() function expects three argument".to_string())}
+    }
+
+    // Reverses string by unicode scalar values, so multi-byte bangla characters stay valid
+    pub(crate) fn _string_reverse(arguments: Vec<DataType>) -> Result<DataType, String> {
+        if arguments.len() == 1 {
+            match &arguments[0] {
+                DataType::String(s) => Ok(DataType::String(s.chars().rev().collect::<String>().into())),
+                _ => Err("_স্ট্রিং-উল্টা() function's argument must be string".to_string()),
+            }
+        } else { Err("_স্ট্রিং-উল্টা() function expects one argument".to_string())}
+    }
+
+    // Counts non-overlapping occurrences of needle in string
+    pub(crate) fn _string_count(arguments: Vec<DataType>) -> Result<DataType, String> {
+        if arguments.len() == 2 {
+            match (&arguments[0], &arguments[1]) {
+                (DataType::String(_), DataType::String(needle)) if needle.is_empty() => {
+                    Err("_স্ট্রিং-গণনা() function's second argument can't be empty string".to_string())
+                },
+                (DataType::String(s), DataType::String(needle)) => {
+                    Ok(DataType::Num(s.matches(&**needle).count() as f64))
+                },
+                _ => Err("_স্ট্রিং-গণনা() function's both arguments must be string".to_string()),
+            }
+        } else { Err("_স্ট্রিং-গণনা() function expects two argument".to_string())}
+    }
+
+    // Splits string into lines, both "\n" and "\r\n" line endings are supported
+    pub(crate) fn _string_split_lines(arguments: Vec<DataType>) -> Result<Vec<DataType>, String> {
+        if arguments.len() == 1 {
+            match &arguments[0] {
+                DataType::String(s) => {
+                    let lines = s.split('\n')
+                        .map(|line| DataType::String(line.strip_suffix('\r').unwrap_or(line).into()))
+                        .collect();
+                    Ok(lines)
+                },
+                _ => Err("_স্ট্রিং-স্প্লিট-লাইন() function's argument must be string".to_string()),
+            }
+        } else { Err("_স্ট্রিং-স্প্লিট-লাইন() function expects one argument".to_string())}
+    }
+
+    pub(crate) fn _format_num(arguments: Vec<DataType>) -> Result<DataType, String> {
+        if arguments.len() == 1 || arguments.len() == 2 {
+            let n = match arguments[0] {
+                DataType::Num(n) => n,
+                _ => return Err("_স্ট্রিং-ফরম্যাট-সংখ্যা() function's first argument must be number".to_string()),
+            };
+            let group_size = match arguments.get(1) {
+                None => None,
+                Some(DataType::Num(size)) if *size >= 1.0 => Some(*size as usize),
+                Some(_) => return Err("_স্ট্রিং-ফরম্যাট-সংখ্যা() group size must be a number greater than zero".to_string()),
+            };
+
+            let digits: Vec<char> = (n.abs().trunc() as u64).to_string().chars().collect();
+            // grouping digits from right side
+            let mut groups: Vec<String> = Vec::new();
+            let mut end = digits.len();
+            while end > 0 {
+                let size = match group_size {
+                    Some(size) => size,
+                    // first group from right has three digits, rest have two
+                    None => if groups.is_empty() { 3 } else { 2 },
+                };
+                let start = end.saturating_sub(size);
+                groups.push(digits[start..end].iter().collect());
+                end = start;
+            }
+            groups.reverse();
+
+            let mut formatted = BuiltInFunctionList::replace_en_with_bn_digit(groups.join(","));
+            if n <= -1.0 {
+                formatted.insert(0, '-');
+            }
+            Ok(DataType::String(formatted.into()))
+        } else {
+            Err("_স্ট্রিং-ফরম্যাট-সংখ্যা() function expects one or two argument".to_string())
+        }
+    }
+
     // Converts DataType::String to DataType::Num
     pub(crate) fn _to_num(arguments: Vec<DataType>) -> Result<DataType, String> {
         if arguments.len() == 1 {
             let string = arguments[0].clone();
 
             if let DataType::String(bangla_num_string) = string {
-                let eng_num_string = BuiltInFunctionList::replace_bn_with_en_digit(bangla_num_string);
+                let eng_num_string = BuiltInFunctionList::replace_bn_with_en_digit(bangla_num_string.to_string());
                 let convert_result = eng_num_string.parse::<f64>();
                 match convert_result {
                     Ok(n) => return Ok(DataType::Num(n)),
@@ -67,6 +301,287 @@ impl BuiltInFunctionList {
         } else { return Err(format!("Function requires one arguments")); }
     }
 
+    // Same as _সংখ্যা but invalid number string isn't an error, returns record with keys
+    // "সফল" and "মান", "মান" is _শূন্য when conversion failed
+    pub(crate) fn _try_num(arguments: Vec<DataType>) -> Result<HashMap<String, DataType>, String> {
+        if arguments.len() == 1 {
+            if let DataType::String(_) = arguments[0] {
+                let mut result: HashMap<String, DataType> = HashMap::new();
+                match BuiltInFunctionList::_to_num(arguments) {
+                    Ok(num) => {
+                        result.insert("সফল".to_string(), DataType::Bool(true));
+                        result.insert("মান".to_string(), num);
+                    },
+                    Err(_) => {
+                        result.insert("সফল".to_string(), DataType::Bool(false));
+                        result.insert("মান".to_string(), DataType::Nil);
+                    },
+                }
+                Ok(result)
+            } else {
+                Err("_ট্রাই-সংখ্যা() function's argument must be string".to_string())
+            }
+        } else { Err("_ট্রাই-সংখ্যা() function expects one argument".to_string())}
+    }
+
+    // Tokenizes pakhi source with the same lexer interpreter uses, every token is described by
+    // record with keys "ধরন" (token kind name), "লেক্সিম" and "লাইন"
+    pub(crate) fn _tokenize(arguments: Vec<DataType>) -> Result<Vec<HashMap<String, DataType>>, String> {
+        if arguments.len() == 1 {
+            if let DataType::String(src) = &arguments[0] {
+                let tokens = match lexer::tokenize(src.chars().collect(), String::new()) {
+                    Ok(tokens) => tokens,
+                    Err(PakhiErr::SyntaxError(line, _, err)) | Err(PakhiErr::TypeError(line, _, err))
+                    | Err(PakhiErr::RuntimeError(line, _, err)) => {
+                        return Err(format!("_টোকেনাইজ() couldn't tokenize source at line {}: {}", line, err));
+                    },
+                    Err(PakhiErr::UnexpectedError(err)) => {
+                        return Err(format!("_টোকেনাইজ() couldn't tokenize source: {}", err));
+                    },
+                };
+
+                let mut described_tokens: Vec<HashMap<String, DataType>> = Vec::new();
+                for token in tokens {
+                    // end of token marker is only needed by parser
+                    let kind = match token.kind {
+                        TokenKind::EOT => continue,
+                        TokenKind::Num(_) => "Num".to_string(),
+                        TokenKind::String(_) => "String".to_string(),
+                        TokenKind::Bool(_) => "Bool".to_string(),
+                        other => format!("{:?}", other),
+                    };
+                    let mut described: HashMap<String, DataType> = HashMap::new();
+                    described.insert("ধরন".to_string(), DataType::String(kind.into()));
+                    described.insert("লেক্সিম".to_string(), DataType::String(token.lexeme.iter().collect::<String>().into()));
+                    described.insert("লাইন".to_string(), DataType::Num(token.line as f64));
+                    described_tokens.push(described);
+                }
+                Ok(described_tokens)
+            } else {
+                Err("_টোকেনাইজ() function's argument must be string".to_string())
+            }
+        } else { Err("_টোকেনাইজ() function expects one argument".to_string())}
+    }
+
+    // Parses ISO-8601 date-time "YYYY-MM-DDTHH:MM:SSZ" or with offset like "+06:00" instead of Z,
+    // returns unix timestamp in seconds. Both bangla and english digits are accepted
+    pub(crate) fn _time_parse(arguments: Vec<DataType>) -> Result<DataType, String> {
+        if arguments.len() == 1 {
+            match &arguments[0] {
+                DataType::String(date_time) => {
+                    let date_time = BuiltInFunctionList::replace_bn_with_en_digit(date_time.to_string());
+                    match BuiltInFunctionList::parse_iso_date_time(&date_time) {
+                        Some(timestamp) => Ok(DataType::Num(timestamp as f64)),
+                        None => Err(format!("_সময়-পার্স() invalid date-time {}, expected format YYYY-MM-DDTHH:MM:SSZ", date_time)),
+                    }
+                },
+                _ => Err("_সময়-পার্স() function's argument must be string".to_string()),
+            }
+        } else { Err("_সময়-পার্স() function expects one argument".to_string())}
+    }
+
+    // Converts unix timestamp in seconds to ISO-8601 date-time string in UTC, "YYYY-MM-DDTHH:MM:SSZ"
+    pub(crate) fn _time_to_string(arguments: Vec<DataType>) -> Result<DataType, String> {
+        if arguments.len() == 1 {
+            match &arguments[0] {
+                DataType::Num(timestamp) if timestamp.fract() == 0.0 && timestamp.abs() < 1e15 => {
+                    let timestamp = *timestamp as i64;
+                    let days = timestamp.div_euclid(86400);
+                    let seconds_of_day = timestamp.rem_euclid(86400);
+                    let (year, month, day) = BuiltInFunctionList::civil_from_days(days);
+                    Ok(DataType::String(format!("{:04}-{:02}-{:02}T{:02}:{:02}:{:02}Z", year, month, day,
+                                                       seconds_of_day / 3600, seconds_of_day % 3600 / 60,
+                                                       seconds_of_day % 60).into()))
+                },
+                _ => Err("_সময়-স্ট্রিং() function's argument must be whole number timestamp".to_string()),
+            }
+        } else { Err("_সময়-স্ট্রিং() function expects one argument".to_string())}
+    }
+
+    // Mathematical modulo, result always has sign of divisor. % operator keeps sign of dividend,
+    // so -৭ % ৩ is -১ but _মড(-৭, ৩) is ২
+    pub(crate) fn _modulo(arguments: Vec<DataType>) -> Result<DataType, String> {
+        match arguments.as_slice() {
+            [DataType::Num(_), DataType::Num(b)] if *b == 0.0 => Err("_মড() division by zero".to_string()),
+            [DataType::Num(a), DataType::Num(b)] => Ok(DataType::Num(((a % b) + b) % b)),
+            [_, _] => Err("_মড() function's arguments must be number".to_string()),
+            _ => Err("_মড() function expects two argument".to_string()),
+        }
+    }
+
+    pub(crate) fn _floor(arguments: Vec<DataType>) -> Result<DataType, String> {
+        BuiltInFunctionList::round_num(arguments, f64::floor, "_ফ্লোর")
+    }
+
+    pub(crate) fn _ceil(arguments: Vec<DataType>) -> Result<DataType, String> {
+        BuiltInFunctionList::round_num(arguments, f64::ceil, "_সিল")
+    }
+
+    // Half way values are rounded away from zero, so _রাউন্ড(-২.৫) is -৩
+    pub(crate) fn _round(arguments: Vec<DataType>) -> Result<DataType, String> {
+        BuiltInFunctionList::round_num(arguments, f64::round, "_রাউন্ড")
+    }
+
+    fn round_num(arguments: Vec<DataType>, round: fn(f64) -> f64, func_name: &str) -> Result<DataType, String> {
+        match arguments.as_slice() {
+            [DataType::Num(n)] => Ok(DataType::Num(round(*n))),
+            [_] => Err(format!("{}() function's argument must be number", func_name)),
+            _ => Err(format!("{}() function expects one argument", func_name)),
+        }
+    }
+
+    pub(crate) fn _square_root(arguments: Vec<DataType>) -> Result<DataType, String> {
+        match arguments.as_slice() {
+            [DataType::Num(n)] if *n < 0.0 => Err("_স্কয়ার-রুট() can't find square root of negative number".to_string()),
+            [DataType::Num(n)] => Ok(DataType::Num(n.sqrt())),
+            [_] => Err("_স্কয়ার-রুট() function's argument must be number".to_string()),
+            _ => Err("_স্কয়ার-রুট() function expects one argument".to_string()),
+        }
+    }
+
+    pub(crate) fn _power(arguments: Vec<DataType>) -> Result<DataType, String> {
+        match arguments.as_slice() {
+            [DataType::Num(base), DataType::Num(exp)] => Ok(DataType::Num(base.powf(*exp))),
+            [_, _] => Err("_পাওয়ার() function's arguments must be number".to_string()),
+            _ => Err("_পাওয়ার() function expects two argument".to_string()),
+        }
+    }
+
+    // Converts whole number to lowercase hexadecimal string, negative number gets '-' prefix
+    pub(crate) fn _hex(arguments: Vec<DataType>) -> Result<DataType, String> {
+        BuiltInFunctionList::to_radix_string(arguments, 16, "_হেক্স")
+    }
+
+    // Parses hexadecimal string made by _হেক্স, both lowercase and uppercase letters are accepted
+    pub(crate) fn _hex_decode(arguments: Vec<DataType>) -> Result<DataType, String> {
+        BuiltInFunctionList::from_radix_string(arguments, 16, "_হেক্স-ডিকোড")
+    }
+
+    pub(crate) fn _binary(arguments: Vec<DataType>) -> Result<DataType, String> {
+        BuiltInFunctionList::to_radix_string(arguments, 2, "_বাইনারি")
+    }
+
+    pub(crate) fn _binary_decode(arguments: Vec<DataType>) -> Result<DataType, String> {
+        BuiltInFunctionList::from_radix_string(arguments, 2, "_বাইনারি-সংখ্যা")
+    }
+
+    pub(crate) fn _octal(arguments: Vec<DataType>) -> Result<DataType, String> {
+        BuiltInFunctionList::to_radix_string(arguments, 8, "_অক্টাল")
+    }
+
+    pub(crate) fn _octal_decode(arguments: Vec<DataType>) -> Result<DataType, String> {
+        BuiltInFunctionList::from_radix_string(arguments, 8, "_অক্টাল-সংখ্যা")
+    }
+
+    // Only numbers which f64 can store exactly are allowed
+    fn to_radix_string(arguments: Vec<DataType>, radix: u32, func_name: &str) -> Result<DataType, String> {
+        if arguments.len() == 1 {
+            match &arguments[0] {
+                DataType::Num(n) if n.fract() == 0.0 && n.abs() <= MAX_EXACT_INTEGER => {
+                    let sign = if *n < 0.0 { "-" } else { "" };
+                    let digits = match radix {
+                        2 => format!("{:b}", n.abs() as u64),
+                        8 => format!("{:o}", n.abs() as u64),
+                        _ => format!("{:x}", n.abs() as u64),
+                    };
+                    Ok(DataType::String(format!("{}{}", sign, digits).into()))
+                },
+                _ => Err(format!("{}() function's argument must be whole number", func_name)),
+            }
+        } else { Err(format!("{}() function expects one argument", func_name))}
+    }
+
+    fn from_radix_string(arguments: Vec<DataType>, radix: u32, func_name: &str) -> Result<DataType, String> {
+        if arguments.len() == 1 {
+            match &arguments[0] {
+                DataType::String(number) => {
+                    let (sign, digits) = match number.strip_prefix('-') {
+                        Some(digits) => (-1.0, digits),
+                        None => (1.0, &**number),
+                    };
+                    // from_str_radix allows leading '+', number string shouldn't
+                    if digits.is_empty() || !digits.chars().all(|c| c.is_digit(radix)) {
+                        return Err(format!("{}() {} is not a valid number", func_name, number));
+                    }
+                    match u64::from_str_radix(digits, radix) {
+                        Ok(n) if n as f64 <= MAX_EXACT_INTEGER => Ok(DataType::Num(sign * n as f64)),
+                        _ => Err(format!("{}() {} is too large", func_name, number)),
+                    }
+                },
+                _ => Err(format!("{}() function's argument must be string", func_name)),
+            }
+        } else { Err(format!("{}() function expects one argument", func_name))}
+    }
+
+    fn parse_iso_date_time(date_time: &str) -> Option<i64> {
+        let chars: Vec<char> = date_time.chars().collect();
+        // date and time part is always 19 characters, rest is timezone
+        if chars.len() < 20 {
+            return None;
+        }
+        let number = |from: usize, to: usize| -> Option<i64> {
+            let part: String = chars[from..to].iter().collect();
+            if part.chars().all(|c| c.is_ascii_digit()) { part.parse().ok() } else { None }
+        };
+        let separators = [(4, '-'), (7, '-'), (10, 'T'), (13, ':'), (16, ':')];
+        if separators.iter().any(|(i, c)| chars[*i] != *c) {
+            return None;
+        }
+        let (year, month, day) = (number(0, 4)?, number(5, 7)?, number(8, 10)?);
+        let (hour, minute, second) = (number(11, 13)?, number(14, 16)?, number(17, 19)?);
+
+        let offset_seconds = match chars[19] {
+            'Z' if chars.len() == 20 => 0,
+            sign @ '+' | sign @ '-' if chars.len() == 25 && chars[22] == ':' => {
+                let (offset_hour, offset_minute) = (number(20, 22)?, number(23, 25)?);
+                if offset_hour > 23 || offset_minute > 59 {
+                    return None;
+                }
+                let offset = offset_hour * 3600 + offset_minute * 60;
+                if sign == '+' { offset } else { -offset }
+            },
+            _ => return None,
+        };
+
+        let days_in_month = match month {
+            1 | 3 | 5 | 7 | 8 | 10 | 12 => 31,
+            4 | 6 | 9 | 11 => 30,
+            2 if year % 4 == 0 && (year % 100 != 0 || year % 400 == 0) => 29,
+            2 => 28,
+            _ => return None,
+        };
+        if day < 1 || day > days_in_month || hour > 23 || minute > 59 || second > 59 {
+            return None;
+        }
+
+        let days = BuiltInFunctionList::days_from_civil(year, month, day);
+        Some(days * 86400 + hour * 3600 + minute * 60 + second - offset_seconds)
+    }
+
+    // Days since 1970-01-01 of a date in proleptic gregorian calendar
+    fn days_from_civil(year: i64, month: i64, day: i64) -> i64 {
+        let year = if month <= 2 { year - 1 } else { year };
+        let era = year.div_euclid(400);
+        let year_of_era = year - era * 400;
+        let day_of_year = (153 * ((month + 9) % 12) + 2) / 5 + day - 1;
+        let day_of_era = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+        era * 146097 + day_of_era - 719468
+    }
+
+    // Inverse of days_from_civil, returns (year, month, day)
+    fn civil_from_days(days: i64) -> (i64, i64, i64) {
+        let days = days + 719468;
+        let era = days.div_euclid(146097);
+        let day_of_era = days - era * 146097;
+        let year_of_era = (day_of_era - day_of_era / 1460 + day_of_era / 36524 - day_of_era / 146096) / 365;
+        let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+        let month_index = (5 * day_of_year + 2) / 153;
+        let day = day_of_year - (153 * month_index + 2) / 5 + 1;
+        let month = if month_index < 10 { month_index + 3 } else { month_index - 9 };
+        let year = year_of_era + era * 400 + if month <= 2 { 1 } else { 0 };
+        (year, month, day)
+    }
+
     fn replace_bn_with_en_digit(bn_num_string: String) -> String {
         let mut num_chars: Vec<char> = bn_num_string.chars().collect();
         for (i, c) in num_chars.clone().iter().enumerate() {
@@ -149,13 +664,15 @@ impl BuiltInFunctionList {
         return Ok(DataType::Nil);
     }
 
+    // Removes last element or element at given index and returns removed element,
+    // popping from empty list returns nil
     pub(crate) fn _list_pop(arguments: Vec<DataType>, lists: &mut Vec<Vec<DataType>>) -> Result<DataType, String> {
         if arguments.len() == 1 {
             let list = arguments[0].clone();
 
             if let DataType::List(index) = list {
                 let actual_list = lists.get_mut(index).unwrap();
-                actual_list.pop();
+                Ok(actual_list.pop().unwrap_or(DataType::Nil))
             } else { return Err(format!("Datatype must be array to push value")); }
 
         } else if arguments.len() == 2 {
@@ -166,15 +683,120 @@ impl BuiltInFunctionList {
                 let actual_list = lists.get_mut(index).unwrap();
 
                 if let DataType::Num(pop_at_i_f) = pop_at {
-                    let pop_at_i = pop_at_i_f as usize;
-                    actual_list.remove(pop_at_i);
-                }
+                    if pop_at_i_f < 0.0 || pop_at_i_f as usize >= actual_list.len() {
+                        return Err(format!("_লিস্ট-পপ() index {} out of range, list length is {}",
+                                           BuiltInFunctionList::replace_en_with_bn_digit(pop_at_i_f.to_string()),
+                                           BuiltInFunctionList::replace_en_with_bn_digit(actual_list.len().to_string())));
+                    }
+                    Ok(actual_list.remove(pop_at_i_f as usize))
+                } else { Err("_লিস্ট-পপ() index must be number".to_string())}
 
             } else { return Err(format!("Datatype must be array to push value")); }
 
         } else { return Err(format!("Function requires one argument")); }
+    }
 
-        return Ok(DataType::Nil);
+    // Returns index of largest number in list, first index is returned if largest number repeats
+    pub(crate) fn _list_position_max(arguments: Vec<DataType>, lists: &[Vec<DataType>]) -> Result<DataType, String> {
+        BuiltInFunctionList::list_extreme_position(arguments, lists, "_লিস্ট-পজিশন-ম্যাক্স", true)
+    }
+
+    // Returns index of smallest number in list, first index is returned if smallest number repeats
+    pub(crate) fn _list_position_min(arguments: Vec<DataType>, lists: &[Vec<DataType>]) -> Result<DataType, String> {
+        BuiltInFunctionList::list_extreme_position(arguments, lists, "_লিস্ট-পজিশন-মিন", false)
+    }
+
+    fn list_extreme_position(arguments: Vec<DataType>,
+                             lists: &[Vec<DataType>],
+                             function_name: &str,
+                             find_max: bool) -> Result<DataType, String> {
+        if arguments.len() == 1 {
+            let list = match arguments[0] {
+                DataType::List(index) => lists.get(index).unwrap(),
+                _ => return Err(format!("{}() function's argument must be list", function_name)),
+            };
+            if list.is_empty() {
+                return Err(format!("{}() list is empty", function_name));
+            }
+
+            let mut position: usize = 0;
+            let mut extreme = f64::NAN;
+            for (i, elem) in list.iter().enumerate() {
+                let n = match elem {
+                    DataType::Num(n) => *n,
+                    _ => return Err(format!("{}() list must only contain numbers", function_name)),
+                };
+                if i == 0 || (find_max && n > extreme) || (!find_max && n < extreme) {
+                    position = i;
+                    extreme = n;
+                }
+            }
+            Ok(DataType::Num(position as f64))
+        } else {
+            Err(format!("{}() function expects one argument", function_name))
+        }
+    }
+
+    pub(crate) fn _abs(arguments: Vec<DataType>) -> Result<DataType, String> {
+        match arguments.as_slice() {
+            [DataType::Num(n)] => Ok(DataType::Num(n.abs())),
+            [_] => Err("_অ্যাবস() function's argument must be number".to_string()),
+            _ => Err("_অ্যাবস() function expects one argument".to_string()),
+        }
+    }
+
+    // Accepts either two numbers or a list of numbers
+    pub(crate) fn _max(arguments: Vec<DataType>, lists: &[Vec<DataType>]) -> Result<DataType, String> {
+        BuiltInFunctionList::extreme_num(arguments, lists, "_ম্যাক্স", true)
+    }
+
+    // Accepts either two numbers or a list of numbers
+    pub(crate) fn _min(arguments: Vec<DataType>, lists: &[Vec<DataType>]) -> Result<DataType, String> {
+        BuiltInFunctionList::extreme_num(arguments, lists, "_মিন", false)
+    }
+
+    fn extreme_num(arguments: Vec<DataType>,
+                   lists: &[Vec<DataType>],
+                   function_name: &str,
+                   find_max: bool) -> Result<DataType, String> {
+        let nums: &[DataType] = match arguments.as_slice() {
+            [DataType::Num(_), DataType::Num(_)] => &arguments,
+            [DataType::List(index)] => &lists[*index],
+            [_, _] | [_] => return Err(format!("{}() function's arguments must be two number or one list", function_name)),
+            _ => return Err(format!("{}() function expects two number or one list", function_name)),
+        };
+
+        let mut extreme: Option<f64> = None;
+        for elem in nums {
+            let n = match elem {
+                DataType::Num(n) => *n,
+                _ => return Err(format!("{}() list must only contain numbers", function_name)),
+            };
+            extreme = match extreme {
+                Some(e) if (find_max && n <= e) || (!find_max && n >= e) => Some(e),
+                _ => Some(n),
+            };
+        }
+        match extreme {
+            Some(n) => Ok(DataType::Num(n)),
+            None => Err(format!("{}() list is empty", function_name)),
+        }
+    }
+
+    // Returns how many elements of list are equal to value
+    pub(crate) fn _count(arguments: Vec<DataType>, lists: &[Vec<DataType>]) -> Result<DataType, String> {
+        if arguments.len() == 2 {
+            match arguments[0] {
+                DataType::List(index) => {
+                    let list = lists.get(index).unwrap();
+                    let count = list.iter().filter(|elem| **elem == arguments[1]).count();
+                    Ok(DataType::Num(count as f64))
+                },
+                _ => Err("_গণনা() function's first argument must be list".to_string()),
+            }
+        } else {
+            Err("_গণনা() function expects two argument".to_string())
+        }
     }
 
     pub(crate) fn _list_len(arguments: Vec<DataType>, lists: &mut Vec<Vec<DataType>>) -> Result<DataType, String> {
@@ -190,6 +812,291 @@ impl BuiltInFunctionList {
         } else { return Err(format!("Function requires one argument")); }
     }
 
+    // Same seed always produces same sequence of random values
+    pub(crate) fn _seed(arguments: Vec<DataType>, prng: &mut Prng) -> Result<DataType, String> {
+        match arguments.as_slice() {
+            [DataType::Num(n)] => {
+                *prng = Prng::new(n.to_bits());
+                Ok(DataType::Nil)
+            },
+            [_] => Err("_সিড() function's argument must be number".to_string()),
+            _ => Err("_সিড() function expects one argument".to_string()),
+        }
+    }
+
+    pub(crate) fn _random(arguments: Vec<DataType>, prng: &mut Prng) -> Result<DataType, String> {
+        if arguments.is_empty() {
+            Ok(DataType::Num(prng.next_f64()))
+        } else {
+            Err("_র‍্যান্ডম() function doesn't take any argument".to_string())
+        }
+    }
+
+    // Fisher-Yates shuffle, list is changed in place
+    pub(crate) fn _list_shuffle(arguments: Vec<DataType>, lists: &mut [Vec<DataType>], prng: &mut Prng) -> Result<DataType, String> {
+        match arguments.as_slice() {
+            [DataType::List(list_index)] => {
+                let list = &mut lists[*list_index];
+                for i in (1..list.len()).rev() {
+                    let j = prng.next_below(i + 1);
+                    list.swap(i, j);
+                }
+                Ok(DataType::Nil)
+            },
+            [_] => Err("_রান্ডম-শাফল() function's argument must be list".to_string()),
+            _ => Err("_রান্ডম-শাফল() function expects one argument".to_string()),
+        }
+    }
+
+    pub(crate) fn _random_choice(arguments: Vec<DataType>, lists: &[Vec<DataType>], prng: &mut Prng) -> Result<DataType, String> {
+        match arguments.as_slice() {
+            [DataType::List(list_index)] => {
+                let list = &lists[*list_index];
+                if list.is_empty() {
+                    return Err("_র‍্যান্ডম-নির্বাচন() list is empty".to_string());
+                }
+                Ok(list[prng.next_below(list.len())].clone())
+            },
+            [_] => Err("_র‍্যান্ডম-নির্বাচন() function's argument must be list".to_string()),
+            _ => Err("_র‍্যান্ডম-নির্বাচন() function expects one argument".to_string()),
+        }
+    }
+
+    // Returns first n elements of list when take is true, otherwise elements after first n.
+    // n larger than list length is clamped to length
+    pub(crate) fn _list_take_or_drop(arguments: Vec<DataType>, lists: &[Vec<DataType>], take: bool) -> Result<Vec<DataType>, String> {
+        let func_name = if take { "_লিস্ট-টেক" } else { "_লিস্ট-ড্রপ" };
+        if arguments.len() == 2 {
+            match (&arguments[0], &arguments[1]) {
+                (DataType::List(list_index), DataType::Num(n)) => {
+                    if *n < 0.0 || n.fract() != 0.0 {
+                        return Err(format!("{}() function's second argument must be non negative whole number", func_name));
+                    }
+                    let list = &lists[*list_index];
+                    let split_at = (*n as usize).min(list.len());
+                    if take {
+                        Ok(list[..split_at].to_vec())
+                    } else {
+                        Ok(list[split_at..].to_vec())
+                    }
+                },
+                _ => Err(format!("{}() function's arguments must be list and number", func_name)),
+            }
+        } else { Err(format!("{}() function expects two argument", func_name))}
+    }
+
+    // Splits list into n parts preserving order, first len % n parts get one extra element.
+    // When n is larger than list length last parts are empty, allocation for a huge n is
+    // reported as error instead of aborting
+    pub(crate) fn _list_split_even(arguments: Vec<DataType>, lists: &[Vec<DataType>]) -> Result<Vec<Vec<DataType>>, String> {
+        match arguments.as_slice() {
+            [DataType::List(list_index), DataType::Num(n)] => {
+                if *n < 1.0 || n.fract() != 0.0 {
+                    return Err("_লিস্ট-সমান-ভাগ() function's second argument must be positive whole number".to_string());
+                }
+                let list = &lists[*list_index];
+                let parts = *n as usize;
+                let (size, extra) = (list.len() / parts, list.len() % parts);
+                let mut split = Vec::new();
+                if split.try_reserve(parts).is_err() {
+                    return Err("_লিস্ট-সমান-ভাগ() couldn't allocate memory for that many parts".to_string());
+                }
+                let mut start = 0;
+                for i in 0..parts {
+                    let end = start + size + if i < extra { 1 } else { 0 };
+                    split.push(list[start..end].to_vec());
+                    start = end;
+                }
+                Ok(split)
+            },
+            [_, _] => Err("_লিস্ট-সমান-ভাগ() function's arguments must be list and number".to_string()),
+            _ => Err("_লিস্ট-সমান-ভাগ() function expects two argument".to_string()),
+        }
+    }
+
+    // Walks path of list indexes and record keys starting from value, returns nil as soon as
+    // a step is missing or can't be applied to current value
+    pub(crate) fn _try_index(arguments: Vec<DataType>,
+                             lists: &[Vec<DataType>],
+                             records: &[HashMap<String, DataType>]) -> Result<DataType, String> {
+        if arguments.len() == 2 {
+            let path = match &arguments[1] {
+                DataType::List(path_index) => &lists[*path_index],
+                _ => return Err("_ট্রাই-ইনডেক্স() function's second argument must be list".to_string()),
+            };
+            let mut current = arguments[0].clone();
+            for step in path.iter() {
+                let next = match (&current, step) {
+                    (DataType::List(list_index), DataType::Num(i)) if *i >= 0.0 && i.fract() == 0.0 => {
+                        lists[*list_index].get(*i as usize)
+                    },
+                    (DataType::NamelessRecord(record_index), DataType::String(key)) => {
+                        records[*record_index].get(&**key)
+                    },
+                    _ => None,
+                };
+                match next {
+                    Some(next) => current = next.clone(),
+                    None => return Ok(DataType::Nil),
+                }
+            }
+            Ok(current)
+        } else { Err("_ট্রাই-ইনডেক্স() function expects two argument".to_string())}
+    }
+
+    // Both arguments are already evaluated before call, so fallback is evaluated even if it is not used
+    pub(crate) fn _default(arguments: Vec<DataType>) -> Result<DataType, String> {
+        match arguments.as_slice() {
+            [DataType::Nil, fallback] => Ok(fallback.clone()),
+            [value, _] => Ok(value.clone()),
+            _ => Err("_ডিফল্ট() function expects two argument".to_string()),
+        }
+    }
+
+    // Returns internal slot index of list or record and -1 for other values, two variables
+    // refer to same list or record if their slot index is same
+    pub(crate) fn _inspect(arguments: Vec<DataType>) -> Result<DataType, String> {
+        if arguments.len() == 1 {
+            match arguments[0] {
+                DataType::List(index) | DataType::NamelessRecord(index) => Ok(DataType::Num(index as f64)),
+                _ => Ok(DataType::Num(-1.0)),
+            }
+        } else { Err("_ইনস্পেক্ট() function expects one argument".to_string())}
+    }
+
+    // Returns number of keys in record, record counterpart of _লিস্ট-লেন
+    pub(crate) fn _record_size(arguments: Vec<DataType>, records: &[HashMap<String, DataType>]) -> Result<DataType, String> {
+        if arguments.len() == 1 {
+            match &arguments[0] {
+                DataType::NamelessRecord(index) => Ok(DataType::Num(records[*index].len() as f64)),
+                _ => Err("_রেকর্ড-আকার() function's argument must be record".to_string()),
+            }
+        } else { Err("_রেকর্ড-আকার() function expects one argument".to_string())}
+    }
+
+    // Returns keys of record in sorted order, same order used when record is printed
+    pub(crate) fn _record_keys(arguments: Vec<DataType>, records: &[HashMap<String, DataType>]) -> Result<Vec<DataType>, String> {
+        if arguments.len() == 1 {
+            match &arguments[0] {
+                DataType::NamelessRecord(index) => {
+                    let mut keys: Vec<&String> = records[*index].keys().collect();
+                    keys.sort();
+                    Ok(keys.into_iter().map(|key| DataType::String(key.as_str().into())).collect())
+                },
+                _ => Err("_রেকর্ড-কী() function's argument must be record".to_string()),
+            }
+        } else { Err("_রেকর্ড-কী() function expects one argument".to_string())}
+    }
+
+    // Builds key=value&key=value query string from record, keys are sorted. Every key and value is
+    // percent encoded, so spaces and non ascii characters are safe to use in url
+    pub(crate) fn _url_query(arguments: Vec<DataType>, records: &[HashMap<String, DataType>]) -> Result<DataType, String> {
+        match arguments.as_slice() {
+            [DataType::NamelessRecord(index)] => {
+                let mut pairs: Vec<(&String, &DataType)> = records[*index].iter().collect();
+                pairs.sort_by(|a, b| a.0.cmp(b.0));
+
+                let mut query_parts: Vec<String> = Vec::with_capacity(pairs.len());
+                for (key, value) in pairs {
+                    let value = match BuiltInFunctionList::primitive_to_string(value) {
+                        Some(value) => value,
+                        None => return Err("_ইউআরএল-কোয়েরি() record values must be number, bool, string or nil".to_string()),
+                    };
+                    query_parts.push(format!("{}={}", BuiltInFunctionList::url_encode(key),
+                                             BuiltInFunctionList::url_encode(&value)));
+                }
+                Ok(DataType::String(query_parts.join("&").into()))
+            },
+            [_] => Err("_ইউআরএল-কোয়েরি() function's argument must be record".to_string()),
+            _ => Err("_ইউআরএল-কোয়েরি() function expects one argument".to_string()),
+        }
+    }
+
+    // Encodes every byte of utf-8 string as %XX except unreserved characters of RFC 3986
+    fn url_encode(s: &str) -> String {
+        let mut encoded = String::with_capacity(s.len());
+        for byte in s.bytes() {
+            match byte {
+                b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'-' | b'_' | b'.' | b'~' => encoded.push(byte as char),
+                _ => encoded.push_str(&format!("%{:02X}", byte)),
+            }
+        }
+        encoded
+    }
+
+    // Returns values of record ordered by their keys, so n-th value belongs to n-th key of _রেকর্ড-কী
+    pub(crate) fn _record_values(arguments: Vec<DataType>, records: &[HashMap<String, DataType>]) -> Result<Vec<DataType>, String> {
+        if arguments.len() == 1 {
+            match &arguments[0] {
+                DataType::NamelessRecord(index) => {
+                    let mut entries: Vec<(&String, &DataType)> = records[*index].iter().collect();
+                    entries.sort_by_key(|(a, _)| *a);
+                    Ok(entries.into_iter().map(|(_, value)| value.clone()).collect())
+                },
+                _ => Err("_রেকর্ড-ভ্যালু() function's argument must be record".to_string()),
+            }
+        } else { Err("_রেকর্ড-ভ্যালু() function expects one argument".to_string())}
+    }
+
+    // Returns true if key exists in record, key with nil value also exists
+    pub(crate) fn _record_has(arguments: Vec<DataType>, records: &[HashMap<String, DataType>]) -> Result<DataType, String> {
+        if arguments.len() == 2 {
+            match (&arguments[0], &arguments[1]) {
+                (DataType::NamelessRecord(index), DataType::String(key)) => {
+                    Ok(DataType::Bool(records[*index].contains_key(&**key)))
+                },
+                _ => Err("_রেকর্ড-আছে() function's arguments must be record and string".to_string()),
+            }
+        } else { Err("_রেকর্ড-আছে() function expects two argument".to_string())}
+    }
+
+    // Returns shallow copy of record with key set to value, original record is not modified.
+    // Nested lists and records are shared between original and copy
+    pub(crate) fn _record_update(arguments: Vec<DataType>, records: &[HashMap<String, DataType>]) -> Result<HashMap<String, DataType>, String> {
+        match arguments.as_slice() {
+            [DataType::NamelessRecord(index), DataType::String(key), value] => {
+                let mut updated = records[*index].clone();
+                updated.insert(key.to_string(), value.clone());
+                Ok(updated)
+            },
+            [_, _, _] => Err("_রেকর্ড-আপডেট() function's first two arguments must be record and string".to_string()),
+            _ => Err("_রেকর্ড-আপডেট() function expects three argument".to_string()),
+        }
+    }
+
+    // Returns element at index, or nil when index is out of range instead of error
+    pub(crate) fn _list_get(arguments: Vec<DataType>, lists: &[Vec<DataType>]) -> Result<DataType, String> {
+        if arguments.len() == 2 {
+            match (&arguments[0], &arguments[1]) {
+                (DataType::List(list_index), DataType::Num(i)) => {
+                    let list = &lists[*list_index];
+                    if *i < 0.0 || i.fract() != 0.0 || *i >= list.len() as f64 {
+                        return Ok(DataType::Nil);
+                    }
+                    Ok(list[*i as usize].clone())
+                },
+                (DataType::List(_), _) => Err("_লিস্ট-পাও() function's second argument must be number".to_string()),
+                _ => Err("_লিস্ট-পাও() function's first argument must be list".to_string()),
+            }
+        } else { Err("_লিস্ট-পাও() function expects two argument".to_string())}
+    }
+
+    // Returns value of key in record, or default when key is absent instead of error
+    pub(crate) fn _record_get(arguments: Vec<DataType>, records: &[HashMap<String, DataType>]) -> Result<DataType, String> {
+        if arguments.len() == 3 {
+            match (&arguments[0], &arguments[1]) {
+                (DataType::NamelessRecord(record_index), DataType::String(key)) => {
+                    match records[*record_index].get(&**key) {
+                        Some(value) => Ok(value.clone()),
+                        None => Ok(arguments[2].clone()),
+                    }
+                },
+                (DataType::NamelessRecord(_), _) => Err("_রেকর্ড-পাও() function's second argument must be string".to_string()),
+                _ => Err("_রেকর্ড-পাও() function's first argument must be record".to_string()),
+            }
+        } else { Err("_রেকর্ড-পাও() function expects three argument".to_string())}
+    }
+
     pub(crate) fn _read_line(arguments: Vec<DataType>) -> Result<DataType, String> {
         if arguments.len() == 0 {
             let mut input = String::new();
@@ -204,7 +1111,7 @@ impl BuiltInFunctionList {
         if arguments.len() == 1 {
             let error = arguments[0].clone();
             match error {
-                DataType::String(err_message) => return Ok(err_message),
+                DataType::String(err_message) => Ok(err_message.to_string()),
                 _ => return Err(format!("_এরর() functions arguments must be string")),
             }
         } else {
@@ -218,7 +1125,7 @@ impl BuiltInFunctionList {
             let split_by = arguments[1].clone();
             match (string, split_by) {
                 (DataType::String(string), DataType::String(split_by)) => {
-                    let mut splitted_string: Vec<&str> = string.split(&split_by).collect();
+                    let mut splitted_string: Vec<&str> = string.split(&*split_by).collect();
                     // For some reason split with "" causes splits to have "" at benginning and end
                     // Thats why removes character at start finish
                     if splitted_string[0] == "" && splitted_string[splitted_string.len() - 1] == "" {
@@ -226,7 +1133,7 @@ impl BuiltInFunctionList {
                         splitted_string.remove(splitted_string.len() - 1);
                     }
                     let splitted_string: Vec<DataType> = splitted_string.iter()
-                        .map(|s| DataType::String(String::from(s.clone()))).collect();
+                        .map(|s| DataType::String((*s).into())).collect();
                     lists.push(splitted_string);
                     return Ok(DataType::List(lists.len() - 1));
                 },
@@ -247,11 +1154,11 @@ impl BuiltInFunctionList {
                     let mut strings: Vec<String> = Vec::new();
                     for string in string_list {
                         if let DataType::String(string) = string.clone() {
-                            strings.push(string);
+                            strings.push(string.to_string());
                         } else { return Err(format!("_স্ট্রিং-জয়েন()); functions only accepts list of strings")); }
                     }
-                    let joined_string = strings.join(&join_by);
-                    return Ok(DataType::String(joined_string));
+                    let joined_string = strings.join(&*join_by);
+                    Ok(DataType::String(joined_string.into()))
                 },
                 _ => return Err(format!("_স্ট্রিং-জয়েন()); functions arguments must be list and string")),
             }
@@ -264,13 +1171,14 @@ impl BuiltInFunctionList {
         if arguments.len() == 1 {
             let data = arguments[0].clone();
             let d = match data {
-                DataType::Num(_) => DataType::String(String::from("_সংখ্যা")),
-                DataType::Bool(_) => DataType::String(String::from("_বুলিয়ান")),
-                DataType::String(_) => DataType::String(String::from("_স্ট্রিং")),
-                DataType::List(_) => DataType::String(String::from("_লিস্ট")),
-                DataType::NamelessRecord(_) => DataType::String(String::from("_রেকর্ড")),
-                DataType::Function(_) => DataType::String(String::from("_ফাং")),
-                DataType::Nil => DataType::String(String::from("_শূন্য")),
+                DataType::Num(_) => DataType::String("_সংখ্যা".into()),
+                DataType::Bool(_) => DataType::String("_বুলিয়ান".into()),
+                DataType::String(_) => DataType::String("_স্ট্রিং".into()),
+                DataType::List(_) => DataType::String("_লিস্ট".into()),
+                DataType::NamelessRecord(_) => DataType::String("_রেকর্ড".into()),
+                DataType::Function(_) => DataType::String("_ফাং".into()),
+                DataType::FileHandle(_) => DataType::String("_ফাইল-হ্যান্ডেল".into()),
+                DataType::Nil => DataType::String("_শূন্য".into()),
             };
             return Ok(d);
         } else {
@@ -283,10 +1191,10 @@ impl BuiltInFunctionList {
             let path_data = arguments[0].clone();
             match path_data {
                 DataType::String(p) => {
-                    let path = Path::new(&p);
+                    let path = Path::new(&*p);
                     let read_result = std::fs::read_to_string(path);
                     match read_result {
-                        Ok(content) => Ok(DataType::String(content)),
+                        Ok(content) => Ok(DataType::String(content.into())),
                         Err(e) => return Err(format!("_রিড-ফাইল());: {}", e.to_string())),
                     }
                 },
@@ -303,8 +1211,8 @@ impl BuiltInFunctionList {
             let content_data = arguments[1].clone();
             match (path_data, content_data) {
                 (DataType::String(p), DataType::String(content)) => {
-                    let path = Path::new(&p);
-                    let write_result = std::fs::write(path, content);
+                    let path = Path::new(&*p);
+                    let write_result = std::fs::write(path, &*content);
                     match write_result {
                         Ok(_) => return Ok(DataType::Bool(true)),
                         Err(e) => return Err(format!("_রাইট-ফাইল(): {}", e.to_string())),
@@ -317,12 +1225,73 @@ impl BuiltInFunctionList {
         }
     }
 
+    // Reads file without any text decoding, every byte becomes a number in returned list
+    pub(crate) fn _read_file_bytes(arguments: Vec<DataType>) -> Result<Vec<DataType>, String> {
+        if arguments.len() == 1 {
+            match &arguments[0] {
+                DataType::String(p) => {
+                    match std::fs::read(Path::new(&**p)) {
+                        Ok(bytes) => Ok(bytes.into_iter().map(|b| DataType::Num(b as f64)).collect()),
+                        Err(e) => Err(format!("_রিড-ফাইল-বাইট(): {}", e)),
+                    }
+                },
+                _ => Err("_রিড-ফাইল-বাইট() function's path argument must be of type string".to_string()),
+            }
+        } else {
+            Err("_রিড-ফাইল-বাইট() function expects one argument".to_string())
+        }
+    }
+
+    // Writes list of numbers as raw bytes, every number must be a whole number from 0 to 255
+    pub(crate) fn _write_file_bytes(arguments: Vec<DataType>, lists: &[Vec<DataType>]) -> Result<DataType, String> {
+        if arguments.len() == 2 {
+            match (&arguments[0], &arguments[1]) {
+                (DataType::String(p), DataType::List(list_index)) => {
+                    let mut bytes: Vec<u8> = Vec::with_capacity(lists[*list_index].len());
+                    for elem in lists[*list_index].iter() {
+                        match elem {
+                            DataType::Num(n) if *n >= 0.0 && *n <= 255.0 && n.fract() == 0.0 => bytes.push(*n as u8),
+                            _ => return Err("_রাইট-ফাইল-বাইট() every byte must be a whole number from ০ to ২৫৫".to_string()),
+                        }
+                    }
+                    match std::fs::write(Path::new(&**p), bytes) {
+                        Ok(_) => Ok(DataType::Bool(true)),
+                        Err(e) => Err(format!("_রাইট-ফাইল-বাইট(): {}", e)),
+                    }
+                },
+                _ => Err("_রাইট-ফাইল-বাইট() function's arguments must be string and list".to_string()),
+            }
+        } else {
+            Err("_রাইট-ফাইল-বাইট() function expects two argument".to_string())
+        }
+    }
+
+    // Never fails for invalid bytes, list containing anything other than whole number
+    // from 0 to 255 is not valid utf-8 either
+    pub(crate) fn _valid_utf8(arguments: Vec<DataType>, lists: &[Vec<DataType>]) -> Result<DataType, String> {
+        match arguments.as_slice() {
+            [DataType::List(list_index)] => {
+                let mut bytes: Vec<u8> = Vec::with_capacity(lists[*list_index].len());
+                for elem in lists[*list_index].iter() {
+                    match elem {
+                        DataType::Num(n) if *n >= 0.0 && *n <= 255.0 && n.fract() == 0.0 => bytes.push(*n as u8),
+                        _ => return Ok(DataType::Bool(false)),
+                    }
+                }
+                Ok(DataType::Bool(std::str::from_utf8(&bytes).is_ok()))
+            },
+            [_] => Err("_বৈধ-ইউটিএফ৮() function's argument must be list".to_string()),
+            _ => Err("_বৈধ-ইউটিএফ৮() function expects one argument".to_string()),
+        }
+    }
+
     pub(crate) fn _delete_file(arguments: Vec<DataType>) -> Result<DataType, String> {
         if arguments.len() == 1 {
             let path_data = arguments[0].clone();
             match path_data {
                 DataType::String(p) => {
-                    let path = Path::new(&p);
+                    let path = Path::new(&*p);
+                    BuiltInFunctionList::check_deletable(path, "_ডিলিট-ফাইল")?;
                     let delete_result = std::fs::remove_file(path);
                     match delete_result {
                         Ok(_) => Ok(DataType::Bool(true)),
@@ -341,7 +1310,7 @@ impl BuiltInFunctionList {
             let path_data = arguments[0].clone();
             match path_data {
                 DataType::String(p) => {
-                    let path = Path::new(&p);
+                    let path = Path::new(&*p);
                     let create_dir_result = std::fs::create_dir_all(path);
                     match create_dir_result {
                         Ok(_) => return Ok(DataType::Bool(true)),
@@ -360,7 +1329,7 @@ impl BuiltInFunctionList {
             let path_data = arguments[0].clone();
             match path_data {
                 DataType::String(p) => {
-                    let path = Path::new(&p);
+                    let path = Path::new(&*p);
                     let read_dir_result = std::fs::read_dir(path);
                     match read_dir_result {
                         Ok(paths) => {
@@ -381,12 +1350,120 @@ impl BuiltInFunctionList {
         }
     }
 
+    // Changes working directory of the process, relative paths used by file functions after this
+    // are resolved from new directory. _ডাইরেক্টরি constant is not affected, it is always source file's directory
+    pub(crate) fn _change_dir(arguments: Vec<DataType>) -> Result<DataType, String> {
+        if arguments.len() == 1 {
+            match &arguments[0] {
+                DataType::String(p) => {
+                    let path = Path::new(&**p);
+                    match std::env::set_current_dir(path) {
+                        Ok(_) => Ok(DataType::Bool(true)),
+                        Err(e) => Err(format!("_ডাইরেক্টরি-বদল(): {}, path: {}", e, path.display())),
+                    }
+                },
+                _ => Err("_ডাইরেক্টরি-বদল() function's argument must be of type string".to_string()),
+            }
+        } else {
+            Err("_ডাইরেক্টরি-বদল() function expects one argument".to_string())
+        }
+    }
+
+    // Returns path of every file under directory recursively, paths are relative to given directory
+    // and always use '/' as separator. Paths are sorted so that result doesn't depend on platform
+    pub(crate) fn _walk_dir(arguments: Vec<DataType>) -> Result<Vec<String>, String> {
+        if arguments.len() == 1 {
+            match &arguments[0] {
+                DataType::String(p) => {
+                    let root = Path::new(&**p);
+                    let mut visited_dirs: HashSet<PathBuf> = HashSet::new();
+                    let mut all_files: Vec<String> = Vec::new();
+                    BuiltInFunctionList::walk_dir(root, "", &mut visited_dirs, &mut all_files)?;
+                    all_files.sort();
+                    Ok(all_files)
+                },
+                _ => Err("_ওয়াক-ডাইরেক্টরি() function's argument must be of type string".to_string()),
+            }
+        } else {
+            Err("_ওয়াক-ডাইরেক্টরি() function expects one argument".to_string())
+        }
+    }
+
+    fn walk_dir(dir: &Path, relative_dir: &str, visited_dirs: &mut HashSet<PathBuf>,
+                all_files: &mut Vec<String>) -> Result<(), String> {
+        let to_err = |e: std::io::Error, path: &Path| format!("_ওয়াক-ডাইরেক্টরি(): {}, path: {}", e, path.display());
+
+        // Symlinks are followed, so same directory may be reached again through a symlink.
+        // Canonical path of every visited directory is remembered to avoid walking in a loop
+        let canonical_dir = dir.canonicalize().map_err(|e| to_err(e, dir))?;
+        if !visited_dirs.insert(canonical_dir) {
+            return Ok(());
+        }
+
+        let entries = std::fs::read_dir(dir).map_err(|e| to_err(e, dir))?;
+        for entry in entries {
+            let entry = entry.map_err(|e| to_err(e, dir))?;
+            let path = entry.path();
+            let relative_path = format!("{}{}", relative_dir, entry.file_name().to_string_lossy());
+            // fs::metadata follows symlinks, broken symlinks are treated as file
+            let is_dir = std::fs::metadata(&path).map(|m| m.is_dir()).unwrap_or(false);
+            if is_dir {
+                BuiltInFunctionList::walk_dir(&path, &format!("{}/", relative_path), visited_dirs, all_files)?;
+            } else {
+                all_files.push(relative_path);
+            }
+        }
+        Ok(())
+    }
+
+    // Returns name, type and size in bytes of every entry as key-value pairs,
+    // interpreter converts them to list of records
+    pub(crate) fn _read_dir_detailed(arguments: Vec<DataType>) -> Result<Vec<HashMap<String, DataType>>, String> {
+        if arguments.len() == 1 {
+            let path_data = arguments[0].clone();
+            match path_data {
+                DataType::String(p) => {
+                    let path = Path::new(&*p);
+                    let read_dir_result = std::fs::read_dir(path);
+                    match read_dir_result {
+                        Ok(entries) => {
+                            let mut all_entries: Vec<HashMap<String, DataType>> = Vec::new();
+                            for entry in entries {
+                                let entry = match entry {
+                                    Ok(entry) => entry,
+                                    Err(e) => return Err(format!("_রিড-ডাইরেক্টরি-বিস্তারিত(): {}, path: {}", e, path.display())),
+                                };
+                                let metadata = match entry.metadata() {
+                                    Ok(metadata) => metadata,
+                                    Err(e) => return Err(format!("_রিড-ডাইরেক্টরি-বিস্তারিত(): {}, path: {}", e, entry.path().display())),
+                                };
+                                let entry_type = if metadata.is_dir() { "ডাইরেক্টরি" } else { "ফাইল" };
+
+                                let mut record: HashMap<String, DataType> = HashMap::new();
+                                record.insert("নাম".to_string(), DataType::String(entry.file_name().to_string_lossy().into()));
+                                record.insert("ধরন".to_string(), DataType::String(entry_type.into()));
+                                record.insert("সাইজ".to_string(), DataType::Num(metadata.len() as f64));
+                                all_entries.push(record);
+                            }
+                            Ok(all_entries)
+                        },
+                        Err(e) => Err(format!("_রিড-ডাইরেক্টরি-বিস্তারিত(): {}, path: {}", e, path.display())),
+                    }
+                },
+                _ => Err("_রিড-ডাইরেক্টরি-বিস্তারিত() function's argument must be of type string".to_string()),
+            }
+        } else {
+            Err("_রিড-ডাইরেক্টরি-বিস্তারিত() function expects one argument".to_string())
+        }
+    }
+
     pub(crate) fn _delete_dir(arguments: Vec<DataType>) -> Result<DataType, String> {
         if arguments.len() == 1 {
             let path_data = arguments[0].clone();
             match path_data {
                 DataType::String(p) => {
-                    let path = Path::new(&p);
+                    let path = Path::new(&*p);
+                    BuiltInFunctionList::check_deletable(path, "_ডিলিট-ডাইরেক্টরি")?;
                     let delete_result = std::fs::remove_dir_all(path);
                     match delete_result {
                         Ok(_) => return Ok(DataType::Bool(true)),
@@ -400,18 +1477,60 @@ impl BuiltInFunctionList {
         }
     }
 
+    // Only deletes directory if it's empty
+    pub(crate) fn _delete_empty_dir(arguments: Vec<DataType>) -> Result<DataType, String> {
+        if arguments.len() == 1 {
+            let path_data = arguments[0].clone();
+            match path_data {
+                DataType::String(p) => {
+                    let path = Path::new(&*p);
+                    BuiltInFunctionList::check_deletable(path, "_ডিলিট-ডাইরেক্টরি-খালি")?;
+                    let delete_result = std::fs::remove_dir(path);
+                    match delete_result {
+                        Ok(_) => Ok(DataType::Bool(true)),
+                        Err(e) => Err(format!("_ডিলিট-ডাইরেক্টরি-খালি(): {}", e))
+                    }
+                },
+                _ => Err("_ডিলিট-ডাইরেক্টরি-খালি() function's argument must be of type string".to_string()),
+            }
+        } else {
+            Err("_ডিলিট-ডাইরেক্টরি-খালি() function expects one argument".to_string())
+        }
+    }
+
+    // Refuses empty path, filesystem root, current working directory and its parents,
+    // because a wrong path given to delete functions can remove everything under it
+    fn check_deletable(path: &Path, function_name: &str) -> Result<(), String> {
+        if path.as_os_str().is_empty() {
+            return Err(format!("{}(): refusing to delete empty path", function_name));
+        }
+        // if path can't be resolved, it doesn't exist and delete function will report it
+        if let Ok(full_path) = path.canonicalize() {
+            if full_path.parent().is_none() {
+                return Err(format!("{}(): refusing to delete filesystem root, path: {}", function_name, path.display()));
+            }
+            if let Ok(current_dir) = std::env::current_dir().and_then(|d| d.canonicalize()) {
+                if current_dir.starts_with(&full_path) {
+                    return Err(format!("{}(): refusing to delete current working directory or its parent, path: {}",
+                                       function_name, path.display()));
+                }
+            }
+        }
+        Ok(())
+    }
+
     pub(crate) fn _file_or_dir(arguments: Vec<DataType>) -> Result<DataType, String> {
         if arguments.len() == 1 {
             let path_data = arguments[0].clone();
             match path_data {
                 DataType::String(p) => {
-                    let path = Path::new(&p);
+                    let path = Path::new(&*p);
                     let result = std::fs::metadata(path);
                     match result {
                         Ok(m) => {
                             match m.is_file() {
-                                true => return Ok(DataType::String("ফাইল".to_string())),
-                                false => return Ok(DataType::String("ডাইরেক্টরি".to_string())),
+                                true => Ok(DataType::String("ফাইল".into())),
+                                false => Ok(DataType::String("ডাইরেক্টরি".into())),
                             }
                         },
                         Err(e) => return Err(format!("_ফাইল-নাকি-ডাইরেক্টরি(): {}, path: {}", e.to_string(), path.display())),
@@ -423,4 +1542,412 @@ impl BuiltInFunctionList {
             return Err(format!("_ফাইল-নাকি-ডাইরেক্টরি() function expects one argument"));
         }
     }
+
+    // Returns parsed json tree, interpreter converts it to pakhi list and record
+    pub(crate) fn _json_parse(arguments: Vec<DataType>) -> Result<JsonValue, String> {
+        if arguments.len() == 1 {
+            match arguments[0].clone() {
+                DataType::String(json_string) => {
+                    match json::parse(&json_string) {
+                        Ok(json_value) => Ok(json_value),
+                        Err(e) => Err(format!("_জেসন-পার্স(): {}", e)),
+                    }
+                },
+                _ => Err("_জেসন-পার্স() function's argument must be of type string".to_string()),
+            }
+        } else {
+            Err("_জেসন-পার্স() function expects one argument".to_string())
+        }
+    }
+
+    // Same as _json_parse but parse error is returned as None instead of Err
+    pub(crate) fn _json_parse_safe(arguments: Vec<DataType>) -> Result<Option<JsonValue>, String> {
+        if arguments.len() == 1 {
+            match arguments[0].clone() {
+                DataType::String(json_string) => Ok(json::parse(&json_string).ok()),
+                _ => Err("_জেসন-পার্স-নিরাপদ() function's argument must be of type string".to_string()),
+            }
+        } else {
+            Err("_জেসন-পার্স-নিরাপদ() function expects one argument".to_string())
+        }
+    }
+
+    // Returns id of currently running pakhi process
+    pub(crate) fn _process_id(arguments: Vec<DataType>) -> Result<DataType, String> {
+        if arguments.is_empty() {
+            Ok(DataType::Num(std::process::id() as f64))
+        } else { Err("_প্রসেস-আইডি() function doesn't take any argument".to_string())}
+    }
+
+    // Returns a unique path inside system temp directory. Name is made from process id, a counter
+    // and current time, so paths don't collide between processes or between calls.
+    // If argument is সত্য empty file is created, existing file is never overwritten
+    pub(crate) fn _temp_file(arguments: Vec<DataType>) -> Result<DataType, String> {
+        let create = match arguments.as_slice() {
+            [] => false,
+            [DataType::Bool(create)] => *create,
+            [_] => return Err("_টেম্প-ফাইল() function's argument must be bool".to_string()),
+            _ => return Err("_টেম্প-ফাইল() function expects zero or one argument".to_string()),
+        };
+
+        loop {
+            let nanos = std::time::SystemTime::now().duration_since(std::time::UNIX_EPOCH)
+                .map(|d| d.as_nanos()).unwrap_or(0);
+            let counter = TEMP_FILE_COUNTER.fetch_add(1, Ordering::SeqCst);
+            let path = std::env::temp_dir().join(format!("pakhi-{}-{}-{}.tmp", std::process::id(), counter, nanos));
+            if !create {
+                return Ok(DataType::String(path.to_string_lossy().as_ref().into()));
+            }
+            match OpenOptions::new().write(true).create_new(true).open(&path) {
+                Ok(_) => return Ok(DataType::String(path.to_string_lossy().as_ref().into())),
+                // some other process created same file, trying again with next counter
+                Err(e) if e.kind() == std::io::ErrorKind::AlreadyExists => continue,
+                Err(e) => return Err(format!("_টেম্প-ফাইল(): {}, path: {}", e, path.display())),
+            }
+        }
+    }
+
+    // Returns record with operating system, cpu architecture and number of cpus program can use
+    pub(crate) fn _system_info(arguments: Vec<DataType>) -> Result<HashMap<String, DataType>, String> {
+        if arguments.is_empty() {
+            let cpu_count = std::thread::available_parallelism().map(|n| n.get()).unwrap_or(1);
+            let mut info: HashMap<String, DataType> = HashMap::new();
+            info.insert("ওএস".to_string(), DataType::String(std::env::consts::OS.into()));
+            info.insert("আর্কিটেকচার".to_string(), DataType::String(std::env::consts::ARCH.into()));
+            info.insert("সিপিইউ".to_string(), DataType::Num(cpu_count as f64));
+            Ok(info)
+        } else { Err("_সিস্টেম-তথ্য() function doesn't take any argument".to_string())}
+    }
+
+    // Returns live list, live record, free list count and bytes used by stored strings
+    // as key-value pairs of a record
+    pub(crate) fn _memory_info(arguments: Vec<DataType>,
+                               lists: &[Vec<DataType>],
+                               free_lists: &[usize],
+                               nameless_records: &[HashMap<String, DataType>],
+                               free_nameless_records: &[usize]) -> Result<HashMap<String, DataType>, String> {
+        if arguments.is_empty() {
+            let mut info: HashMap<String, DataType> = HashMap::new();
+            let live_lists = lists.len() - free_lists.len();
+            let live_records = nameless_records.len() - free_nameless_records.len();
+            info.insert("তালিকা".to_string(), DataType::Num(live_lists as f64));
+            info.insert("রেকর্ড".to_string(), DataType::Num(live_records as f64));
+            info.insert("মুক্ত-তালিকা".to_string(), DataType::Num(free_lists.len() as f64));
+
+            // Bytes used by strings stored inside lists and records, strings sharing same
+            // storage are counted once
+            let mut seen_strings: HashSet<*const u8> = HashSet::new();
+            let mut string_bytes = 0;
+            let stored_values = lists.iter().flat_map(|list| list.iter())
+                .chain(nameless_records.iter().flat_map(|record| record.values()));
+            for value in stored_values {
+                if let DataType::String(s) = value {
+                    if seen_strings.insert(s.as_ptr()) {
+                        string_bytes += s.len();
+                    }
+                }
+            }
+            info.insert("স্ট্রিং-বাইট".to_string(), DataType::Num(string_bytes as f64));
+            Ok(info)
+        } else {
+            Err("_মেমোরি-তথ্য() function expects zero argument".to_string())
+        }
+    }
+
+    // Formats list of records as aligned table lines, first line is header made of sorted
+    // record keys, second line separates header from rows
+    pub(crate) fn _print_table(arguments: Vec<DataType>,
+                               lists: &[Vec<DataType>],
+                               nameless_records: &[HashMap<String, DataType>]) -> Result<Vec<String>, String> {
+        if arguments.len() == 1 {
+            let rows = match &arguments[0] {
+                DataType::List(list_index) => lists.get(*list_index).unwrap(),
+                _ => return Err("_প্রিন্ট-সারণি() function's argument must be list of records".to_string()),
+            };
+            if rows.is_empty() {
+                return Ok(Vec::new());
+            }
+
+            let mut keys: Vec<String> = Vec::new();
+            let mut cells: Vec<Vec<String>> = Vec::new();
+            for (row_no, row) in rows.iter().enumerate() {
+                let record = match row {
+                    DataType::NamelessRecord(record_index) => nameless_records.get(*record_index).unwrap(),
+                    _ => return Err("_প্রিন্ট-সারণি() function's argument must be list of records".to_string()),
+                };
+                let mut row_keys: Vec<String> = record.keys().cloned().collect();
+                row_keys.sort();
+                if row_no == 0 {
+                    keys = row_keys;
+                } else if row_keys != keys {
+                    return Err(format!("_প্রিন্ট-সারণি() row {} has keys [{}] but first row has keys [{}]",
+                                       row_no, row_keys.join(", "), keys.join(", ")));
+                }
+
+                let mut row_cells: Vec<String> = Vec::new();
+                for key in keys.iter() {
+                    match BuiltInFunctionList::primitive_to_string(record.get(key).unwrap()) {
+                        Some(cell) => row_cells.push(cell),
+                        None => return Err("_প্রিন্ট-সারণি() table cell can only be number, bool, string or nil".to_string()),
+                    }
+                }
+                cells.push(row_cells);
+            }
+
+            let mut widths: Vec<usize> = keys.iter().map(|k| k.chars().count()).collect();
+            for row_cells in cells.iter() {
+                for (i, cell) in row_cells.iter().enumerate() {
+                    widths[i] = widths[i].max(cell.chars().count());
+                }
+            }
+
+            let format_line = |line_cells: &Vec<String>| -> String {
+                let padded: Vec<String> = line_cells.iter().enumerate()
+                    .map(|(i, cell)| {
+                        let padding = widths[i] - cell.chars().count();
+                        format!("{}{}", cell, " ".repeat(padding))
+                    }).collect();
+                padded.join(" | ").trim_end().to_string()
+            };
+
+            let mut lines: Vec<String> = Vec::new();
+            lines.push(format_line(&keys));
+            let separator: Vec<String> = widths.iter().map(|w| "-".repeat(*w)).collect();
+            lines.push(separator.join("-+-"));
+            for row_cells in cells.iter() {
+                lines.push(format_line(row_cells));
+            }
+            Ok(lines)
+        } else {
+            Err("_প্রিন্ট-সারণি() function expects one argument".to_string())
+        }
+    }
+
+    // Describes every active function call, innermost call comes first
+    pub(crate) fn _stack_trace(arguments: Vec<DataType>, call_stack: &[CallFrame]) -> Result<Vec<String>, String> {
+        if arguments.is_empty() {
+            let frames: Vec<String> = call_stack.iter().rev()
+                .map(|frame| format!("{} ({}: লাইন {})", frame.function_name, frame.src_file_path,
+                                     BuiltInFunctionList::replace_en_with_bn_digit(frame.call_line.to_string())))
+                .collect();
+            Ok(frames)
+        } else {
+            Err("_স্ট্যাক-ট্রেস() function expects zero argument".to_string())
+        }
+    }
+
+    // Opens file in "পড়া" (read), "লেখা" (write) or "যোগ" (append) mode and returns handle
+    pub(crate) fn _file_open(arguments: Vec<DataType>, file_handles: &mut Vec<Option<FileHandle>>) -> Result<DataType, String> {
+        if arguments.len() == 2 {
+            match (arguments[0].clone(), arguments[1].clone()) {
+                (DataType::String(p), DataType::String(mode)) => {
+                    let path = Path::new(&*p);
+                    let open_result = match &*mode {
+                        "পড়া" => File::open(path).map(|f| FileHandle::Reader(BufReader::new(f))),
+                        "লেখা" => File::create(path).map(FileHandle::Writer),
+                        "যোগ" => OpenOptions::new().append(true).create(true).open(path).map(FileHandle::Writer),
+                        _ => return Err("_ফাইল-খোলো() mode must be \"পড়া\", \"লেখা\" or \"যোগ\"".to_string()),
+                    };
+                    match open_result {
+                        Ok(handle) => {
+                            file_handles.push(Some(handle));
+                            Ok(DataType::FileHandle(file_handles.len() - 1))
+                        },
+                        Err(e) => Err(format!("_ফাইল-খোলো(): {}, path: {}", e, path.display())),
+                    }
+                },
+                _ => Err("_ফাইল-খোলো() function's arguments must be of type string".to_string()),
+            }
+        } else {
+            Err("_ফাইল-খোলো() function expects two argument".to_string())
+        }
+    }
+
+    // Returns next line without line ending, returns _শূন্য when whole file was read
+    pub(crate) fn _file_read_line(arguments: Vec<DataType>, file_handles: &mut [Option<FileHandle>]) -> Result<DataType, String> {
+        if arguments.len() == 1 {
+            match BuiltInFunctionList::get_file_handle(&arguments[0], file_handles, "_ফাইল-লাইন-পড়ো")? {
+                FileHandle::Reader(reader) => {
+                    let mut line = String::new();
+                    match reader.read_line(&mut line) {
+                        Ok(0) => Ok(DataType::Nil),
+                        Ok(_) => {
+                            let line = line.trim_end_matches('\n').trim_end_matches('\r');
+                            Ok(DataType::String(line.into()))
+                        },
+                        Err(e) => Err(format!("_ফাইল-লাইন-পড়ো(): {}", e)),
+                    }
+                },
+                FileHandle::Writer(_) => Err("_ফাইল-লাইন-পড়ো() file wasn't opened in \"পড়া\" mode".to_string()),
+            }
+        } else {
+            Err("_ফাইল-লাইন-পড়ো() function expects one argument".to_string())
+        }
+    }
+
+    pub(crate) fn _file_write(arguments: Vec<DataType>, file_handles: &mut [Option<FileHandle>]) -> Result<DataType, String> {
+        if arguments.len() == 2 {
+            let content = match arguments[1].clone() {
+                DataType::String(content) => content,
+                _ => return Err("_ফাইল-লেখো() function's content argument must be of type string".to_string()),
+            };
+            match BuiltInFunctionList::get_file_handle(&arguments[0], file_handles, "_ফাইল-লেখো")? {
+                FileHandle::Writer(file) => {
+                    match file.write_all(content.as_bytes()) {
+                        Ok(_) => Ok(DataType::Bool(true)),
+                        Err(e) => Err(format!("_ফাইল-লেখো(): {}", e)),
+                    }
+                },
+                FileHandle::Reader(_) => Err("_ফাইল-লেখো() file wasn't opened in \"লেখা\" or \"যোগ\" mode".to_string()),
+            }
+        } else {
+            Err("_ফাইল-লেখো() function expects two argument".to_string())
+        }
+    }
+
+    pub(crate) fn _file_close(arguments: Vec<DataType>, file_handles: &mut [Option<FileHandle>]) -> Result<DataType, String> {
+        if arguments.len() == 1 {
+            BuiltInFunctionList::get_file_handle(&arguments[0], file_handles, "_ফাইল-বন্ধ")?;
+            if let DataType::FileHandle(i) = arguments[0] {
+                // dropping handle closes the file
+                file_handles[i] = None;
+            }
+            Ok(DataType::Bool(true))
+        } else {
+            Err("_ফাইল-বন্ধ() function expects one argument".to_string())
+        }
+    }
+
+    fn get_file_handle<'a>(handle: &DataType,
+                           file_handles: &'a mut [Option<FileHandle>],
+                           function_name: &str) -> Result<&'a mut FileHandle, String> {
+        match handle {
+            DataType::FileHandle(i) => match file_handles.get_mut(*i) {
+                Some(Some(file_handle)) => Ok(file_handle),
+                _ => Err(format!("{}() file was already closed", function_name)),
+            },
+            _ => Err(format!("{}() function's first argument must be a file handle", function_name)),
+        }
+    }
+
+    // Joins all arguments as a string, used for string interpolation "${expr}"
+    pub(crate) fn _interpolate(arguments: Vec<DataType>) -> Result<DataType, String> {
+        let mut joined = String::new();
+        for argument in arguments.iter() {
+            match BuiltInFunctionList::primitive_to_string(argument) {
+                Some(s) => joined.push_str(&s),
+                None => return Err("_ইন্টারপোলেট() only number, bool, string or nil can be interpolated".to_string()),
+            }
+        }
+        Ok(DataType::String(joined.into()))
+    }
+
+    // Replaces every {key} in template with value of key in record. Keys not found in record
+    // and unclosed '{' are kept as it is
+    pub(crate) fn _string_template(arguments: Vec<DataType>, records: &[HashMap<String, DataType>]) -> Result<DataType, String> {
+        if arguments.len() == 2 {
+            match (&arguments[0], &arguments[1]) {
+                (DataType::String(template), DataType::NamelessRecord(record_i)) => {
+                    let record = &records[*record_i];
+                    let mut rendered = String::new();
+                    let mut rest: &str = template;
+                    while let Some(open) = rest.find('{') {
+                        rendered.push_str(&rest[..open]);
+                        let after_open = &rest[open + 1..];
+                        match after_open.find('}') {
+                            Some(close) => {
+                                let key = &after_open[..close];
+                                match record.get(key) {
+                                    Some(value) => match BuiltInFunctionList::primitive_to_string(value) {
+                                        Some(value) => rendered.push_str(&value),
+                                        None => return Err(format!("_স্ট্রিং-টেমপ্লেট() value of key {} must be number, bool, string or nil", key)),
+                                    },
+                                    None => {
+                                        rendered.push('{');
+                                        rendered.push_str(key);
+                                        rendered.push('}');
+                                    },
+                                }
+                                rest = &after_open[close + 1..];
+                            },
+                            None => {
+                                rendered.push('{');
+                                rest = after_open;
+                            },
+                        }
+                    }
+                    rendered.push_str(rest);
+                    Ok(DataType::String(rendered.into()))
+                },
+                _ => Err("_স্ট্রিং-টেমপ্লেট() function's arguments must be string and record".to_string()),
+            }
+        } else { Err("_স্ট্রিং-টেমপ্লেট() function expects two argument".to_string())}
+    }
+
+    // Returns value as it is shown by দেখাও, None for list, record and other non-primitive values
+    fn primitive_to_string(data: &DataType) -> Option<String> {
+        match data {
+            DataType::Num(n) => Some(BuiltInFunctionList::replace_en_with_bn_digit(n.to_string())),
+            DataType::Bool(true) => Some("সত্য".to_string()),
+            DataType::Bool(false) => Some("মিথ্যা".to_string()),
+            DataType::String(s) => Some(s.to_string()),
+            DataType::Nil => Some("_শূন্য".to_string()),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn civil_days_round_trip() {
+        assert_eq!(0, BuiltInFunctionList::days_from_civil(1970, 1, 1));
+        assert_eq!(11016, BuiltInFunctionList::days_from_civil(2000, 2, 29));
+        assert_eq!(-1, BuiltInFunctionList::days_from_civil(1969, 12, 31));
+        for days in -800_000..800_000 {
+            let (year, month, day) = BuiltInFunctionList::civil_from_days(days);
+            assert_eq!(days, BuiltInFunctionList::days_from_civil(year, month, day));
+        }
+    }
+
+    #[test]
+    fn url_encode_keeps_only_unreserved_characters() {
+        assert_eq!("a-b_c.d~9", BuiltInFunctionList::url_encode("a-b_c.d~9"));
+        assert_eq!("a%20b%26c%3Dd", BuiltInFunctionList::url_encode("a b&c=d"));
+        assert_eq!("%E0%A6%95", BuiltInFunctionList::url_encode("ক"));
+    }
+
+    #[test]
+    fn prng_same_seed_same_sequence() {
+        let mut first = Prng::new(42);
+        let mut second = Prng::new(42);
+        let mut other = Prng::new(43);
+        let first_values: Vec<f64> = (0..10).map(|_| first.next_f64()).collect();
+        let second_values: Vec<f64> = (0..10).map(|_| second.next_f64()).collect();
+        let other_values: Vec<f64> = (0..10).map(|_| other.next_f64()).collect();
+        assert_eq!(first_values, second_values);
+        assert_ne!(first_values, other_values);
+        assert!(first_values.iter().all(|n| *n >= 0.0 && *n < 1.0));
+    }
+
+    #[test]
+    fn terminal_color_wraps_with_escape_codes() {
+        let args = vec![DataType::String("ক".into()), DataType::String("লাল".into())];
+        assert_eq!(Ok(DataType::String("\x1b[31mক\x1b[0m".into())), BuiltInFunctionList::_terminal_color(args.clone(), false));
+        assert_eq!(Ok(DataType::String("ক".into())), BuiltInFunctionList::_terminal_color(args, true));
+        let unknown = vec![DataType::String("ক".into()), DataType::String("গোলাপি".into())];
+        assert!(BuiltInFunctionList::_terminal_color(unknown, true).is_err());
+    }
+
+    #[test]
+    fn check_deletable_refuses_protected_paths() {
+        assert_eq!(Err("_ডিলিট-ডাইরেক্টরি(): refusing to delete empty path".to_string()),
+                   BuiltInFunctionList::check_deletable(Path::new(""), "_ডিলিট-ডাইরেক্টরি"));
+        assert!(BuiltInFunctionList::check_deletable(Path::new("/"), "_ডিলিট-ডাইরেক্টরি").is_err());
+        assert!(BuiltInFunctionList::check_deletable(Path::new("."), "_ডিলিট-ডাইরেক্টরি").is_err());
+        assert!(BuiltInFunctionList::check_deletable(Path::new(".."), "_ডিলিট-ডাইরেক্টরি").is_err());
+        assert!(BuiltInFunctionList::check_deletable(Path::new("./src"), "_ডিলিট-ডাইরেক্টরি").is_ok());
+    }
 }
@@ -1,9 +1,11 @@
-use std::collections::HashMap;
-use crate::common::io::{IO, RealIO};
-use crate::frontend::parser;
+use std::collections::{HashMap, HashSet};
+use std::rc::Rc;
+use crate::common::io::IO;
+use crate::frontend::{lexer, parser};
 use crate::frontend::lexer::{TokenKind, Token};
-use crate::backend::built_ins::BuiltInFunctionList;
+use crate::backend::built_ins::{BuiltInFunctionList, FileHandle, Prng};
 use crate::backend::mark_sweep;
+use crate::backend::json::JsonValue;
 use crate::common::pakhi_error::PakhiErr;
 use std::iter::FromIterator;
 use crate::common::pakhi_error::PakhiErr::{RuntimeError, TypeError};
@@ -17,12 +19,15 @@ enum Index {
 pub enum DataType {
     Num(f64),
     Bool(bool),
-    String(String),
+    // Reference counted so that interned equal strings can share same storage
+    String(Rc<str>),
     // Array variant of DataType enum only stores the index of the actual array from arrays
     // field in Interpreter, so multiple array reference implementation is easy.
     List(usize),
     NamelessRecord(usize),
     Function(Func),
+    // Stores index of opened file from file_handles field in Interpreter
+    FileHandle(usize),
     Nil,
 }
 
@@ -30,6 +35,21 @@ pub enum DataType {
 pub struct Func {
     starting_statement: usize,
     args: Vec<String>,
+    // Index of result cache in memo_caches field in Interpreter, set for functions
+    // returned by _মেমোআইজ
+    memo: Option<usize>,
+}
+
+// Rust function callable from pakhi program, arguments are already evaluated
+pub type HostFn = Box<dyn FnMut(Vec<DataType>) -> Result<DataType, String>>;
+
+// Describes an active user-defined function call, used for showing call stack
+#[derive(Debug, Clone)]
+pub(crate) struct CallFrame {
+    pub(crate) function_name: String,
+    // line from where function was called
+    pub(crate) call_line: u32,
+    pub(crate) src_file_path: String,
 }
 
 #[derive(Debug)]
@@ -37,13 +57,37 @@ struct LoopEnv {
     start: usize,
     // this is needed to destroy envs created inside loop when using continue or break
     total_envs_at_loop_creation: usize,
+    // completed iterations, checked against max_loop_iterations
+    iterations: usize,
+    // only ফরইচ loop has this
+    for_each: Option<ForEachState>,
+}
+
+#[derive(Debug)]
+struct ForEachState {
+    var_name: String,
+    // index of next element to bind with loop variable
+    next: usize,
 }
 
+// Statement indexes from here refer to statements being run by _ইভাল, so that they can be
+// removed after evaluation without moving functions kept in program statements
+const EVAL_STATEMENTS_START: usize = usize::MAX / 2;
+
+// Iterated list of ফরইচ loop is stored in a scope with this name, so that gc doesn't free it
+// while looping. Comments start with #, so program can't use this name
+const FOR_EACH_LIST: &str = "#ফরইচ-লিস্ট";
+
+// Prefix of names in the scope which keeps temporary values alive during garbage collection
+const TEMPORARY_VALUE: &str = "#অস্থায়ী-মান-";
+
 pub struct Interpreter<'a, T: IO> {
     current: usize,
     statements: Vec<parser::Stmt>,
     loops: Vec<LoopEnv>,
     return_addrs: Vec<usize>,
+    // Active function calls, last frame is the innermost call
+    call_stack: Vec<CallFrame>,
     scopes: Vec<HashMap<String, Option<DataType>>>,
     previous_if_was_executed: Vec<bool>,
     lists: Vec<Vec<DataType>>,
@@ -54,6 +98,54 @@ pub struct Interpreter<'a, T: IO> {
     free_nameless_records: Vec<usize>,
     // This is used as parameter of gc to decide if it's time to collect garbage
     total_allocated_object_count: usize,
+    // Garbage is collected when total_allocated_object_count reaches this
+    gc_threshold: usize,
+    // When true garbage is collected in small steps between statements instead of
+    // pausing program for whole collection
+    incremental_gc: bool,
+    // Marking state of currently running incremental collection
+    incremental_gc_state: Option<mark_sweep::IncrementalState>,
+    // Lists and records produced by unfinished statement of every active function call, last
+    // one is innermost call. They may not be stored in any variable yet, for example left operand
+    // of '+' while right operand calls a function, so gc treats them as roots
+    temporaries: Vec<Vec<DataType>>,
+    // When true equal strings stored inside lists and records share same storage
+    string_interning: bool,
+    // Pool of interned strings, strings only referenced from here are removed after gc
+    interned_strings: HashSet<Rc<str>>,
+    // When true declaring a variable with নাম twice in same scope is an error
+    strict_redeclaration: bool,
+    // Files opened with _ফাইল-খোলো, closed files are set to None
+    file_handles: Vec<Option<FileHandle>>,
+    // Result caches of memoized functions, key is structural key of arguments.
+    // Only number, bool, string and nil results are cached
+    memo_caches: Vec<HashMap<String, DataType>>,
+    // Indexes of lists and records frozen with _ফ্রিজ, they can't be modified.
+    // Index is removed when slot is reused for a new list or record
+    frozen_lists: HashSet<usize>,
+    frozen_records: HashSet<usize>,
+    // When true assigning a list or record to a variable stores a deep copy instead of
+    // a reference to same list or record
+    value_semantics: bool,
+    // When set a loop running more iterations than this is an error, unlimited by default
+    max_loop_iterations: Option<usize>,
+    // Calling a function when this many calls are already active is an error. Every call
+    // uses native stack, so without this limit infinite recursion crashes the whole process
+    max_call_depth: usize,
+    // Functions implemented by embedder, registered with register_host_fn
+    host_functions: HashMap<String, HostFn>,
+    // When true numbers are printed and converted to string with ASCII digits instead of bangla digits
+    ascii_digits: bool,
+    // When true built-ins that can run arbitrary code or change file system, like _ইভাল, are not available
+    sandbox: bool,
+    // State of random number generator shared by all random built-ins
+    prng: Prng,
+    // Statements of sources being run by _ইভাল, innermost call's statements are last.
+    // Index EVAL_STATEMENTS_START + i refers to eval_statements[i]
+    eval_statements: Vec<parser::Stmt>,
+    // Start and length of function definitions declared by _ইভাল, kept in statements.
+    // Same definition is kept only once
+    eval_functions: Vec<(usize, usize)>,
     io: &'a mut T,
     // Storing all built-in function names because when modules identifiers are renamed
     // we don't want to rename built-in functions
@@ -75,13 +167,14 @@ impl<'a, T: 'a + IO> Interpreter<'a, T> {
         // android
         // windows
         let os = std::env::consts::OS.to_string();
-        root_scope.insert("_প্ল্যাটফর্ম".to_string(), Some(DataType::String(os)));
+        root_scope.insert("_প্ল্যাটফর্ম".to_string(), Some(DataType::String(os.into())));
 
         Interpreter {
             current: 0,
             statements,
             loops: Vec::new(),
             return_addrs: Vec::new(),
+            call_stack: Vec::new(),
             scopes: vec![root_scope],
             previous_if_was_executed: Vec::new(),
             lists: Vec::new(),
@@ -89,32 +182,235 @@ impl<'a, T: 'a + IO> Interpreter<'a, T> {
             nameless_records: Vec::new(),
             free_nameless_records: Vec::new(),
             total_allocated_object_count: 0,
+            gc_threshold: 1000,
+            incremental_gc: false,
+            incremental_gc_state: None,
+            temporaries: vec![Vec::new()],
+            string_interning: false,
+            interned_strings: HashSet::new(),
+            strict_redeclaration: false,
+            file_handles: Vec::new(),
+            memo_caches: Vec::new(),
+            frozen_lists: HashSet::new(),
+            frozen_records: HashSet::new(),
+            value_semantics: false,
+            max_loop_iterations: None,
+            max_call_depth: 1000,
+            host_functions: HashMap::new(),
+            ascii_digits: false,
+            sandbox: false,
+            prng: Prng::from_time(),
+            eval_statements: Vec::new(),
+            eval_functions: Vec::new(),
             io,
             built_in_functions: BuiltInFunctionList::new(),
         }
     }
 
+    fn statement(&self, i: usize) -> &parser::Stmt {
+        if i >= EVAL_STATEMENTS_START {
+            &self.eval_statements[i - EVAL_STATEMENTS_START]
+        } else {
+            &self.statements[i]
+        }
+    }
+
+    fn statement_exists(&self, i: usize) -> bool {
+        if i >= EVAL_STATEMENTS_START {
+            i - EVAL_STATEMENTS_START < self.eval_statements.len()
+        } else {
+            i < self.statements.len()
+        }
+    }
+
     pub fn run(&mut self) -> Result<(), PakhiErr> {
         loop {
-            if let  parser::Stmt::EOS(_, _) = self.statements[self.current] {
+            if let  parser::Stmt::EOS(_, _) = *self.statement(self.current) {
                 break;
             }
             self.interpret()?;
-            if self.total_allocated_object_count >= 1000 {
-                let mut gc = mark_sweep::GC::new(&mut self.scopes, &mut self.lists,
-                                                 &mut self.free_lists,
-                                                 &mut self.nameless_records,
-                                                 &mut self.free_nameless_records);
-                gc.collect_garbage();
-                self.total_allocated_object_count = 0;
-            }
+            self.temporaries[0].clear();
+            self.collect_garbage_if_needed();
         }
 
         Ok(())
     }
 
+    // Enables or disables incremental garbage collection, by default whole garbage is
+    // collected at once
+    pub fn set_incremental_gc(&mut self, enabled: bool) {
+        self.incremental_gc = enabled;
+        if !enabled {
+            self.incremental_gc_state = None;
+        }
+    }
+
+    // Enables or disables interning of strings stored inside lists and records, by default
+    // every string has its own storage
+    pub fn set_string_interning(&mut self, enabled: bool) {
+        self.string_interning = enabled;
+        if !enabled {
+            self.interned_strings.clear();
+        }
+    }
+
+    // Enables or disables error on redeclaring a variable in same scope, by default
+    // redeclaration overwrites previous variable
+    pub fn set_strict_redeclaration(&mut self, enabled: bool) {
+        self.strict_redeclaration = enabled;
+    }
+
+    // Enables or disables copying lists and records on assignment, by default assignment
+    // makes both variables refer to same list or record
+    pub fn set_value_semantics(&mut self, enabled: bool) {
+        self.value_semantics = enabled;
+    }
+
+    // Limits how many times a single loop can repeat, helps finding loops which never stop.
+    // None means unlimited
+    pub fn set_max_loop_iterations(&mut self, max_iterations: Option<usize>) {
+        self.max_loop_iterations = max_iterations;
+    }
+
+    // Limits how many function calls can be active at once, default is 1000
+    pub fn set_max_call_depth(&mut self, max_depth: usize) {
+        self.max_call_depth = max_depth;
+    }
+
+    // Sets how many allocated objects trigger garbage collection, default is 1000.
+    // usize::MAX practically disables automatic collection, e.g. for benchmarking
+    pub fn set_gc_threshold(&mut self, threshold: usize) {
+        self.gc_threshold = threshold;
+    }
+
+    // Enables or disables all correctness checks at once, currently only strict redeclaration.
+    // New opt-in checks must be added here too
+    pub fn set_strict(&mut self, enabled: bool) {
+        self.set_strict_redeclaration(enabled);
+    }
+
+    // Makes দেখাও, _দেখাও and _স্ট্রিং show numbers with ASCII digits, useful for machine readable
+    // output. By default bangla digits are used
+    pub fn set_ascii_digits(&mut self, enabled: bool) {
+        self.ascii_digits = enabled;
+    }
+
+    // Enables or disables sandbox mode, in sandbox mode calling _ইভাল, _ডাইরেক্টরি-বদল or any built-in
+    // that creates, writes or deletes files and directories is an error
+    pub fn set_sandbox(&mut self, enabled: bool) {
+        self.sandbox = enabled;
+    }
+
+    // Makes f callable from pakhi program as name(...), error returned by f is reported as
+    // runtime error at call line. Identifiers in imported modules are prefixed with module name,
+    // so host function can only be called from root module
+    pub fn register_host_fn(&mut self, name: &str, f: HostFn) {
+        self.host_functions.insert(name.to_string(), f);
+    }
+
+    fn collect_garbage_if_needed(&mut self) {
+        if self.incremental_gc_state.is_none() && self.total_allocated_object_count < self.gc_threshold {
+            return;
+        }
+        let temporaries = self.temporaries_scope();
+        self.scopes.push(temporaries);
+        self.collect_garbage();
+        self.scopes.pop();
+    }
+
+    // Collects all garbage immediately, ignoring gc threshold. If an incremental collection
+    // is in progress it is abandoned and whole collection is done at once
+    pub fn collect_garbage_now(&mut self) {
+        self.incremental_gc_state = None;
+        let temporaries = self.temporaries_scope();
+        self.scopes.push(temporaries);
+        self.collect_all_garbage();
+        self.scopes.pop();
+    }
+
+    // Scope which only exists during collection, so that gc treats temporary values as roots
+    fn temporaries_scope(&self) -> HashMap<String, Option<DataType>> {
+        self.temporaries.iter().flatten().enumerate()
+            .map(|(i, value)| (format!("{}{}", TEMPORARY_VALUE, i), Some(value.clone())))
+            .collect()
+    }
+
+    fn collect_all_garbage(&mut self) {
+        let mut gc = mark_sweep::GC::new(&mut self.scopes, &mut self.lists,
+                                         &mut self.free_lists,
+                                         &mut self.nameless_records,
+                                         &mut self.free_nameless_records);
+        gc.collect_garbage();
+        self.total_allocated_object_count = 0;
+        self.prune_interned_strings();
+    }
+
+    fn collect_garbage(&mut self) {
+        if !self.incremental_gc {
+            if self.total_allocated_object_count >= self.gc_threshold {
+                self.collect_all_garbage();
+            }
+            return;
+        }
+
+        let mut gc = mark_sweep::GC::new(&mut self.scopes, &mut self.lists,
+                                         &mut self.free_lists,
+                                         &mut self.nameless_records,
+                                         &mut self.free_nameless_records);
+        if self.incremental_gc_state.is_none() && self.total_allocated_object_count >= self.gc_threshold {
+            self.incremental_gc_state = Some(gc.start_incremental());
+            self.total_allocated_object_count = 0;
+        }
+        if let Some(mut state) = self.incremental_gc_state.take() {
+            let finished = gc.incremental_step(&mut state, mark_sweep::INCREMENTAL_STEP_BUDGET);
+            if !finished {
+                self.incremental_gc_state = Some(state);
+            } else {
+                self.prune_interned_strings();
+            }
+        }
+    }
+
+    // Returns string sharing storage with previously interned equal string,
+    // other datatypes are returned unchanged
+    fn intern_string(&mut self, data: DataType) -> DataType {
+        if !self.string_interning {
+            return data;
+        }
+        match data {
+            DataType::String(s) => {
+                if let Some(interned) = self.interned_strings.get(&*s) {
+                    return DataType::String(Rc::clone(interned));
+                }
+                self.interned_strings.insert(Rc::clone(&s));
+                DataType::String(s)
+            },
+            _ => data,
+        }
+    }
+
+    // Strings referenced only by the pool aren't used by program anymore
+    fn prune_interned_strings(&mut self) {
+        self.interned_strings.retain(|s| Rc::strong_count(s) > 1);
+    }
+
+    // Must be called when a value is stored inside a list or record, so that incremental
+    // gc doesn't miss it
+    fn gc_write_barrier(&mut self, data: &DataType) {
+        if let Some(state) = &mut self.incremental_gc_state {
+            state.shade(data);
+        }
+    }
+
+    // Keeps list or record alive until statement being executed by innermost function call finishes
+    fn keep_temporary(&mut self, data: &DataType) {
+        if let DataType::List(_) | DataType::NamelessRecord(_) = data {
+            self.temporaries.last_mut().unwrap().push(data.clone());
+        }
+    }
+
     fn interpret(&mut self) -> Result<(), PakhiErr> {
-        match self.statements[self.current].clone() {
+        match self.statement(self.current).clone() {
             parser::Stmt::Print(expr, _, _) => self.interpret_print_stmt(expr)?,
             parser::Stmt::PrintNoEOL(expr, _, _) => self.interpret_print_no_eol(expr)?,
             parser::Stmt::Assignment(assign_stmt, _, _) => self.interpret_assign_stmt(assign_stmt)?,
@@ -125,12 +421,14 @@ impl<'a, T: 'a + IO> Interpreter<'a, T> {
                 self.interpret_expr(expr)?;
                 self.current += 1;
             },
+            parser::Stmt::ForEach(for_each, line, file_name) => self.interpret_for_each_stmt(for_each, line, file_name)?,
             parser::Stmt::Loop(_, _) => {
                 // consuming loop
                 self.current += 1;
 
                 // saving loop start to reuse in continue statement
-                self.loops.push(LoopEnv { start: self.current, total_envs_at_loop_creation: self.scopes.len()});
+                self.loops.push(LoopEnv { start: self.current, total_envs_at_loop_creation: self.scopes.len(),
+                                          iterations: 0, for_each: None });
 
             },
             parser::Stmt::Continue(_, _) => {
@@ -143,7 +441,20 @@ impl<'a, T: 'a + IO> Interpreter<'a, T> {
 
                 let loop_start = self.loops[last_loop_env_index].start;
 
+                self.loops[last_loop_env_index].iterations += 1;
+                if let Some(max_iterations) = self.max_loop_iterations {
+                    if self.loops[last_loop_env_index].iterations >= max_iterations {
+                        // loop statement is just before loop start
+                        let (line, file_name) = self.extract_err_meta_stmt(loop_start - 1)?;
+                        return Err(RuntimeError(line, file_name,
+                                                format!("Loop exceeded maximum iteration count {}", max_iterations)));
+                    }
+                }
+
                 self.current = loop_start;
+                if self.loops[last_loop_env_index].for_each.is_some() {
+                    self.next_for_each_iteration();
+                }
             },
             parser::Stmt::Break(_, _) => {
                 self.current += 1;
@@ -159,26 +470,12 @@ impl<'a, T: 'a + IO> Interpreter<'a, T> {
                 }
 
                 // destroying loop env
-                self.loops.pop();
-
-                let mut stack: Vec<char> = Vec::new();
-                loop {
-                    if let parser::Stmt::Loop(_, _) = self.statements[self.current] {
-                        stack.push('{');
-                    }
-
-                    if let parser::Stmt::Continue(_, _) = self.statements[self.current] {
-                        stack.pop();
-                        if stack.is_empty() {
-                            // consuming Stmt::Continue
-                            self.current += 1;
-                            break;
-                        }
-                    }
-
-                    // skipping statements in block of loop
-                    self.current += 1;
+                if let Some(LoopEnv { for_each: Some(_), .. }) = self.loops.pop() {
+                    // destroying scope holding iterated list
+                    self.scopes.pop();
                 }
+
+                self.skip_loop_body();
             },
             parser::Stmt::BlockStart(_, _) => {
                 self.current += 1;
@@ -194,20 +491,100 @@ impl<'a, T: 'a + IO> Interpreter<'a, T> {
             _ => {
                 let (line, file_name) = self.extract_err_meta_stmt(self.current)?;
                 return Err(PakhiErr::RuntimeError(line, file_name,
-                              format!("Debug Statement {:#?}", self.statements[self.current])));
+                              format!("Debug Statement {:#?}", self.statement(self.current))));
             },
         }
         Ok(())
     }
 
+    // Moves current after Stmt::Continue which ends the loop current is inside
+    fn skip_loop_body(&mut self) {
+        // counts loops nested inside skipped body, their Stmt::Continue doesn't end this loop
+        let mut nested_loops = 0;
+        loop {
+            match *self.statement(self.current) {
+                parser::Stmt::Loop(_, _) | parser::Stmt::ForEach(_, _, _) => nested_loops += 1,
+                parser::Stmt::Continue(_, _) => {
+                    if nested_loops == 0 {
+                        // consuming Stmt::Continue
+                        self.current += 1;
+                        break;
+                    }
+                    nested_loops -= 1;
+                },
+                _ => {},
+            }
+
+            // skipping statements in block of loop
+            self.current += 1;
+        }
+    }
+
+    fn interpret_for_each_stmt(&mut self, for_each: parser::ForEach, line: u32, file_name: String) -> Result<(), PakhiErr> {
+        let list = match self.interpret_expr(for_each.iterable)? {
+            list @ DataType::List(_) => list,
+            // string is iterated by characters
+            DataType::String(s) => {
+                let chars: Vec<DataType> = s.chars().map(|c| DataType::String(c.to_string().into())).collect();
+                self.create_new_list_datatype(chars)
+            },
+            _ => return Err(RuntimeError(line, file_name, "ফরইচ can only iterate list or string".to_string())),
+        };
+
+        // consuming ফরইচ, loop body starts from next statement
+        self.current += 1;
+
+        let mut list_scope: HashMap<String, Option<DataType>> = HashMap::new();
+        list_scope.insert(FOR_EACH_LIST.to_string(), Some(list));
+        self.scopes.push(list_scope);
+
+        let for_each = ForEachState { var_name: for_each.var_name.lexeme.iter().collect(), next: 0 };
+        self.loops.push(LoopEnv { start: self.current, total_envs_at_loop_creation: self.scopes.len(),
+                                  iterations: 0, for_each: Some(for_each) });
+        self.next_for_each_iteration();
+        Ok(())
+    }
+
+    // Assumes current is at Stmt::BlockStart of ফরইচ loop body. Creates body scope with loop
+    // variable set to next element, if all elements were used loop is finished
+    fn next_for_each_iteration(&mut self) {
+        let list_index = match self.scopes.last() {
+            Some(scope) => match scope.get(FOR_EACH_LIST) {
+                Some(Some(DataType::List(list_index))) => *list_index,
+                _ => unreachable!("ফরইচ list scope must be last scope when starting iteration"),
+            },
+            None => unreachable!(),
+        };
+        let last_loop_env_index = self.loops.len() - 1;
+        let for_each = self.loops[last_loop_env_index].for_each.as_mut().unwrap();
+
+        // list is read on every iteration, so changes made to list inside loop are seen
+        match self.lists[list_index].get(for_each.next) {
+            Some(elem) => {
+                let mut body_scope: HashMap<String, Option<DataType>> = HashMap::new();
+                body_scope.insert(for_each.var_name.clone(), Some(elem.clone()));
+                for_each.next += 1;
+                // body scope is already created, so Stmt::BlockStart is skipped
+                self.current += 1;
+                self.scopes.push(body_scope);
+            },
+            None => {
+                self.loops.pop();
+                // destroying scope holding iterated list
+                self.scopes.pop();
+                self.skip_loop_body();
+            },
+        }
+    }
+
     fn interpret_print_no_eol(&mut self, expr: parser::Expr) -> Result<(), PakhiErr> {
         match self.interpret_expr(expr)? {
             DataType::Num(n) => {
-                let num = self.to_bn_num(n)?;
+                let num = self.num_to_output_string(n)?;
                 self.io.print( num.as_str())
             },
             DataType::Bool(b) => self.io.print( self.to_bn_bool(b).as_str()),
-            DataType::String(s) => self.io.print(s.as_str()),
+            DataType::String(s) => self.io.print(&s),
             DataType::List(arr_i) => {
                 let mut elems: Vec<(usize, DataType)>  = Vec::new();
                 for (i, elem) in self.lists[arr_i].iter().enumerate() {
@@ -223,7 +600,7 @@ impl<'a, T: 'a + IO> Interpreter<'a, T> {
                 self.io.print("]");
             },
             DataType::NamelessRecord(record_i) => {
-                let nameless_record = self.nameless_records.get(record_i).unwrap().clone();
+                let nameless_record = self.sorted_record_entries(record_i);
                 self.io.print("@{");
                 for (k, v) in nameless_record {
                     self.io.print(&*format!("\"{}\":", k));
@@ -242,14 +619,24 @@ impl<'a, T: 'a + IO> Interpreter<'a, T> {
         Ok(())
     }
 
+    // Record entries sorted by key, so that printed record doesn't depend on HashMap
+    // iteration order or on which slot record occupies
+    fn sorted_record_entries(&self, record_i: usize) -> Vec<(String, DataType)> {
+        let mut entries: Vec<(String, DataType)> = self.nameless_records[record_i].iter()
+            .map(|(k, v)| (k.clone(), v.clone()))
+            .collect();
+        entries.sort_by(|a, b| a.0.cmp(&b.0));
+        entries
+    }
+
     fn print_datatype(&mut self, data: DataType) -> Result<(), PakhiErr> {
         match data {
             DataType::Num(n) => {
-                let num = self.to_bn_num(n)?;
+                let num = self.num_to_output_string(n)?;
                 self.io.print( num.as_str());
             },
             DataType::Bool(b) => self.io.print( self.to_bn_bool(b).as_str()),
-            DataType::String(s) => self.io.print(s.as_str()),
+            DataType::String(s) => self.io.print(&s),
             DataType::List(arr_i) => {
                 let mut elems: Vec<(usize, DataType)>  = Vec::new();
                 for (i, elem) in self.lists[arr_i].iter().enumerate() {
@@ -265,7 +652,7 @@ impl<'a, T: 'a + IO> Interpreter<'a, T> {
                 self.io.print("]");
             },
             DataType::NamelessRecord(record_i) => {
-                let nameless_record = self.nameless_records.get(record_i).unwrap().clone();
+                let nameless_record = self.sorted_record_entries(record_i);
                 self.io.print("@{");
                 for (k, v) in nameless_record {
                     self.io.print(&*format!("\"{}\":", k));
@@ -285,11 +672,11 @@ impl<'a, T: 'a + IO> Interpreter<'a, T> {
     fn interpret_print_stmt(&mut self, expr: parser::Expr) -> Result<(), PakhiErr> {
         match self.interpret_expr(expr)? {
             DataType::Num(n) => {
-                let num = self.to_bn_num(n)?;
+                let num = self.num_to_output_string(n)?;
                 self.io.println(num.as_str())
             },
             DataType::Bool(b) => self.io.println(self.to_bn_bool(b).as_str()),
-            DataType::String(s) => self.io.println( s.as_str()),
+            DataType::String(s) => self.io.println( &s),
             DataType::List(arr_i) => {
                 let mut elems: Vec<(usize, DataType)>  = Vec::new();
                 for (i, elem) in self.lists[arr_i].iter().enumerate() {
@@ -305,7 +692,7 @@ impl<'a, T: 'a + IO> Interpreter<'a, T> {
                 self.io.println("]");
             },
             DataType::NamelessRecord(record_i) => {
-                let nameless_record = self.nameless_records.get(record_i).unwrap().clone();
+                let nameless_record = self.sorted_record_entries(record_i);
                 self.io.print("@{");
                 for (k, v) in nameless_record {
                     self.io.print(&*format!("\"{}\":", k));
@@ -338,9 +725,14 @@ impl<'a, T: 'a + IO> Interpreter<'a, T> {
 
     fn create_new_var(&mut self, var_key: String, assign_stmt: parser::Assignment) -> Result<(), PakhiErr>
     {
+        if self.strict_redeclaration && self.scopes[self.scopes.len() - 1].contains_key(&var_key) {
+            let (line, file_name) = self.extract_err_meta_stmt(self.current)?;
+            return Err(RuntimeError(line, file_name, format!("Variable was already declared in this scope {:#}", var_key)));
+        }
         match assign_stmt.init_value {
             Some(expr) => {
                 let init_value = self.interpret_expr(expr)?;
+                let init_value = self.copy_if_value_semantics(init_value);
 
                 let env_i = self.scopes.len() - 1;
                 let current_env = &mut self.scopes[env_i];
@@ -355,26 +747,107 @@ impl<'a, T: 'a + IO> Interpreter<'a, T> {
         Ok(())
     }
 
+    // Marks list or record and everything reachable from it as frozen
+    fn freeze(&mut self, data: &DataType) {
+        match data {
+            DataType::List(i)
+                // already frozen lists are skipped, so cyclic lists terminate
+                if self.frozen_lists.insert(*i) => {
+                    for elem in self.lists[*i].clone() {
+                        self.freeze(&elem);
+                    }
+                },
+            DataType::NamelessRecord(i)
+                if self.frozen_records.insert(*i) => {
+                    for elem in self.nameless_records[*i].values().cloned().collect::<Vec<DataType>>() {
+                        self.freeze(&elem);
+                    }
+                },
+            _ => {},
+        }
+    }
+
+    fn check_not_frozen(&self, data: &DataType) -> Result<(), PakhiErr> {
+        let message = match data {
+            DataType::List(i) if self.frozen_lists.contains(i) => "Frozen list can't be modified",
+            DataType::NamelessRecord(i) if self.frozen_records.contains(i) => "Frozen record can't be modified",
+            _ => return Ok(()),
+        };
+        let (line, file_name) = self.extract_err_meta_stmt(self.current)?;
+        Err(RuntimeError(line, file_name, message.to_string()))
+    }
+
+    fn copy_if_value_semantics(&mut self, data: DataType) -> DataType {
+        if !self.value_semantics {
+            return data;
+        }
+        self.deep_copy(data, &mut HashMap::new(), &mut HashMap::new())
+    }
+
+    // Copies list or record with everything reachable from it. copied_lists and copied_records
+    // map original index to copy, so shared and cyclic elements are copied only once
+    fn deep_copy(&mut self, data: DataType,
+                 copied_lists: &mut HashMap<usize, DataType>,
+                 copied_records: &mut HashMap<usize, DataType>) -> DataType
+    {
+        match data {
+            DataType::List(i) => {
+                if let Some(copy) = copied_lists.get(&i) {
+                    return copy.clone();
+                }
+                let copy = self.create_new_list_datatype(Vec::new());
+                copied_lists.insert(i, copy.clone());
+                let mut elems = Vec::new();
+                for elem in self.lists[i].clone() {
+                    elems.push(self.deep_copy(elem, copied_lists, copied_records));
+                }
+                if let DataType::List(copy_i) = copy {
+                    self.total_allocated_object_count += elems.len();
+                    self.lists[copy_i] = elems;
+                }
+                copy
+            },
+            DataType::NamelessRecord(i) => {
+                if let Some(copy) = copied_records.get(&i) {
+                    return copy.clone();
+                }
+                let copy = self.create_new_nameless_record_datatype(HashMap::new());
+                copied_records.insert(i, copy.clone());
+                let mut entries = HashMap::new();
+                for (k, v) in self.nameless_records[i].clone() {
+                    entries.insert(k, self.deep_copy(v, copied_lists, copied_records));
+                }
+                if let DataType::NamelessRecord(copy_i) = copy {
+                    self.total_allocated_object_count += entries.len();
+                    self.nameless_records[copy_i] = entries;
+                }
+                copy
+            },
+            _ => data,
+        }
+    }
+
     fn reassign_to_old_var(&mut self, var_key: String,
                            assign_stmt: parser::Assignment) -> Result<(), PakhiErr>
     {
         let init_expr = assign_stmt.init_value.clone().unwrap();
         let init_value = self.interpret_expr(init_expr)?;
 
-        // if variable wasn't found it evaluates to any negative number
-        let var_found_at_env_index: i32 = self.find_var_env_index(var_key.clone(), assign_stmt.init_value.clone());
-
-        if var_found_at_env_index >= 0 {
-            if assign_stmt.indexes.is_empty() {
-                // only simple variable assignment
-                self.scopes[var_found_at_env_index as usize].insert(var_key, Some(init_value));
-            } else {
-                // assignment to element in a list or record
-                self.reassign_to_list_or_record(assign_stmt, var_key, var_found_at_env_index, init_value)?;
-            }
-        } else {
-            let (line, file_name) = self.extract_err_meta_stmt(self.current)?;
-            return Err(RuntimeError(line, file_name, format!("Variable wasn't declared {:#}", var_key)));
+        match self.find_var_env_index(&var_key) {
+            Some(var_found_at_env_index) => {
+                if assign_stmt.indexes.is_empty() {
+                    // only simple variable assignment
+                    let init_value = self.copy_if_value_semantics(init_value);
+                    self.scopes[var_found_at_env_index].insert(var_key, Some(init_value));
+                } else {
+                    // assignment to element in a list or record
+                    self.reassign_to_list_or_record(assign_stmt, var_key, var_found_at_env_index, init_value)?;
+                }
+            },
+            None => {
+                let (line, file_name) = self.extract_err_meta_stmt(self.current)?;
+                return Err(RuntimeError(line, file_name, format!("Variable wasn't declared {:#}", var_key)));
+            },
         }
         Ok(())
     }
@@ -383,25 +856,40 @@ impl<'a, T: 'a + IO> Interpreter<'a, T> {
     fn reassign_to_list_or_record(&mut self,
                                   assign_stmt: parser::Assignment,
                                   var_key: String,
-                                  var_found_at_env_index: i32,
+                                  var_found_at_env_index: usize,
                                   init_value: DataType) -> Result<(), PakhiErr>
     {
+        if let Some(parser::Expr::Primary(parser::Primary::List(elems), _, _)) = assign_stmt.indexes.last() {
+            if let Some(parser::Expr::Range(start, end, line, file_name)) = elems.first() {
+                if assign_stmt.indexes.len() != 1 {
+                    return Err(RuntimeError(*line, file_name.clone(),
+                                            "Slice assignment is only supported for one dimensional list".to_string()));
+                }
+                let start = self.interpret_expr(*start.clone())?;
+                let end = self.interpret_expr(*end.clone())?;
+                let var = self.get_var_from_env(var_key.as_str(), var_found_at_env_index);
+                if let Some(var) = &var {
+                    self.check_not_frozen(var)?;
+                }
+                return self.list_slice_assign(var, start, end, init_value);
+            }
+        }
+
         // effective_index is index of deepest nested array, to which init_val will be assigned
         let effective_index = self.interpret_expr(assign_stmt.indexes.last().unwrap().clone())?;
         let evaluated_indexes: Vec<Index> = self.evaluate_all_indexes(assign_stmt.indexes.clone())?;
 
-        let var = self.get_var_from_env(var_key.as_str(), var_found_at_env_index as usize);
+        let var = self.get_var_from_env(var_key.as_str(), var_found_at_env_index);
+        let init_value = self.intern_string(init_value);
+        self.gc_write_barrier(&init_value);
 
+        if let (Some(var), 1) = (&var, assign_stmt.indexes.len()) {
+            self.check_not_frozen(var)?;
+        }
         match var {
             Some(DataType::List(i)) => {
-                if assign_stmt.indexes.len() == 1 {
-                    // single dimensional list
-                    // changing list element at only one level deep
-                    self.list_single_dim_assign(i, effective_index, init_value)?;
-                } else {
-                    // multidimensional array so need to traverse nested list ore record
-                    self.list_multi_dim_assign(i, evaluated_indexes, init_value.clone())?;
-                }
+                // single dimensional list is also assigned by traversing, so that index is bounds checked
+                self.multi_dim_assign(DataType::List(i), evaluated_indexes, init_value.clone())?;
             },
             Some(DataType::NamelessRecord(record_ref)) => {
                 if assign_stmt.indexes.len() == 1 {
@@ -410,7 +898,7 @@ impl<'a, T: 'a + IO> Interpreter<'a, T> {
                     self.record_single_dim_assign(record_ref, effective_index, init_value)?;
                 } else {
                     // multidimensional array so need to traverse nested list ore record
-                    self.record_multi_dim_assign(record_ref, evaluated_indexes, init_value.clone())?;
+                    self.multi_dim_assign(DataType::NamelessRecord(record_ref), evaluated_indexes, init_value.clone())?;
                 }
             },
             _ => {
@@ -421,32 +909,41 @@ impl<'a, T: 'a + IO> Interpreter<'a, T> {
         Ok(())
     }
 
-    fn list_single_dim_assign(&mut self,
-                              list_ref: usize,
-                              index: DataType,
-                              init_value: DataType) -> Result<(), PakhiErr>
+    // Replaces elements from start to end (excluding end) with elements of replacement list,
+    // replacement list can be shorter or longer than replaced range
+    fn list_slice_assign(&mut self,
+                         list: Option<DataType>,
+                         start: DataType,
+                         end: DataType,
+                         replacement: DataType) -> Result<(), PakhiErr>
     {
-        match index {
-            DataType::List(j) => {
-                let a = self.lists[j].clone();
-                match a[0].clone() {
-                    DataType::Num(n) => {
-                        let list = self.lists.get_mut(list_ref).unwrap();
-                        list[n as usize] = init_value
-                    },
-                    _ => {
-                        let (line, file_name) = self.extract_err_meta_stmt(self.current)?;
-                        return Err(RuntimeError(line, file_name,
-                                         "List must be indexed with number type".to_string()));
-                    },
-                }
-            },
-            _ => {
-                let (line, file_name) = self.extract_err_meta_stmt(self.current)?;
-                return Err(RuntimeError(line, file_name,
-                                        "Unexpected error while assigning to a index".to_string()));
-            },
+        let (line, file_name) = self.extract_err_meta_stmt(self.current)?;
+        let list_ref = match list {
+            Some(DataType::List(list_ref)) => list_ref,
+            _ => return Err(TypeError(line, file_name, "Only list datatype supports slice assignment".to_string())),
+        };
+        let replacement = match replacement {
+            DataType::List(replacement_ref) => self.lists[replacement_ref].clone(),
+            _ => return Err(TypeError(line, file_name, "Slice can only be assigned a list".to_string())),
+        };
+        let (start, end) = match (start, end) {
+            (DataType::Num(start), DataType::Num(end)) => (start, end),
+            _ => return Err(RuntimeError(line, file_name, "List must be indexed with number type".to_string())),
+        };
+        // casting negative or fractional bound to usize would silently change it
+        if start < 0.0 || end < 0.0 || start.fract() != 0.0 || end.fract() != 0.0 {
+            return Err(RuntimeError(line, file_name, format!("Slice range {}..{} must be whole numbers",
+                                                             self.to_bn_num(start)?, self.to_bn_num(end)?)));
         }
+        let (start, end) = (start as usize, end as usize);
+        if start > end || end > self.lists[list_ref].len() {
+            return Err(RuntimeError(line, file_name, "Slice range is out of list bounds".to_string()));
+        }
+
+        for elem in replacement.iter() {
+            self.gc_write_barrier(elem);
+        }
+        self.lists[list_ref].splice(start..end, replacement);
         Ok(())
     }
 
@@ -462,7 +959,7 @@ impl<'a, T: 'a + IO> Interpreter<'a, T> {
                     DataType::String(key) => {
                         let record = self.nameless_records
                                                                 .get_mut(record_ref).unwrap();
-                        record.insert(key, init_value);
+                        record.insert(key.to_string(), init_value);
                     },
                     _ => {
                         let (line, file_name) = self.extract_err_meta_stmt(self.current)?;
@@ -480,152 +977,79 @@ impl<'a, T: 'a + IO> Interpreter<'a, T> {
         Ok(())
     }
 
-    fn list_multi_dim_assign(&mut self,
-                             list_reference: usize,
-                             evaluated_indexes: Vec<Index>,
-                             init_value: DataType) -> Result<(), PakhiErr>
+    // Traverses nested lists and records through all indexes except last one, then assigns
+    // init_value at last index. Errors show the index path up to where traversal failed
+    fn multi_dim_assign(&mut self,
+                        container: DataType,
+                        evaluated_indexes: Vec<Index>,
+                        init_value: DataType) -> Result<(), PakhiErr>
     {
-        let list = self.lists.get_mut(list_reference).unwrap();
-
-        match evaluated_indexes.get(0).unwrap() {
-            Index::List(list_ref) => {
-                let mut assignee: DataType = list.get(list_ref.clone()).unwrap().clone();
-
-                for i in 1..evaluated_indexes.len() {
-                    if i == evaluated_indexes.len() - 1 {
-                        match assignee {
-                            DataType::List(arr_i) => {
-                                //let a = self.arrays.get_mut(arr_i).unwrap();
-                                let index = evaluated_indexes.get(i).unwrap();
-                                match index {
-                                    Index::List(i) => {
-                                        self.lists[arr_i][i.clone()] = init_value.clone();
-                                        break;
-                                    },
-                                    _ => {
-                                        let (line, file_name) = self.extract_err_meta_stmt(self.current)?;
-                                        return Err(RuntimeError(line, file_name, "Error on assignment to index".to_string()));
-                                    }
-                                }
-                            }
-                            _ => {
-                                let (line, file_name) = self.extract_err_meta_stmt(self.current)?;
-                                return Err(RuntimeError(line, file_name, "Cannot assign at index if datatype is not list".to_string()));
-                            },
-                        }
-                    } else {
-                        match assignee {
-                            DataType::List(arr_i) => {
-                                let a = self.lists.get_mut(arr_i).unwrap();
-                                let index = evaluated_indexes.get(i).unwrap();
-                                match index {
-                                    Index::List(i) => {
-                                        assignee = a.get(i.clone()).unwrap().clone();
-                                    },
-                                    _ => {
-                                        let (line, file_name) = self.extract_err_meta_stmt(self.current)?;
-                                        return Err(RuntimeError(line, file_name, "Error on assignment to index".to_string()));
-                                    },
-                                }
-                            },
-                            _ => {
-                                let (line, file_name) = self.extract_err_meta_stmt(self.current)?;
-                                return Err(RuntimeError(line, file_name, "Cannot index if datatype not list".to_string()));
-                            },
-                        }
+        let mut assignee = container;
+        let last = evaluated_indexes.len() - 1;
+
+        for (depth, index) in evaluated_indexes.iter().enumerate() {
+            match (&assignee, index) {
+                (DataType::List(list_i), Index::List(i)) => {
+                    let list_i = *list_i;
+                    if *i >= self.lists[list_i].len() {
+                        return Err(self.assignment_path_err("List index out of range at", &evaluated_indexes[..=depth])?);
                     }
-                }
-            },
-            _ => {
-                let (line, file_name) = self.extract_err_meta_stmt(self.current)?;
-                return Err(RuntimeError(line, file_name, "Only list and record datatype can be indexed".to_string()));
-            },
-        }
-
-        Ok(())
-    }
-
-    fn record_multi_dim_assign(&mut self,
-                               record_reference: usize,
-                               evaluated_indexes: Vec<Index>,
-                               init_value: DataType) -> Result<(), PakhiErr>
-    {
-        let record = self.nameless_records.get_mut(record_reference).unwrap();
-
-        match evaluated_indexes.get(0).unwrap() {
-            Index::NamelessRecord(key) => {
-                let mut assignee: DataType = record.get(key).unwrap().clone();
-
-                for i in 1..evaluated_indexes.len() {
-                    if i == evaluated_indexes.len() - 1 {
-                        match assignee {
-                            DataType::NamelessRecord(record_i) => {
-                                let index = evaluated_indexes.get(i).unwrap();
-                                match index {
-                                    Index::NamelessRecord(k) => {
-                                        self.nameless_records[record_i].insert(k.clone(), init_value);
-                                        break;
-                                    }
-                                    _ => {
-                                        let (line, file_name) = self.extract_err_meta_stmt(self.current)?;
-                                        return Err(RuntimeError(line, file_name,
-                                                     "Error on assignment to index".to_string()));
-                                    }
-                                }
-                            }
-                            _ => {
-                                let (line, file_name) = self.extract_err_meta_stmt(self.current)?;
-                                return Err(RuntimeError(line, file_name,
-                                   "Cannot assign at index if datatype is not record".to_string()));
-                            },
-                        }
-                    } else {
-                        match assignee {
-                            DataType::NamelessRecord(record_i) => {
-                                let r = self.nameless_records.get_mut(record_i).unwrap();
-                                let index = evaluated_indexes.get(i).unwrap();
-                                match index {
-                                    Index::NamelessRecord(k) => {
-                                        assignee = r.get(k).unwrap().clone();
-                                    },
-                                    _ => {
-                                        let (line, file_name) = self.extract_err_meta_stmt(self.current)?;
-                                        return Err(RuntimeError(line, file_name,
-                                     "Cannot assign at index if datatype is not record".to_string()));
-                                    },
-                                }
-                            }
-                            _ => {
-                                let (line, file_name) = self.extract_err_meta_stmt(self.current)?;
-                                return Err(RuntimeError(line, file_name, "Cannot assign at index if datatype is not record".to_string()));
-                            },
-                        }
+                    if depth == last {
+                        self.check_not_frozen(&assignee)?;
+                        self.lists[list_i][*i] = init_value;
+                        return Ok(());
                     }
-                }
+                    assignee = self.lists[list_i][*i].clone();
+                },
+                (DataType::NamelessRecord(record_i), Index::NamelessRecord(key)) => {
+                    let record_i = *record_i;
+                    if depth == last {
+                        self.check_not_frozen(&assignee)?;
+                        self.nameless_records[record_i].insert(key.clone(), init_value);
+                        return Ok(());
+                    }
+                    match self.nameless_records[record_i].get(key) {
+                        Some(data) => assignee = data.clone(),
+                        None => return Err(self.assignment_path_err("Record key not found at", &evaluated_indexes[..=depth])?),
+                    }
+                },
+                (DataType::List(_), Index::NamelessRecord(_)) => {
+                    return Err(self.assignment_path_err("List can't be indexed with string at", &evaluated_indexes[..=depth])?);
+                },
+                (DataType::NamelessRecord(_), Index::List(_)) => {
+                    return Err(self.assignment_path_err("Record can't be indexed with number at", &evaluated_indexes[..=depth])?);
+                },
+                _ => {
+                    return Err(self.assignment_path_err("Only list and record datatype can be indexed at", &evaluated_indexes[..=depth])?);
+                },
             }
-            _ => {
-                let (line, file_name) = self.extract_err_meta_stmt(self.current)?;
-                return Err(RuntimeError(line, file_name, "Only list and record datatype can be indexed".to_string()));
-            },
         }
 
         Ok(())
     }
 
-    fn find_var_env_index(&mut self, var_key: String, init_value: Option<parser::Expr>) -> i32 {
-        // if var was found at env returns its scope index
-        // if not found return any integer
-        let mut var_found_at_env_index: i32 = (self.scopes.len() - 1) as i32;
+    // Error for assignment through indexes, message is followed by index path like [১]["ক"]
+    fn assignment_path_err(&self, message: &str, indexes: &[Index]) -> Result<PakhiErr, PakhiErr> {
+        let path = self.index_path(indexes)?;
+        let (line, file_name) = self.extract_err_meta_stmt(self.current)?;
+        Ok(RuntimeError(line, file_name, format!("{} {}", message, path)))
+    }
 
-        for env in self.scopes.iter().rev() {
-            if env.contains_key(&var_key) && init_value.is_some() {
-                break;
-            } else {
-                var_found_at_env_index -= 1;
+    fn index_path(&self, indexes: &[Index]) -> Result<String, PakhiErr> {
+        let mut path = String::new();
+        for index in indexes {
+            match index {
+                Index::List(i) => path.push_str(&format!("[{}]", self.to_bn_num(*i as f64)?)),
+                Index::NamelessRecord(key) => path.push_str(&format!("[\"{}\"]", key)),
             }
         }
+        Ok(path)
+    }
 
-        return var_found_at_env_index;
+    // Returns index of innermost scope where variable was declared, None if it wasn't
+    // declared in any scope
+    fn find_var_env_index(&self, var_key: &str) -> Option<usize> {
+        self.scopes.iter().rposition(|env| env.contains_key(var_key))
     }
 
     fn get_var_from_env(&mut self, var_name: &str, env_index: usize) -> Option<DataType> {
@@ -640,8 +1064,15 @@ impl<'a, T: 'a + IO> Interpreter<'a, T> {
             match  index {
                 DataType::List(arr_i) => {
                     match self.lists[arr_i][0].clone() {
+                        // casting negative or fractional index to usize would silently change it
+                        DataType::Num(n) if n < 0.0 || n.fract() != 0.0 => {
+                            let path = self.index_path(&evaluated_index_exprs)?;
+                            let (line, file_name) = self.extract_err_meta_stmt(self.current)?;
+                            return Err(RuntimeError(line, file_name, format!("List index must be a whole number at {}[{}]",
+                                                                             path, self.to_bn_num(n)?)));
+                        },
                         DataType::Num(i) => evaluated_index_exprs.push(Index::List(i as usize)),
-                        DataType::String(key) => evaluated_index_exprs.push(Index::NamelessRecord(key)),
+                        DataType::String(key) => evaluated_index_exprs.push(Index::NamelessRecord(key.to_string())),
                         _ => {
                             let (line, file_name) = self.extract_expr_err_meta(&index_exprs[i]);
                             return Err(RuntimeError(line, file_name, "Index must be of number or string type".to_string()));
@@ -663,7 +1094,7 @@ impl<'a, T: 'a + IO> Interpreter<'a, T> {
         self.current += 1;
 
         if let parser::Stmt::Expression(parser::Expr::Call(function, _, _),
-                                        line, file_name) = self.statements[self.current].clone()
+                                        line, file_name) = self.statement(self.current).clone()
         {
             match *function.expr {
                 parser::Expr::Primary(parser::Primary::Var(func_token), line, file_name) => {
@@ -685,6 +1116,7 @@ impl<'a, T: 'a + IO> Interpreter<'a, T> {
                     let func = Func {
                         starting_statement: self.current + 1,
                         args: func_args_name,
+                        memo: None,
                     };
 
                     let current_env_i = self.scopes.len() - 1;
@@ -707,11 +1139,11 @@ impl<'a, T: 'a + IO> Interpreter<'a, T> {
         self.skip_block()?;
 
         // consuming return statement
-        if self.current >= self.statements.len() {
+        if !self.statement_exists(self.current) {
             let (line, file_name) = self.extract_err_meta_stmt(self.statements.len() - 1)?;
             return Err(RuntimeError(line, file_name, "Unexpected error at function call".to_string()));
         }
-        if let parser::Stmt::Return(_, _, _) = self.statements[self.current].clone() {
+        if let parser::Stmt::Return(_, _, _) = self.statement(self.current).clone() {
            self.current += 1;
         } else {
             let (line, file_name) = self.extract_err_meta_stmt(self.statements.len() - 1)?;
@@ -763,12 +1195,12 @@ impl<'a, T: 'a + IO> Interpreter<'a, T> {
     fn skip_block(&mut self) -> Result<(), PakhiErr> {
         let mut stack: Vec<char> = Vec::new();
 
-        while self.current < self.statements.len() {
-            if let parser::Stmt::BlockStart(_, _) = self.statements[self.current] {
+        while self.statement_exists(self.current) {
+            if let parser::Stmt::BlockStart(_, _) = *self.statement(self.current) {
                 stack.push('{');
             }
 
-            if let parser::Stmt::BlockEnd(_, _) = self.statements[self.current] {
+            if let parser::Stmt::BlockEnd(_, _) = *self.statement(self.current) {
                 let previous = stack.pop();
                 match previous {
                     Some(_) => {
@@ -795,7 +1227,7 @@ impl<'a, T: 'a + IO> Interpreter<'a, T> {
     fn skip_block_in_if(&mut self) -> Result<(), PakhiErr> {
         self.skip_block()?;
 
-        match self.statements[self.current] {
+        match *self.statement(self.current) {
             parser::Stmt::Else(_, _) => {},
             _ => { self.previous_if_was_executed.pop(); },
         }
@@ -804,71 +1236,63 @@ impl<'a, T: 'a + IO> Interpreter<'a, T> {
     }
 
     fn interpret_expr(&mut self, expr: parser::Expr) -> Result<DataType, PakhiErr> {
-        match expr {
-            parser::Expr::Primary(p, _, _) => {
-                let expr = self.interpret_primary_expr(p)?;
-                return Ok(expr);
+        let value = match expr {
+            parser::Expr::Primary(p, _, _) => self.interpret_primary_expr(p)?,
+            parser::Expr::Unary(u_expr, _, _) => self.interpret_unary_expr(u_expr)?,
+            parser::Expr::And(and_expr, _, _) => self.interpret_and_expr(and_expr)?,
+            parser::Expr::Or(or_expr, _, _) => self.interpret_or_expr(or_expr)?,
+            parser::Expr::Equality(eq_expr, _, _) => self.interpret_eq_expr(eq_expr)?,
+            parser::Expr::Comparison(comp_expr, _, _) => self.interpret_comp_expr(comp_expr)?,
+            parser::Expr::AddOrSub(addsub_expr, _, _) => self.interpret_addsub_expr(addsub_expr)?,
+            parser::Expr::MulOrDivOrRemainder(muldiv_expr, _, _) => self.interpret_muldiv_remainder_expr(muldiv_expr)?,
+            parser::Expr::Call(function, _, _) => self.interpret_func_call_expr(function)?,
+            parser::Expr::Indexing(identifier, i, _, _) => self.interpret_indexing(identifier, i)?,
+            parser::Expr::Range(_, _, line, file_name) => {
+                return Err(RuntimeError(line, file_name, "Range can only be used for list slice assignment".to_string()));
+            },
+        };
+        self.keep_temporary(&value);
+        Ok(value)
+    }
+
+    fn interpret_indexing(&mut self,
+                          identifier_expr: Box<parser::Expr>,
+                          index_expr: Box<parser::Expr>) -> Result<DataType, PakhiErr>
+    {
+        let (line, file_name) = self.extract_expr_err_meta(&*index_expr);
+
+        let identifier = self.interpret_expr(*identifier_expr)?;
+        let index = self.interpret_expr(*index_expr)?;
+
+        match (identifier, index) {
+            (DataType::List(arr_i), DataType::Num(i)) => {
+                let len = self.lists[arr_i].len();
+                // negative index is checked separately because casting it to usize gives 0
+                if i < 0.0 || i as usize >= len {
+                    return Err(RuntimeError(line, file_name, format!("List index {} out of range, list length is {}",
+                                                                     self.to_bn_num(i)?, self.to_bn_num(len as f64)?)));
+                }
+                Ok(self.lists[arr_i][i as usize].clone())
             },
-            parser::Expr::Unary(u_expr, _, _) => {
-                let expr = self.interpret_unary_expr(u_expr)?;
-                return Ok(expr);
-            },
-            parser::Expr::And(and_expr, _, _) => {
-                let expr = self.interpret_and_expr(and_expr)?;
-                return Ok(expr);
-            },
-            parser::Expr::Or(or_expr, _, _) => {
-                let expr = self.interpret_or_expr(or_expr)?;
-                return Ok(expr);
-            },
-            parser::Expr::Equality(eq_expr, _, _) => {
-                let expr = self.interpret_eq_expr(eq_expr)?;
-                return Ok(expr);
-            },
-            parser::Expr::Comparison(comp_expr, _, _) => {
-                let expr = self.interpret_comp_expr(comp_expr)?;
-                return Ok(expr);
-            },
-            parser::Expr::AddOrSub(addsub_expr, _, _) => {
-                let expr = self.interpret_addsub_expr(addsub_expr)?;
-                return Ok(expr);
-            },
-            parser::Expr::MulOrDivOrRemainder(muldiv_expr, _, _) => {
-                let expr = self.interpret_muldiv_remainder_expr(muldiv_expr)?;
-                return Ok(expr);
-            },
-            parser::Expr::Call(function, _, _) => {
-                let expr = self.interpret_func_call_expr(function)?;
-                return Ok(expr);
-            },
-            parser::Expr::Indexing(identifier, i, _, _) => {
-                let expr = self.interpret_indexing(identifier, i)?;
-                return Ok(expr);
-            },
-        }
-    }
-
-    fn interpret_indexing(&mut self,
-                          identifier_expr: Box<parser::Expr>,
-                          index_expr: Box<parser::Expr>) -> Result<DataType, PakhiErr>
-    {
-        let (line, file_name) = self.extract_expr_err_meta(&*index_expr);
-
-        let identifier = self.interpret_expr(*identifier_expr)?;
-        let index = self.interpret_expr(*index_expr)?;
-
-        match (identifier, index) {
-            (DataType::List(arr_i), DataType::Num(i)) => {
-                let arr = self.lists[arr_i].clone();
-                return Ok(arr[i as usize].clone());
+            (DataType::String(s), DataType::Num(i)) => {
+                // indexing by unicode scalar value, byte index would split multi-byte bangla characters
+                match s.chars().nth(i as usize) {
+                    Some(c) if i >= 0.0 => Ok(DataType::String(c.to_string().into())),
+                    _ => {
+                        let len = s.chars().count();
+                        Err(RuntimeError(line, file_name, format!("String index {} out of range, string length is {}",
+                                                                         self.to_bn_num(i)?, self.to_bn_num(len as f64)?)))
+                    },
+                }
             },
             (DataType::NamelessRecord(record_i), DataType::String(key)) => {
-                let nameless_record = self.nameless_records[record_i].clone();
-                let record_data = nameless_record.get(&*key).unwrap().clone();
-                return Ok(record_data);
+                match self.nameless_records[record_i].get(&*key) {
+                    Some(record_data) => Ok(record_data.clone()),
+                    None => Err(RuntimeError(line, file_name, format!("Record key \"{}\" not found", key))),
+                }
             },
             (_, DataType::Num(_)) => {
-                return Err(RuntimeError(line, file_name, "Only list supports indexing with number".to_string()));
+                Err(RuntimeError(line, file_name, "Only list and string supports indexing with number".to_string()))
             },
             (DataType::List(_), _) => {
                 return Err(TypeError(line, file_name, "List index must of number type".to_string()));
@@ -885,6 +1309,17 @@ impl<'a, T: 'a + IO> Interpreter<'a, T> {
         }
     }
 
+    // Built-ins that can run arbitrary code or change file system, _ফাইল-খোলো is only
+    // blocked when file is opened for writing
+    fn blocked_in_sandbox(name: &str, arguments: &[DataType]) -> bool {
+        match name {
+            "_ইভাল" | "_ডাইরেক্টরি-বদল" | "_রাইট-ফাইল" | "_রাইট-ফাইল-বাইট" | "_ডিলিট-ফাইল"
+            | "_নতুন-ডাইরেক্টরি" | "_ডিলিট-ডাইরেক্টরি" | "_ডিলিট-ডাইরেক্টরি-খালি" | "_টেম্প-ফাইল" => true,
+            "_ফাইল-খোলো" => !matches!(arguments.get(1), Some(DataType::String(mode)) if &**mode == "পড়া"),
+            _ => false,
+        }
+    }
+
     fn call_built_in_function(&mut self, f: &parser::FunctionCall, func_token: &Token) -> Result<DataType, PakhiErr> {
         let mut evaluated_arguments: Vec<DataType> = Vec::new();
         // Evaluating all arguments
@@ -892,19 +1327,700 @@ impl<'a, T: 'a + IO> Interpreter<'a, T> {
             let e_a = self.interpret_expr(arg.clone())?;
             evaluated_arguments.push(e_a);
         }
+        let built_in_name = self.built_in_functions.get_name(&func_token.lexeme);
+        if self.sandbox && Self::blocked_in_sandbox(&built_in_name, &evaluated_arguments) {
+            return Err(RuntimeError(func_token.line, func_token.src_file_path.clone(),
+                                    format!("{}() function is not available in sandbox mode", built_in_name)));
+        }
         // Finding out which built-in function and executing that accordingly
-        match self.built_in_functions.get_name(&func_token.lexeme).as_str() {
+        match built_in_name.as_str() {
             "_স্ট্রিং" => {
-                match BuiltInFunctionList::_to_string(evaluated_arguments) {
+                match BuiltInFunctionList::_to_string(evaluated_arguments, self.ascii_digits) {
+                    Ok(result_data) => Ok(result_data),
+                    Err(err) => {
+                        let (line, file_name) = self.extract_err_meta_stmt(self.current)?;
+                        return Err(RuntimeError(line, file_name, err));
+                    }
+                }
+            },
+            "_সংখ্যা" => {
+                match  BuiltInFunctionList::_to_num(evaluated_arguments) {
+                    Ok(result_data) => Ok(result_data),
+                    Err(err) => {
+                        let (line, file_name) = self.extract_err_meta_stmt(self.current)?;
+                        Err(RuntimeError(line, file_name, err))
+                    }
+                }
+            },
+            "_ট্রাই-সংখ্যা" => {
+                match BuiltInFunctionList::_try_num(evaluated_arguments) {
+                    Ok(record) => Ok(self.create_new_nameless_record_datatype(record)),
+                    Err(err) => {
+                        let (line, file_name) = self.extract_err_meta_stmt(self.current)?;
+                        return Err(RuntimeError(line, file_name, err));
+                    }
+                }
+            },
+            "_অ্যাবস" => {
+                match BuiltInFunctionList::_abs(evaluated_arguments) {
+                    Ok(result_data) => Ok(result_data),
+                    Err(err) => {
+                        let (line, file_name) = self.extract_err_meta_stmt(self.current)?;
+                        Err(RuntimeError(line, file_name, err))
+                    }
+                }
+            },
+            name @ ("_ম্যাক্স" | "_মিন") => {
+                let call_result = match name {
+                    "_ম্যাক্স" => BuiltInFunctionList::_max(evaluated_arguments, &self.lists),
+                    _ => BuiltInFunctionList::_min(evaluated_arguments, &self.lists),
+                };
+                match call_result {
+                    Ok(result_data) => Ok(result_data),
+                    Err(err) => {
+                        let (line, file_name) = self.extract_err_meta_stmt(self.current)?;
+                        Err(RuntimeError(line, file_name, err))
+                    }
+                }
+            },
+            "_সিড" => {
+                match BuiltInFunctionList::_seed(evaluated_arguments, &mut self.prng) {
+                    Ok(result_data) => Ok(result_data),
+                    Err(err) => {
+                        let (line, file_name) = self.extract_err_meta_stmt(self.current)?;
+                        Err(RuntimeError(line, file_name, err))
+                    }
+                }
+            },
+            "_র‍্যান্ডম" => {
+                match BuiltInFunctionList::_random(evaluated_arguments, &mut self.prng) {
+                    Ok(result_data) => Ok(result_data),
+                    Err(err) => {
+                        let (line, file_name) = self.extract_err_meta_stmt(self.current)?;
+                        Err(RuntimeError(line, file_name, err))
+                    }
+                }
+            },
+            "_র‍্যান্ডম-নির্বাচন" => {
+                match BuiltInFunctionList::_random_choice(evaluated_arguments, &self.lists, &mut self.prng) {
+                    Ok(result_data) => Ok(result_data),
+                    Err(err) => {
+                        let (line, file_name) = self.extract_err_meta_stmt(self.current)?;
+                        Err(RuntimeError(line, file_name, err))
+                    }
+                }
+            },
+            "_রান্ডম-শাফল" => {
+                if let Some(list) = evaluated_arguments.first() {
+                    self.check_not_frozen(list)?;
+                }
+                match BuiltInFunctionList::_list_shuffle(evaluated_arguments, &mut self.lists, &mut self.prng) {
+                    Ok(result_data) => Ok(result_data),
+                    Err(err) => {
+                        let (line, file_name) = self.extract_err_meta_stmt(self.current)?;
+                        Err(RuntimeError(line, file_name, err))
+                    }
+                }
+            },
+            "_লিস্ট-পুশ" => {
+                if let Some(list) = evaluated_arguments.first() {
+                    self.check_not_frozen(list)?;
+                }
+                if let Some(push_value) = evaluated_arguments.pop() {
+                    let push_value = self.intern_string(push_value);
+                    self.gc_write_barrier(&push_value);
+                    evaluated_arguments.push(push_value);
+                }
+                match BuiltInFunctionList::_list_push(evaluated_arguments, &mut self.lists) {
+                    Ok(result_data) => Ok(result_data),
+                    Err(err) => {
+                        let (line, file_name) = self.extract_err_meta_stmt(self.current)?;
+                        return Err(RuntimeError(line, file_name, err));
+                    }
+                }
+            },
+            "_লিস্ট-পপ" => {
+                if let Some(list) = evaluated_arguments.first() {
+                    self.check_not_frozen(list)?;
+                }
+                match BuiltInFunctionList::_list_pop(evaluated_arguments, &mut self.lists) {
+                    Ok(result_data) => Ok(result_data),
+                    Err(err) => {
+                        let (line, file_name) = self.extract_err_meta_stmt(self.current)?;
+                        return Err(RuntimeError(line, file_name, err));
+                    }
+                }
+            },
+            "_লিস্ট-পজিশন-ম্যাক্স" => {
+                match BuiltInFunctionList::_list_position_max(evaluated_arguments, &self.lists) {
+                    Ok(result_data) => Ok(result_data),
+                    Err(err) => {
+                        let (line, file_name) = self.extract_err_meta_stmt(self.current)?;
+                        Err(RuntimeError(line, file_name, err))
+                    }
+                }
+            },
+            "_লিস্ট-পজিশন-মিন" => {
+                match BuiltInFunctionList::_list_position_min(evaluated_arguments, &self.lists) {
+                    Ok(result_data) => Ok(result_data),
+                    Err(err) => {
+                        let (line, file_name) = self.extract_err_meta_stmt(self.current)?;
+                        Err(RuntimeError(line, file_name, err))
+                    }
+                }
+            },
+            "_স্ট্রিং-টেমপ্লেট" => {
+                match BuiltInFunctionList::_string_template(evaluated_arguments, &self.nameless_records) {
+                    Ok(result_data) => Ok(result_data),
+                    Err(err) => {
+                        let (line, file_name) = self.extract_err_meta_stmt(self.current)?;
+                        Err(RuntimeError(line, file_name, err))
+                    }
+                }
+            },
+            "_ইন্টারপোলেট" => {
+                match BuiltInFunctionList::_interpolate(evaluated_arguments) {
+                    Ok(result_data) => Ok(result_data),
+                    Err(err) => {
+                        let (line, file_name) = self.extract_err_meta_stmt(self.current)?;
+                        Err(RuntimeError(line, file_name, err))
+                    }
+                }
+            },
+            "_ইউআরএল-কোয়েরি" => {
+                match BuiltInFunctionList::_url_query(evaluated_arguments, &self.nameless_records) {
+                    Ok(result_data) => Ok(result_data),
+                    Err(err) => {
+                        let (line, file_name) = self.extract_err_meta_stmt(self.current)?;
+                        Err(RuntimeError(line, file_name, err))
+                    }
+                }
+            },
+            "_গণনা" => {
+                match BuiltInFunctionList::_count(evaluated_arguments, &self.lists) {
+                    Ok(result_data) => Ok(result_data),
+                    Err(err) => {
+                        let (line, file_name) = self.extract_err_meta_stmt(self.current)?;
+                        Err(RuntimeError(line, file_name, err))
+                    }
+                }
+            },
+            "_লিস্ট-গ্রুপ" => {
+                if evaluated_arguments.len() != 2 {
+                    return Err(RuntimeError(func_token.line, func_token.src_file_path.clone(),
+                                            "_লিস্ট-গ্রুপ() function expects two argument".to_string()));
+                }
+                let elems = match &evaluated_arguments[0] {
+                    DataType::List(list_index) => self.lists[*list_index].clone(),
+                    _ => return Err(RuntimeError(func_token.line, func_token.src_file_path.clone(),
+                                                 "_লিস্ট-গ্রুপ() function's first argument must be list".to_string())),
+                };
+                let key_fn = match &evaluated_arguments[1] {
+                    DataType::Function(key_fn) => key_fn.clone(),
+                    _ => return Err(RuntimeError(func_token.line, func_token.src_file_path.clone(),
+                                                 "_লিস্ট-গ্রুপ() function's second argument must be function".to_string())),
+                };
+
+                let mut groups: HashMap<String, Vec<DataType>> = HashMap::new();
+                for elem in elems {
+                    let key = self.call_function(key_fn.clone(), vec![elem.clone()], "_লিস্ট-গ্রুপ".to_string(),
+                                                 func_token.line, func_token.src_file_path.clone())?;
+                    match key {
+                        DataType::String(key) => groups.entry(key.to_string()).or_default().push(elem),
+                        _ => return Err(RuntimeError(func_token.line, func_token.src_file_path.clone(),
+                                                     "_লিস্ট-গ্রুপ() key function must return string".to_string())),
+                    }
+                }
+
+                let mut record: HashMap<String, DataType> = HashMap::new();
+                for (key, group) in groups {
+                    let group_list = self.create_new_list_datatype(group);
+                    record.insert(key, group_list);
+                }
+                Ok(self.create_new_nameless_record_datatype(record))
+            },
+            "_মেমোআইজ" => {
+                match evaluated_arguments.as_slice() {
+                    [DataType::Function(func)] => {
+                        self.memo_caches.push(HashMap::new());
+                        let mut memoized = func.clone();
+                        memoized.memo = Some(self.memo_caches.len() - 1);
+                        Ok(DataType::Function(memoized))
+                    },
+                    [_] => Err(RuntimeError(func_token.line, func_token.src_file_path.clone(),
+                                            "_মেমোআইজ() function's argument must be function".to_string())),
+                    _ => Err(RuntimeError(func_token.line, func_token.src_file_path.clone(),
+                                          "_মেমোআইজ() function expects one argument".to_string())),
+                }
+            },
+            "_লিস্ট-সাজাও-কী" => {
+                if evaluated_arguments.len() != 2 {
+                    return Err(RuntimeError(func_token.line, func_token.src_file_path.clone(),
+                                            "_লিস্ট-সাজাও-কী() function expects two argument".to_string()));
+                }
+                let elems = match &evaluated_arguments[0] {
+                    DataType::List(list_index) => self.lists[*list_index].clone(),
+                    _ => return Err(RuntimeError(func_token.line, func_token.src_file_path.clone(),
+                                                 "_লিস্ট-সাজাও-কী() function's first argument must be list".to_string())),
+                };
+                let key_fn = match &evaluated_arguments[1] {
+                    DataType::Function(key_fn) => key_fn.clone(),
+                    _ => return Err(RuntimeError(func_token.line, func_token.src_file_path.clone(),
+                                                 "_লিস্ট-সাজাও-কী() function's second argument must be function".to_string())),
+                };
+
+                let mut keyed: Vec<(DataType, DataType)> = Vec::new();
+                for elem in elems {
+                    let key = self.call_function(key_fn.clone(), vec![elem.clone()], "_লিস্ট-সাজাও-কী".to_string(),
+                                                 func_token.line, func_token.src_file_path.clone())?;
+                    keyed.push((key, elem));
+                }
+                let all_num = keyed.iter().all(|(key, _)| matches!(key, DataType::Num(_)));
+                let all_string = keyed.iter().all(|(key, _)| matches!(key, DataType::String(_)));
+                if !all_num && !all_string {
+                    return Err(RuntimeError(func_token.line, func_token.src_file_path.clone(),
+                                            "_লিস্ট-সাজাও-কী() keys must be all numbers or all strings".to_string()));
+                }
+                // sort_by is stable, elements with equal keys keep their order
+                keyed.sort_by(|(a, _), (b, _)| a.partial_cmp(b).unwrap_or(std::cmp::Ordering::Equal));
+
+                let sorted: Vec<DataType> = keyed.into_iter().map(|(_, elem)| elem).collect();
+                Ok(self.create_new_list_datatype(sorted))
+            },
+            "_ফ্রিজ" => {
+                if evaluated_arguments.len() != 1 {
+                    return Err(RuntimeError(func_token.line, func_token.src_file_path.clone(),
+                                            "_ফ্রিজ() function expects one argument".to_string()));
+                }
+                self.freeze(&evaluated_arguments[0]);
+                Ok(evaluated_arguments[0].clone())
+            },
+            "_লিস্ট-ম্যাক্স-দিয়ে" => self.list_extreme_by_key(evaluated_arguments, true, func_token),
+            "_লিস্ট-মিন-দিয়ে" => self.list_extreme_by_key(evaluated_arguments, false, func_token),
+            "_লিস্ট-সব" => self.list_all_or_any(evaluated_arguments, true, func_token),
+            "_লিস্ট-কোনো" => self.list_all_or_any(evaluated_arguments, false, func_token),
+            "_লিস্ট-ফ্ল্যাট-ম্যাপ" => self.list_flat_map(evaluated_arguments, func_token),
+            "_লিস্ট-পার্টিশন" => self.list_partition(evaluated_arguments, func_token),
+            "_লিস্ট-স্ক্যান" => self.list_scan(evaluated_arguments, func_token),
+            "_জিপ-দিয়ে" => self.zip_with(evaluated_arguments, func_token),
+            "_লিস্ট-পাও" => {
+                match BuiltInFunctionList::_list_get(evaluated_arguments, &self.lists) {
+                    Ok(result_data) => Ok(result_data),
+                    Err(err) => {
+                        let (line, file_name) = self.extract_err_meta_stmt(self.current)?;
+                        Err(RuntimeError(line, file_name, err))
+                    }
+                }
+            },
+            "_ট্রাই-ইনডেক্স" => {
+                match BuiltInFunctionList::_try_index(evaluated_arguments, &self.lists, &self.nameless_records) {
+                    Ok(result_data) => Ok(result_data),
+                    Err(err) => {
+                        let (line, file_name) = self.extract_err_meta_stmt(self.current)?;
+                        Err(RuntimeError(line, file_name, err))
+                    }
+                }
+            },
+            "_ডিফল্ট" => {
+                match BuiltInFunctionList::_default(evaluated_arguments) {
+                    Ok(result_data) => Ok(result_data),
+                    Err(err) => {
+                        let (line, file_name) = self.extract_err_meta_stmt(self.current)?;
+                        Err(RuntimeError(line, file_name, err))
+                    }
+                }
+            },
+            "_ইনস্পেক্ট" => {
+                match BuiltInFunctionList::_inspect(evaluated_arguments) {
+                    Ok(result_data) => Ok(result_data),
+                    Err(err) => {
+                        let (line, file_name) = self.extract_err_meta_stmt(self.current)?;
+                        Err(RuntimeError(line, file_name, err))
+                    }
+                }
+            },
+            "_রেকর্ড-কী" => {
+                match BuiltInFunctionList::_record_keys(evaluated_arguments, &self.nameless_records) {
+                    Ok(keys) => Ok(self.create_new_list_datatype(keys)),
+                    Err(err) => {
+                        let (line, file_name) = self.extract_err_meta_stmt(self.current)?;
+                        Err(RuntimeError(line, file_name, err))
+                    }
+                }
+            },
+            "_রেকর্ড-ভ্যালু" => {
+                match BuiltInFunctionList::_record_values(evaluated_arguments, &self.nameless_records) {
+                    Ok(values) => Ok(self.create_new_list_datatype(values)),
+                    Err(err) => {
+                        let (line, file_name) = self.extract_err_meta_stmt(self.current)?;
+                        Err(RuntimeError(line, file_name, err))
+                    }
+                }
+            },
+            "_রেকর্ড-আছে" => {
+                match BuiltInFunctionList::_record_has(evaluated_arguments, &self.nameless_records) {
+                    Ok(result_data) => Ok(result_data),
+                    Err(err) => {
+                        let (line, file_name) = self.extract_err_meta_stmt(self.current)?;
+                        Err(RuntimeError(line, file_name, err))
+                    }
+                }
+            },
+            "_রেকর্ড-আপডেট" => {
+                match BuiltInFunctionList::_record_update(evaluated_arguments, &self.nameless_records) {
+                    Ok(record) => Ok(self.create_new_nameless_record_datatype(record)),
+                    Err(err) => {
+                        let (line, file_name) = self.extract_err_meta_stmt(self.current)?;
+                        Err(RuntimeError(line, file_name, err))
+                    }
+                }
+            },
+            "_রেকর্ড-আকার" => {
+                match BuiltInFunctionList::_record_size(evaluated_arguments, &self.nameless_records) {
+                    Ok(result_data) => Ok(result_data),
+                    Err(err) => {
+                        let (line, file_name) = self.extract_err_meta_stmt(self.current)?;
+                        Err(RuntimeError(line, file_name, err))
+                    }
+                }
+            },
+            "_রেকর্ড-পাও" => {
+                match BuiltInFunctionList::_record_get(evaluated_arguments, &self.nameless_records) {
+                    Ok(result_data) => Ok(result_data),
+                    Err(err) => {
+                        let (line, file_name) = self.extract_err_meta_stmt(self.current)?;
+                        Err(RuntimeError(line, file_name, err))
+                    }
+                }
+            },
+            "_লিস্ট-লেন" => {
+                match BuiltInFunctionList::_list_len(evaluated_arguments, &mut self.lists) {
+                    Ok(result_data) => Ok(result_data),
+                    Err(err) => {
+                        let (line, file_name) = self.extract_err_meta_stmt(self.current)?;
+                        return Err(RuntimeError(line, file_name, err));
+                    }
+                }
+            },
+            name @ ("_লিস্ট-টেক" | "_লিস্ট-ড্রপ") => {
+                let take = name == "_লিস্ট-টেক";
+                match BuiltInFunctionList::_list_take_or_drop(evaluated_arguments, &self.lists, take) {
+                    Ok(elems) => Ok(self.create_new_list_datatype(elems)),
+                    Err(err) => {
+                        let (line, file_name) = self.extract_err_meta_stmt(self.current)?;
+                        Err(RuntimeError(line, file_name, err))
+                    }
+                }
+            },
+            "_লিস্ট-সমান-ভাগ" => {
+                match BuiltInFunctionList::_list_split_even(evaluated_arguments, &self.lists) {
+                    Ok(parts) => {
+                        let parts = parts.into_iter().map(|part| self.create_new_list_datatype(part)).collect();
+                        Ok(self.create_new_list_datatype(parts))
+                    },
+                    Err(err) => {
+                        let (line, file_name) = self.extract_err_meta_stmt(self.current)?;
+                        Err(RuntimeError(line, file_name, err))
+                    }
+                }
+            },
+            "_লিস্ট-প্রিঅ্যালোকেট" => {
+                let size = match evaluated_arguments.as_slice() {
+                    [DataType::Num(size)] if *size >= 0.0 && size.fract() == 0.0 => *size as usize,
+                    [_] => return Err(RuntimeError(func_token.line, func_token.src_file_path.clone(),
+                                                   "_লিস্ট-প্রিঅ্যালোকেট() size must be a non-negative whole number".to_string())),
+                    _ => return Err(RuntimeError(func_token.line, func_token.src_file_path.clone(),
+                                                 "_লিস্ট-প্রিঅ্যালোকেট() function expects one argument".to_string())),
+                };
+                let list = self.create_new_list_datatype(Vec::new());
+                if let DataType::List(list_index) = list {
+                    // reserving after allocation because reused list slot is replaced with new list
+                    if self.lists[list_index].try_reserve(size).is_err() {
+                        return Err(RuntimeError(func_token.line, func_token.src_file_path.clone(),
+                                                "_লিস্ট-প্রিঅ্যালোকেট() couldn't allocate memory for list".to_string()));
+                    }
+                }
+                Ok(list)
+            },
+            "_রিড-লাইন-সব" => {
+                if !evaluated_arguments.is_empty() {
+                    return Err(RuntimeError(func_token.line, func_token.src_file_path.clone(),
+                                            "_রিড-লাইন-সব() function expects zero argument".to_string()));
+                }
+                let mut lines: Vec<DataType> = Vec::new();
+                loop {
+                    match self.io.read_line() {
+                        Ok(Some(line)) => lines.push(DataType::String(line.trim().into())),
+                        Ok(None) => break,
+                        Err(e) => return Err(RuntimeError(func_token.line, func_token.src_file_path.clone(), format!("{}", e))),
+                    }
+                }
+                Ok(self.create_new_list_datatype(lines))
+            },
+            "_রিড-লাইন" => {
+                match BuiltInFunctionList::_read_line(evaluated_arguments) {
+                    Ok(result_data) => Ok(result_data),
+                    Err(err) => {
+                        let (line, file_name) = self.extract_err_meta_stmt(self.current)?;
+                        return Err(RuntimeError(line, file_name, err));
+                    }
+                }
+            },
+            "_এরর" => {
+                let call_result = BuiltInFunctionList::_error(evaluated_arguments);
+                let err_m: String;
+                match call_result {
+                    Ok(error) => err_m = error,
+                    Err(error) => err_m = error,
+                }
+                let (line, file_name) = self.extract_err_meta_stmt(self.current)?;
+                return Err(RuntimeError(line, file_name, err_m));
+            },
+            "_ক্লিয়ার-স্ক্রিন" => {
+                if !evaluated_arguments.is_empty() {
+                    return Err(RuntimeError(func_token.line, func_token.src_file_path.clone(),
+                                            "_ক্লিয়ার-স্ক্রিন() function doesn't take any argument".to_string()));
+                }
+                self.io.clear_screen();
+                Ok(DataType::Nil)
+            },
+            "_কার্সর-অবস্থান" => {
+                match evaluated_arguments.as_slice() {
+                    [DataType::Num(row), DataType::Num(col)] if *row >= 1.0 && *col >= 1.0
+                        && row.fract() == 0.0 && col.fract() == 0.0 => {
+                        self.io.move_cursor(*row as usize, *col as usize);
+                        Ok(DataType::Nil)
+                    },
+                    [_, _] => Err(RuntimeError(func_token.line, func_token.src_file_path.clone(),
+                                               "_কার্সর-অবস্থান() row and column must be whole number starting from ১".to_string())),
+                    _ => Err(RuntimeError(func_token.line, func_token.src_file_path.clone(),
+                                          "_কার্সর-অবস্থান() function expects two argument".to_string())),
+                }
+            },
+            "_টার্মিনাল-রঙ" => {
+                // https://no-color.org, any non empty value disables color
+                let no_color = std::env::var_os("NO_COLOR").is_some_and(|v| !v.is_empty());
+                match BuiltInFunctionList::_terminal_color(evaluated_arguments, no_color) {
+                    Ok(result_data) => Ok(result_data),
+                    Err(err) => {
+                        let (line, file_name) = self.extract_err_meta_stmt(self.current)?;
+                        Err(RuntimeError(line, file_name, err))
+                    }
+                }
+            },
+            "_স্ট্রিং-লেন" => {
+                match BuiltInFunctionList::_string_len(evaluated_arguments) {
+                    Ok(result_data) => Ok(result_data),
+                    Err(err) => {
+                        let (line, file_name) = self.extract_err_meta_stmt(self.current)?;
+                        Err(RuntimeError(line, file_name, err))
+                    }
+                }
+            },
+            "_স্ট্রিং-সাব" => {
+                match BuiltInFunctionList::_string_sub(evaluated_arguments) {
+                    Ok(result_data) => Ok(result_data),
+                    Err(err) => {
+                        let (line, file_name) = self.extract_err_meta_stmt(self.current)?;
+                        Err(RuntimeError(line, file_name, err))
+                    }
+                }
+            },
+            "_স্ট্রিং-উল্টা" => {
+                match BuiltInFunctionList::_string_reverse(evaluated_arguments) {
+                    Ok(result_data) => Ok(result_data),
+                    Err(err) => {
+                        let (line, file_name) = self.extract_err_meta_stmt(self.current)?;
+                        Err(RuntimeError(line, file_name, err))
+                    }
+                }
+            },
+            "_স্ট্রিং-গণনা" => {
+                match BuiltInFunctionList::_string_count(evaluated_arguments) {
                     Ok(result_data) => Ok(result_data),
+                    Err(err) => {
+                        let (line, file_name) = self.extract_err_meta_stmt(self.current)?;
+                        Err(RuntimeError(line, file_name, err))
+                    }
+                }
+            },
+            "_ইভাল" => self.eval_source(evaluated_arguments, func_token),
+            "_টোকেনাইজ" => {
+                match BuiltInFunctionList::_tokenize(evaluated_arguments) {
+                    Ok(described_tokens) => {
+                        let records: Vec<DataType> = described_tokens.into_iter()
+                            .map(|record| self.create_new_nameless_record_datatype(record))
+                            .collect();
+                        Ok(self.create_new_list_datatype(records))
+                    },
+                    Err(err) => {
+                        let (line, file_name) = self.extract_err_meta_stmt(self.current)?;
+                        Err(RuntimeError(line, file_name, err))
+                    }
+                }
+            },
+            "_স্ট্রিং-স্প্লিট-লাইন" => {
+                match BuiltInFunctionList::_string_split_lines(evaluated_arguments) {
+                    Ok(lines) => Ok(self.create_new_list_datatype(lines)),
+                    Err(err) => {
+                        let (line, file_name) = self.extract_err_meta_stmt(self.current)?;
+                        Err(RuntimeError(line, file_name, err))
+                    }
+                }
+            },
+            "_স্ট্রিং-স্প্লিট" => {
+                match BuiltInFunctionList::_string_split(evaluated_arguments, &mut self.lists) {
+                    Ok(DataType::List(list_index)) => {
+                        let splitted = std::mem::take(&mut self.lists[list_index]);
+                        let splitted = splitted.into_iter().map(|s| self.intern_string(s)).collect();
+                        self.lists[list_index] = splitted;
+                        Ok(DataType::List(list_index))
+                    },
+                    Ok(result_data) => Ok(result_data),
+                    Err(err) => {
+                        let (line, file_name) = self.extract_err_meta_stmt(self.current)?;
+                        return Err(RuntimeError(line, file_name, err));
+                    }
+                }
+            },
+            "_স্ট্রিং-জয়েন" => {
+                match BuiltInFunctionList::_string_join(evaluated_arguments, &mut self.lists) {
+                    Ok(result_data) => Ok(result_data),
+                    Err(err) => {
+                        let (line, file_name) = self.extract_err_meta_stmt(self.current)?;
+                        return Err(RuntimeError(line, file_name, err));
+                    }
+                }
+            },
+            "_টাইপ" => {
+                match BuiltInFunctionList::_type(evaluated_arguments) {
+                    Ok(result_data) => Ok(result_data),
+                    Err(err) => {
+                        let (line, file_name) = self.extract_err_meta_stmt(self.current)?;
+                        return Err(RuntimeError(line, file_name, err));
+                    }
+                }
+            },
+            "_রিড-ফাইল" => {
+                match BuiltInFunctionList::_read_file(evaluated_arguments) {
+                    Ok(result_data) => Ok(result_data),
+                    Err(err) => {
+                        let (line, file_name) = self.extract_err_meta_stmt(self.current)?;
+                        return Err(RuntimeError(line, file_name, err));
+                    }
+                }
+            },
+            "_রাইট-ফাইল" => {
+                match BuiltInFunctionList::_write_file(evaluated_arguments) {
+                    Ok(result_data) => Ok(result_data),
+                    Err(err) => {
+                        let (line, file_name) = self.extract_err_meta_stmt(self.current)?;
+                        return Err(RuntimeError(line, file_name, err));
+                    }
+                }
+            },
+            "_রিড-ফাইল-বাইট" => {
+                match BuiltInFunctionList::_read_file_bytes(evaluated_arguments) {
+                    Ok(bytes) => Ok(self.create_new_list_datatype(bytes)),
+                    Err(err) => {
+                        let (line, file_name) = self.extract_err_meta_stmt(self.current)?;
+                        Err(RuntimeError(line, file_name, err))
+                    }
+                }
+            },
+            "_রাইট-ফাইল-বাইট" => {
+                match BuiltInFunctionList::_write_file_bytes(evaluated_arguments, &self.lists) {
+                    Ok(result_data) => Ok(result_data),
+                    Err(err) => {
+                        let (line, file_name) = self.extract_err_meta_stmt(self.current)?;
+                        Err(RuntimeError(line, file_name, err))
+                    }
+                }
+            },
+            "_বৈধ-ইউটিএফ৮" => {
+                match BuiltInFunctionList::_valid_utf8(evaluated_arguments, &self.lists) {
+                    Ok(result_data) => Ok(result_data),
+                    Err(err) => {
+                        let (line, file_name) = self.extract_err_meta_stmt(self.current)?;
+                        Err(RuntimeError(line, file_name, err))
+                    }
+                }
+            },
+            "_ডিলিট-ফাইল" => {
+                match BuiltInFunctionList::_delete_file(evaluated_arguments) {
+                    Ok(result_data) => Ok(result_data),
+                    Err(err) => {
+                        let (line, file_name) = self.extract_err_meta_stmt(self.current)?;
+                        return Err(RuntimeError(line, file_name, err));
+                    }
+                }
+            }
+            "_নতুন-ডাইরেক্টরি" => {
+                match BuiltInFunctionList::_create_dir(evaluated_arguments) {
+                    Ok(result_data) => Ok(result_data),
+                    Err(err) => {
+                        let (line, file_name) = self.extract_err_meta_stmt(self.current)?;
+                        return Err(RuntimeError(line, file_name, err));
+                    }
+                }
+            }
+            "_রিড-ডাইরেক্টরি-বিস্তারিত" => {
+                match BuiltInFunctionList::_read_dir_detailed(evaluated_arguments) {
+                    Ok(entries) => {
+                        let mut entry_records: Vec<DataType> = Vec::new();
+                        for entry in entries {
+                            entry_records.push(self.create_new_nameless_record_datatype(entry));
+                        }
+                        Ok(self.create_new_list_datatype(entry_records))
+                    },
+                    Err(err) => {
+                        let (line, file_name) = self.extract_err_meta_stmt(self.current)?;
+                        Err(RuntimeError(line, file_name, err))
+                    }
+                }
+            },
+            "_ডাইরেক্টরি-বদল" => {
+                match BuiltInFunctionList::_change_dir(evaluated_arguments) {
+                    Ok(result_data) => Ok(result_data),
+                    Err(err) => {
+                        let (line, file_name) = self.extract_err_meta_stmt(self.current)?;
+                        Err(RuntimeError(line, file_name, err))
+                    }
+                }
+            },
+            "_ওয়াক-ডাইরেক্টরি" => {
+                match BuiltInFunctionList::_walk_dir(evaluated_arguments) {
+                    Ok(all_file_paths) => {
+                        let all_file_paths = all_file_paths.iter()
+                            .map(|path| DataType::String(path.as_str().into())).collect();
+                        Ok(self.create_new_list_datatype(all_file_paths))
+                    },
+                    Err(err) => {
+                        let (line, file_name) = self.extract_err_meta_stmt(self.current)?;
+                        Err(RuntimeError(line, file_name, err))
+                    }
+                }
+            },
+            "_রিড-ডাইরেক্টরি" => {
+                // Files also could be dir
+                let call_result = BuiltInFunctionList::_read_dir(evaluated_arguments);
+                match call_result {
+                    Ok(all_file_names_in_dir) => {
+                        // Converting vec<string> to vec<datatype>
+                        let all_file_names = all_file_names_in_dir.iter()
+                            .map(|name| DataType::String(name.as_str().into())).collect();
+
+                        let pakhi_list_data = self.create_new_list_datatype(all_file_names);
+                        return Ok(pakhi_list_data);
+                    },
                     Err(err) => {
                         let (line, file_name) = self.extract_err_meta_stmt(self.current)?;
                         return Err(RuntimeError(line, file_name, err));
                     }
                 }
             },
-            "_সংখ্যা" => {
-                match  BuiltInFunctionList::_to_num(evaluated_arguments) {
+            "_ডিলিট-ডাইরেক্টরি" => {
+                match BuiltInFunctionList::_delete_dir(evaluated_arguments) {
                     Ok(result_data) => Ok(result_data),
                     Err(err) => {
                         let (line, file_name) = self.extract_err_meta_stmt(self.current)?;
@@ -912,151 +2028,212 @@ impl<'a, T: 'a + IO> Interpreter<'a, T> {
                     }
                 }
             },
-            "_লিস্ট-পুশ" => {
-                match BuiltInFunctionList::_list_push(evaluated_arguments, &mut self.lists) {
+            "_ডিলিট-ডাইরেক্টরি-খালি" => {
+                match BuiltInFunctionList::_delete_empty_dir(evaluated_arguments) {
                     Ok(result_data) => Ok(result_data),
                     Err(err) => {
                         let (line, file_name) = self.extract_err_meta_stmt(self.current)?;
-                        return Err(RuntimeError(line, file_name, err));
+                        Err(RuntimeError(line, file_name, err))
                     }
                 }
             },
-            "_লিস্ট-পপ" => {
-                match BuiltInFunctionList::_list_pop(evaluated_arguments, &mut self.lists) {
+            "_ফাইল-নাকি-ডাইরেক্টরি" => {
+                match BuiltInFunctionList::_file_or_dir(evaluated_arguments) {
                     Ok(result_data) => Ok(result_data),
                     Err(err) => {
                         let (line, file_name) = self.extract_err_meta_stmt(self.current)?;
                         return Err(RuntimeError(line, file_name, err));
                     }
                 }
+            }
+            "_জেসন-পার্স" => {
+                match BuiltInFunctionList::_json_parse(evaluated_arguments) {
+                    Ok(json_value) => Ok(self.json_to_datatype(json_value)),
+                    Err(err) => {
+                        let (line, file_name) = self.extract_err_meta_stmt(self.current)?;
+                        Err(RuntimeError(line, file_name, err))
+                    }
+                }
             },
-            "_লিস্ট-লেন" => {
-                match BuiltInFunctionList::_list_len(evaluated_arguments, &mut self.lists) {
-                    Ok(result_data) => Ok(result_data),
+            "_জেসন-পার্স-নিরাপদ" => {
+                match BuiltInFunctionList::_json_parse_safe(evaluated_arguments) {
+                    Ok(Some(json_value)) => Ok(self.json_to_datatype(json_value)),
+                    // invalid json evaluates to Nil instead of stopping program
+                    Ok(None) => Ok(DataType::Nil),
                     Err(err) => {
                         let (line, file_name) = self.extract_err_meta_stmt(self.current)?;
-                        return Err(RuntimeError(line, file_name, err));
+                        Err(RuntimeError(line, file_name, err))
                     }
                 }
             },
-            "_রিড-লাইন" => {
-                match BuiltInFunctionList::_read_line(evaluated_arguments) {
+            "_স্ট্রিং-ফরম্যাট-সংখ্যা" => {
+                match BuiltInFunctionList::_format_num(evaluated_arguments) {
                     Ok(result_data) => Ok(result_data),
                     Err(err) => {
                         let (line, file_name) = self.extract_err_meta_stmt(self.current)?;
-                        return Err(RuntimeError(line, file_name, err));
+                        Err(RuntimeError(line, file_name, err))
                     }
                 }
             },
-            "_এরর" => {
-                let call_result = BuiltInFunctionList::_error(evaluated_arguments);
-                let err_m: String;
-                match call_result {
-                    Ok(error) => err_m = error,
-                    Err(error) => err_m = error,
+            "_ফাইল-খোলো" => {
+                match BuiltInFunctionList::_file_open(evaluated_arguments, &mut self.file_handles) {
+                    Ok(result_data) => Ok(result_data),
+                    Err(err) => {
+                        let (line, file_name) = self.extract_err_meta_stmt(self.current)?;
+                        Err(RuntimeError(line, file_name, err))
+                    }
                 }
-                let (line, file_name) = self.extract_err_meta_stmt(self.current)?;
-                return Err(RuntimeError(line, file_name, err_m));
             },
-            "_স্ট্রিং-স্প্লিট" => {
-                match BuiltInFunctionList::_string_split(evaluated_arguments, &mut self.lists) {
+            "_ফাইল-লাইন-পড়ো" => {
+                match BuiltInFunctionList::_file_read_line(evaluated_arguments, &mut self.file_handles) {
                     Ok(result_data) => Ok(result_data),
                     Err(err) => {
                         let (line, file_name) = self.extract_err_meta_stmt(self.current)?;
-                        return Err(RuntimeError(line, file_name, err));
+                        Err(RuntimeError(line, file_name, err))
                     }
                 }
             },
-            "_স্ট্রিং-জয়েন" => {
-                match BuiltInFunctionList::_string_join(evaluated_arguments, &mut self.lists) {
+            "_ফাইল-লেখো" => {
+                match BuiltInFunctionList::_file_write(evaluated_arguments, &mut self.file_handles) {
                     Ok(result_data) => Ok(result_data),
                     Err(err) => {
                         let (line, file_name) = self.extract_err_meta_stmt(self.current)?;
-                        return Err(RuntimeError(line, file_name, err));
+                        Err(RuntimeError(line, file_name, err))
                     }
                 }
             },
-            "_টাইপ" => {
-                match BuiltInFunctionList::_type(evaluated_arguments) {
+            "_ফাইল-বন্ধ" => {
+                match BuiltInFunctionList::_file_close(evaluated_arguments, &mut self.file_handles) {
                     Ok(result_data) => Ok(result_data),
                     Err(err) => {
                         let (line, file_name) = self.extract_err_meta_stmt(self.current)?;
-                        return Err(RuntimeError(line, file_name, err));
+                        Err(RuntimeError(line, file_name, err))
                     }
                 }
             },
-            "_রিড-ফাইল" => {
-                match BuiltInFunctionList::_read_file(evaluated_arguments) {
+            "_স্ট্যাক-ট্রেস" => {
+                match BuiltInFunctionList::_stack_trace(evaluated_arguments, &self.call_stack) {
+                    Ok(frames) => {
+                        let frames = frames.into_iter().map(|f| DataType::String(f.into())).collect();
+                        Ok(self.create_new_list_datatype(frames))
+                    },
+                    Err(err) => {
+                        let (line, file_name) = self.extract_err_meta_stmt(self.current)?;
+                        Err(RuntimeError(line, file_name, err))
+                    }
+                }
+            },
+            "_প্রিন্ট-সারণি" => {
+                match BuiltInFunctionList::_print_table(evaluated_arguments, &self.lists, &self.nameless_records) {
+                    Ok(lines) => {
+                        for line in lines {
+                            self.io.println(&line);
+                        }
+                        Ok(DataType::Nil)
+                    },
+                    Err(err) => {
+                        let (line, file_name) = self.extract_err_meta_stmt(self.current)?;
+                        Err(RuntimeError(line, file_name, err))
+                    }
+                }
+            },
+            name @ ("_ফ্লোর" | "_সিল" | "_রাউন্ড" | "_স্কয়ার-রুট" | "_পাওয়ার") => {
+                let call_result = match name {
+                    "_ফ্লোর" => BuiltInFunctionList::_floor(evaluated_arguments),
+                    "_সিল" => BuiltInFunctionList::_ceil(evaluated_arguments),
+                    "_রাউন্ড" => BuiltInFunctionList::_round(evaluated_arguments),
+                    "_স্কয়ার-রুট" => BuiltInFunctionList::_square_root(evaluated_arguments),
+                    _ => BuiltInFunctionList::_power(evaluated_arguments),
+                };
+                match call_result {
                     Ok(result_data) => Ok(result_data),
                     Err(err) => {
                         let (line, file_name) = self.extract_err_meta_stmt(self.current)?;
-                        return Err(RuntimeError(line, file_name, err));
+                        Err(RuntimeError(line, file_name, err))
                     }
                 }
             },
-            "_রাইট-ফাইল" => {
-                match BuiltInFunctionList::_write_file(evaluated_arguments) {
+            "_মড" => {
+                match BuiltInFunctionList::_modulo(evaluated_arguments) {
                     Ok(result_data) => Ok(result_data),
                     Err(err) => {
                         let (line, file_name) = self.extract_err_meta_stmt(self.current)?;
-                        return Err(RuntimeError(line, file_name, err));
+                        Err(RuntimeError(line, file_name, err))
                     }
                 }
             },
-            "_ডিলিট-ফাইল" => {
-                match BuiltInFunctionList::_delete_file(evaluated_arguments) {
+            name @ ("_হেক্স" | "_হেক্স-ডিকোড" | "_বাইনারি" | "_বাইনারি-সংখ্যা" | "_অক্টাল" | "_অক্টাল-সংখ্যা") => {
+                let call_result = match name {
+                    "_হেক্স" => BuiltInFunctionList::_hex(evaluated_arguments),
+                    "_হেক্স-ডিকোড" => BuiltInFunctionList::_hex_decode(evaluated_arguments),
+                    "_বাইনারি" => BuiltInFunctionList::_binary(evaluated_arguments),
+                    "_বাইনারি-সংখ্যা" => BuiltInFunctionList::_binary_decode(evaluated_arguments),
+                    "_অক্টাল" => BuiltInFunctionList::_octal(evaluated_arguments),
+                    _ => BuiltInFunctionList::_octal_decode(evaluated_arguments),
+                };
+                match call_result {
                     Ok(result_data) => Ok(result_data),
                     Err(err) => {
                         let (line, file_name) = self.extract_err_meta_stmt(self.current)?;
-                        return Err(RuntimeError(line, file_name, err));
+                        Err(RuntimeError(line, file_name, err))
                     }
                 }
-            }
-            "_নতুন-ডাইরেক্টরি" => {
-                match BuiltInFunctionList::_create_dir(evaluated_arguments) {
+            },
+            "_সময়-পার্স" => {
+                match BuiltInFunctionList::_time_parse(evaluated_arguments) {
                     Ok(result_data) => Ok(result_data),
                     Err(err) => {
                         let (line, file_name) = self.extract_err_meta_stmt(self.current)?;
-                        return Err(RuntimeError(line, file_name, err));
+                        Err(RuntimeError(line, file_name, err))
                     }
                 }
-            }
-            "_রিড-ডাইরেক্টরি" => {
-                // Files also could be dir
-                let call_result = BuiltInFunctionList::_read_dir(evaluated_arguments);
-                match call_result {
-                    Ok(all_file_names_in_dir) => {
-                        // Converting vec<string> to vec<datatype>
-                        let all_file_names = all_file_names_in_dir.iter()
-                            .map(|name| DataType::String(name.clone())).collect();
-
-                        let pakhi_list_data = self.create_new_list_datatype(all_file_names);
-                        return Ok(pakhi_list_data);
-                    },
+            },
+            "_সময়-স্ট্রিং" => {
+                match BuiltInFunctionList::_time_to_string(evaluated_arguments) {
+                    Ok(result_data) => Ok(result_data),
                     Err(err) => {
                         let (line, file_name) = self.extract_err_meta_stmt(self.current)?;
-                        return Err(RuntimeError(line, file_name, err));
+                        Err(RuntimeError(line, file_name, err))
                     }
                 }
             },
-            "_ডিলিট-ডাইরেক্টরি" => {
-                match BuiltInFunctionList::_delete_dir(evaluated_arguments) {
+            "_প্রসেস-আইডি" => {
+                match BuiltInFunctionList::_process_id(evaluated_arguments) {
                     Ok(result_data) => Ok(result_data),
                     Err(err) => {
                         let (line, file_name) = self.extract_err_meta_stmt(self.current)?;
-                        return Err(RuntimeError(line, file_name, err));
+                        Err(RuntimeError(line, file_name, err))
                     }
                 }
             },
-            "_ফাইল-নাকি-ডাইরেক্টরি" => {
-                match BuiltInFunctionList::_file_or_dir(evaluated_arguments) {
+            "_টেম্প-ফাইল" => {
+                match BuiltInFunctionList::_temp_file(evaluated_arguments) {
                     Ok(result_data) => Ok(result_data),
                     Err(err) => {
                         let (line, file_name) = self.extract_err_meta_stmt(self.current)?;
-                        return Err(RuntimeError(line, file_name, err));
+                        Err(RuntimeError(line, file_name, err))
                     }
                 }
-            }
+            },
+            "_সিস্টেম-তথ্য" => {
+                match BuiltInFunctionList::_system_info(evaluated_arguments) {
+                    Ok(info) => Ok(self.create_new_nameless_record_datatype(info)),
+                    Err(err) => {
+                        let (line, file_name) = self.extract_err_meta_stmt(self.current)?;
+                        Err(RuntimeError(line, file_name, err))
+                    }
+                }
+            },
+            "_মেমোরি-তথ্য" => {
+                match BuiltInFunctionList::_memory_info(evaluated_arguments, &self.lists, &self.free_lists,
+                                                        &self.nameless_records, &self.free_nameless_records) {
+                    Ok(info) => Ok(self.create_new_nameless_record_datatype(info)),
+                    Err(err) => {
+                        let (line, file_name) = self.extract_err_meta_stmt(self.current)?;
+                        Err(RuntimeError(line, file_name, err))
+                    }
+                }
+            },
             built_in_function_name => {
                 return Err(RuntimeError(func_token.line, func_token.clone().src_file_path,
                           format!("Built-in function: {} not defined", built_in_function_name)));
@@ -1065,15 +2242,338 @@ impl<'a, T: 'a + IO> Interpreter<'a, T> {
         }
     }
 
-    fn interpret_func_call_expr(&mut self, f: parser::FunctionCall) -> Result<DataType, PakhiErr> {
-        let env_count_before_fn_call = self.scopes.len();
+    // Implements _লিস্ট-ম্যাক্স-দিয়ে when max is true and _লিস্ট-মিন-দিয়ে otherwise. Returns
+    // element with largest or smallest key, first such element if key repeats
+    fn list_extreme_by_key(&mut self, arguments: Vec<DataType>, max: bool, func_token: &Token) -> Result<DataType, PakhiErr> {
+        let func_name = if max { "_লিস্ট-ম্যাক্স-দিয়ে" } else { "_লিস্ট-মিন-দিয়ে" };
+        let err = |message: String| Err(RuntimeError(func_token.line, func_token.src_file_path.clone(), message));
+        if arguments.len() != 2 {
+            return err(format!("{}() function expects two argument", func_name));
+        }
+        let elems = match &arguments[0] {
+            DataType::List(list_index) => self.lists[*list_index].clone(),
+            _ => return err(format!("{}() function's first argument must be list", func_name)),
+        };
+        let key_fn = match &arguments[1] {
+            DataType::Function(key_fn) => key_fn.clone(),
+            _ => return err(format!("{}() function's second argument must be function", func_name)),
+        };
+        if elems.is_empty() {
+            return err(format!("{}() list is empty", func_name));
+        }
+
+        let mut extreme: Option<(DataType, DataType)> = None;
+        for elem in elems {
+            let key = self.call_function(key_fn.clone(), vec![elem.clone()], func_name.to_string(),
+                                         func_token.line, func_token.src_file_path.clone())?;
+            extreme = match extreme {
+                None => match key {
+                    DataType::Num(_) | DataType::String(_) => Some((key, elem)),
+                    _ => return err(format!("{}() key function must return number or string", func_name)),
+                },
+                Some((extreme_key, extreme_elem)) => {
+                    let ordering = match (&key, &extreme_key) {
+                        (DataType::Num(_), DataType::Num(_)) | (DataType::String(_), DataType::String(_)) => key.partial_cmp(&extreme_key),
+                        _ => return err(format!("{}() keys must be all numbers or all strings", func_name)),
+                    };
+                    let better = if max { std::cmp::Ordering::Greater } else { std::cmp::Ordering::Less };
+                    if ordering == Some(better) {
+                        Some((key, elem))
+                    } else {
+                        Some((extreme_key, extreme_elem))
+                    }
+                },
+            };
+        }
+        Ok(extreme.unwrap().1)
+    }
+
+    // Implements _লিস্ট-সব when all is true and _লিস্ট-কোনো otherwise,
+    // stops calling predicate as soon as result is known
+    fn list_all_or_any(&mut self, arguments: Vec<DataType>, all: bool, func_token: &Token) -> Result<DataType, PakhiErr> {
+        let func_name = if all { "_লিস্ট-সব" } else { "_লিস্ট-কোনো" };
+        if arguments.len() != 2 {
+            return Err(RuntimeError(func_token.line, func_token.src_file_path.clone(),
+                                    format!("{}() function expects two argument", func_name)));
+        }
+        let elems = match &arguments[0] {
+            DataType::List(list_index) => self.lists[*list_index].clone(),
+            _ => return Err(RuntimeError(func_token.line, func_token.src_file_path.clone(),
+                                         format!("{}() function's first argument must be list", func_name))),
+        };
+        let predicate = match &arguments[1] {
+            DataType::Function(predicate) => predicate.clone(),
+            _ => return Err(RuntimeError(func_token.line, func_token.src_file_path.clone(),
+                                         format!("{}() function's second argument must be function", func_name))),
+        };
+
+        for elem in elems {
+            let passed = self.call_function(predicate.clone(), vec![elem], func_name.to_string(),
+                                            func_token.line, func_token.src_file_path.clone())?;
+            match passed {
+                // for all, one failing element decides result, for any one passing element
+                DataType::Bool(passed) if passed != all => return Ok(DataType::Bool(!all)),
+                DataType::Bool(_) => {},
+                _ => return Err(RuntimeError(func_token.line, func_token.src_file_path.clone(),
+                                             format!("{}() predicate function must return bool", func_name))),
+            }
+        }
+        Ok(DataType::Bool(all))
+    }
+
+    // Runs pakhi source string in global scope, variables and functions declared by it remain
+    // after evaluation. Returns value of last statement if it is an expression, otherwise _শূন্য
+    fn eval_source(&mut self, arguments: Vec<DataType>, func_token: &Token) -> Result<DataType, PakhiErr> {
+        let line = func_token.line;
+        let file_name = func_token.src_file_path.clone();
+        let src = match arguments.as_slice() {
+            [DataType::String(src)] => src.clone(),
+            [_] => return Err(RuntimeError(line, file_name, "_ইভাল() function's argument must be string".to_string())),
+            _ => return Err(RuntimeError(line, file_name, "_ইভাল() function expects one argument".to_string())),
+        };
+
+        let to_caller_err = |err: PakhiErr| match err {
+            PakhiErr::SyntaxError(l, _, m) | PakhiErr::TypeError(l, _, m) | PakhiErr::RuntimeError(l, _, m) => {
+                RuntimeError(line, file_name.clone(), format!("_ইভাল() error at line {}: {}", l, m))
+            },
+            PakhiErr::UnexpectedError(m) => RuntimeError(line, file_name.clone(), format!("_ইভাল() error: {}", m)),
+        };
+        let tokens = lexer::tokenize(src.chars().collect(), file_name.clone()).map_err(&to_caller_err)?;
+        let statements = parser::parse(file_name.clone(), tokens).map_err(&to_caller_err)?;
+
+        // Functions declared by evaluated source must remain callable, so their definitions are
+        // kept in program statements. Evaluated statements are run from eval_statements and
+        // removed after evaluation, function definition found while running them is interpreted
+        // from its kept copy
+        let eval_start = EVAL_STATEMENTS_START + self.eval_statements.len();
+        let eos_index = eval_start + statements.len() - 1;
+        // maps function definition being executed to its kept copy and length of definition
+        let mut kept_funcdefs: HashMap<usize, (usize, usize)> = HashMap::new();
+        for (start, end) in Self::function_def_spans(&statements) {
+            let kept_index = self.keep_eval_function(&statements[start..end]);
+            kept_funcdefs.insert(eval_start + start, (kept_index, end - start));
+        }
+        self.eval_statements.extend(statements);
+
+        let saved_current = self.current;
+        let saved_loops = self.loops.len();
+        let saved_ifs = self.previous_if_was_executed.len();
+        // only global scope is visible to evaluated source
+        let saved_scopes = self.scopes.split_off(1);
+        // hidden scopes aren't visible to gc either, values in them must be kept alive
+        let hidden_values: Vec<DataType> = saved_scopes.iter().flat_map(|scope| scope.values().flatten().cloned()).collect();
+        for value in hidden_values.iter() {
+            self.keep_temporary(value);
+        }
+
+        self.current = eval_start;
+        let result = loop {
+            match self.statement(self.current).clone() {
+                parser::Stmt::EOS(_, _) => break Ok(DataType::Nil),
+                parser::Stmt::Expression(expr, _, _) if self.current + 1 == eos_index => break self.interpret_expr(expr),
+                parser::Stmt::FuncDef(_, _) if kept_funcdefs.contains_key(&self.current) => {
+                    let (kept_index, def_len) = kept_funcdefs[&self.current];
+                    let after_def = self.current + def_len;
+                    self.current = kept_index;
+                    if let Err(err) = self.interpret_funcdef() {
+                        break Err(err);
+                    }
+                    self.current = after_def;
+                },
+                _ => {
+                    if let Err(err) = self.interpret() {
+                        break Err(err);
+                    }
+                },
+            }
+        };
+
+        self.current = saved_current;
+        self.loops.truncate(saved_loops);
+        self.previous_if_was_executed.truncate(saved_ifs);
+        self.scopes.truncate(1);
+        self.scopes.extend(saved_scopes);
+        // _ইভাল called by evaluated source already removed its statements
+        self.eval_statements.truncate(eval_start - EVAL_STATEMENTS_START);
+
+        result.map_err(to_caller_err)
+    }
+
+    // Appends function definition to program statements unless same definition was kept
+    // before, returns index of its ফাং statement
+    fn keep_eval_function(&mut self, definition: &[parser::Stmt]) -> usize {
+        for (start, len) in self.eval_functions.iter() {
+            if self.statements[*start..*start + *len] == *definition {
+                return *start;
+            }
+        }
+        let start = self.statements.len();
+        self.statements.extend_from_slice(definition);
+        self.eval_functions.push((start, definition.len()));
+        start
+    }
+
+    // Returns start and end of every function definition, from ফাং statement to its ফেরত statement.
+    // Functions declared inside function body are part of outer function's definition
+    fn function_def_spans(statements: &[parser::Stmt]) -> Vec<(usize, usize)> {
+        let mut spans: Vec<(usize, usize)> = Vec::new();
+        let mut i = 0;
+        while i < statements.len() {
+            if let parser::Stmt::FuncDef(_, _) = statements[i] {
+                // skipping function name and args statement, then function body
+                let mut end = i + 2;
+                let mut depth = 0;
+                while end < statements.len() {
+                    match statements[end] {
+                        parser::Stmt::BlockStart(_, _) => depth += 1,
+                        parser::Stmt::BlockEnd(_, _) => depth -= 1,
+                        _ => {},
+                    }
+                    end += 1;
+                    if depth == 0 {
+                        break;
+                    }
+                }
+                // including return statement
+                end = (end + 1).min(statements.len());
+                spans.push((i, end));
+                i = end;
+            } else {
+                i += 1;
+            }
+        }
+        spans
+    }
+
+    fn list_partition(&mut self, arguments: Vec<DataType>, func_token: &Token) -> Result<DataType, PakhiErr> {
+        if arguments.len() != 2 {
+            return Err(RuntimeError(func_token.line, func_token.src_file_path.clone(),
+                                    "_লিস্ট-পার্টিশন() function expects two argument".to_string()));
+        }
+        let elems = match &arguments[0] {
+            DataType::List(list_index) => self.lists[*list_index].clone(),
+            _ => return Err(RuntimeError(func_token.line, func_token.src_file_path.clone(),
+                                         "_লিস্ট-পার্টিশন() function's first argument must be list".to_string())),
+        };
+        let predicate = match &arguments[1] {
+            DataType::Function(predicate) => predicate.clone(),
+            _ => return Err(RuntimeError(func_token.line, func_token.src_file_path.clone(),
+                                         "_লিস্ট-পার্টিশন() function's second argument must be function".to_string())),
+        };
+
+        let mut matching: Vec<DataType> = Vec::new();
+        let mut non_matching: Vec<DataType> = Vec::new();
+        for elem in elems {
+            let passed = self.call_function(predicate.clone(), vec![elem.clone()], "_লিস্ট-পার্টিশন".to_string(),
+                                            func_token.line, func_token.src_file_path.clone())?;
+            match passed {
+                DataType::Bool(true) => matching.push(elem),
+                DataType::Bool(false) => non_matching.push(elem),
+                _ => return Err(RuntimeError(func_token.line, func_token.src_file_path.clone(),
+                                             "_লিস্ট-পার্টিশন() predicate function must return bool".to_string())),
+            }
+        }
+        let matching = self.create_new_list_datatype(matching);
+        let non_matching = self.create_new_list_datatype(non_matching);
+        Ok(self.create_new_list_datatype(vec![matching, non_matching]))
+    }
+
+    fn list_flat_map(&mut self, arguments: Vec<DataType>, func_token: &Token) -> Result<DataType, PakhiErr> {
+        if arguments.len() != 2 {
+            return Err(RuntimeError(func_token.line, func_token.src_file_path.clone(),
+                                    "_লিস্ট-ফ্ল্যাট-ম্যাপ() function expects two argument".to_string()));
+        }
+        let elems = match &arguments[0] {
+            DataType::List(list_index) => self.lists[*list_index].clone(),
+            _ => return Err(RuntimeError(func_token.line, func_token.src_file_path.clone(),
+                                         "_লিস্ট-ফ্ল্যাট-ম্যাপ() function's first argument must be list".to_string())),
+        };
+        let mapper = match &arguments[1] {
+            DataType::Function(mapper) => mapper.clone(),
+            _ => return Err(RuntimeError(func_token.line, func_token.src_file_path.clone(),
+                                         "_লিস্ট-ফ্ল্যাট-ম্যাপ() function's second argument must be function".to_string())),
+        };
+
+        let mut flattened: Vec<DataType> = Vec::new();
+        for elem in elems {
+            let mapped = self.call_function(mapper.clone(), vec![elem], "_লিস্ট-ফ্ল্যাট-ম্যাপ".to_string(),
+                                            func_token.line, func_token.src_file_path.clone())?;
+            match mapped {
+                // only one level is flattened, nested lists inside returned list are kept as it is
+                DataType::List(list_index) => flattened.extend(self.lists[list_index].iter().cloned()),
+                // non list result is kept as single element
+                other => flattened.push(other),
+            }
+        }
+        Ok(self.create_new_list_datatype(flattened))
+    }
+
+    fn list_scan(&mut self, arguments: Vec<DataType>, func_token: &Token) -> Result<DataType, PakhiErr> {
+        if arguments.len() != 3 {
+            return Err(RuntimeError(func_token.line, func_token.src_file_path.clone(),
+                                    "_লিস্ট-স্ক্যান() function expects three argument".to_string()));
+        }
+        let elems = match &arguments[0] {
+            DataType::List(list_index) => self.lists[*list_index].clone(),
+            _ => return Err(RuntimeError(func_token.line, func_token.src_file_path.clone(),
+                                         "_লিস্ট-স্ক্যান() function's first argument must be list".to_string())),
+        };
+        let accumulator_fn = match &arguments[1] {
+            DataType::Function(accumulator_fn) => accumulator_fn.clone(),
+            _ => return Err(RuntimeError(func_token.line, func_token.src_file_path.clone(),
+                                         "_লিস্ট-স্ক্যান() function's second argument must be function".to_string())),
+        };
+
+        // initial value is not part of result, only values returned by function are kept
+        let mut accumulator = arguments[2].clone();
+        let mut accumulated: Vec<DataType> = Vec::with_capacity(elems.len());
+        for elem in elems {
+            accumulator = self.call_function(accumulator_fn.clone(), vec![accumulator, elem], "_লিস্ট-স্ক্যান".to_string(),
+                                             func_token.line, func_token.src_file_path.clone())?;
+            accumulated.push(accumulator.clone());
+        }
+        Ok(self.create_new_list_datatype(accumulated))
+    }
+
+    // Calls function with elements at same index of both lists, extra elements of longer list are ignored
+    fn zip_with(&mut self, arguments: Vec<DataType>, func_token: &Token) -> Result<DataType, PakhiErr> {
+        if arguments.len() != 3 {
+            return Err(RuntimeError(func_token.line, func_token.src_file_path.clone(),
+                                    "_জিপ-দিয়ে() function expects three argument".to_string()));
+        }
+        let (first, second) = match (&arguments[0], &arguments[1]) {
+            (DataType::List(first), DataType::List(second)) => (self.lists[*first].clone(), self.lists[*second].clone()),
+            _ => return Err(RuntimeError(func_token.line, func_token.src_file_path.clone(),
+                                         "_জিপ-দিয়ে() function's first two arguments must be list".to_string())),
+        };
+        let combine_fn = match &arguments[2] {
+            DataType::Function(combine_fn) => combine_fn.clone(),
+            _ => return Err(RuntimeError(func_token.line, func_token.src_file_path.clone(),
+                                         "_জিপ-দিয়ে() function's third argument must be function".to_string())),
+        };
+
+        let mut combined: Vec<DataType> = Vec::with_capacity(first.len().min(second.len()));
+        for (a, b) in first.into_iter().zip(second) {
+            combined.push(self.call_function(combine_fn.clone(), vec![a, b], "_জিপ-দিয়ে".to_string(),
+                                             func_token.line, func_token.src_file_path.clone())?);
+        }
+        Ok(self.create_new_list_datatype(combined))
+    }
 
+    fn interpret_func_call_expr(&mut self, f: parser::FunctionCall) -> Result<DataType, PakhiErr> {
         match *f.expr.clone() {
             parser::Expr::Primary(parser::Primary::Var(func_token), _, _) => {
                 //  Checking if function is built-in
                 if self.built_in_functions.is_built_in(&func_token.lexeme) {
                     // Function is definitely built-in
                     return self.call_built_in_function(&f, &func_token); // this will return DataType or panic)
+                } else if self.host_functions.contains_key(&String::from_iter(func_token.lexeme.iter())) {
+                    let mut args: Vec<DataType> = Vec::new();
+                    for arg in f.arguments.iter() {
+                        args.push(self.interpret_expr(arg.clone())?);
+                    }
+                    let host_fn = self.host_functions.get_mut(&String::from_iter(func_token.lexeme.iter())).unwrap();
+                    host_fn(args).map_err(|err| RuntimeError(func_token.line, func_token.src_file_path.clone(), err))
                 } else {
                     // These are for error reporting
                     let line = func_token.line;
@@ -1082,33 +2582,27 @@ impl<'a, T: 'a + IO> Interpreter<'a, T> {
 
                     // Functions is definitely user-defined and not built-in
 
-                    // this block checks if function was declared,
-                    // sets up environment, inserts args to new environment
-                    // and saves return address for function call
-                    let func = self.interpret_var(func_token)?;
+                    // this block checks if function was declared and evaluates arguments
+                    let func = match self.interpret_var(func_token) {
+                        Ok(func) => func,
+                        Err(_) => return Err(RuntimeError(line, src_path,
+                                                          format!("Function '{}' not Declared", func_name))),
+                    };
 
                     if let DataType::Function(func) = func {
-                        let mut root_env: HashMap<String, Option<DataType>> = HashMap::new();
-                        for i in 0..func.args.len() {
-                            if i < f.arguments.len() {
-                                let arg = self.interpret_expr(f.arguments[i].clone())?;
-                                root_env.insert(func.args[i].clone(), Option::from(arg));
-                            } else {
-                                // not enough arguments passed so assigning Nil
-                                root_env.insert(func.args[i].clone(), Option::from(DataType::Nil));
-                            }
+                        if f.arguments.len() != func.args.len() {
+                            return Err(RuntimeError(line, src_path,
+                                                    format!("Function '{}' expects {} argument, but {} passed",
+                                                            func_name, func.args.len(), f.arguments.len())));
                         }
-
-                        // creating root_envs
-                        self.scopes.push(root_env);
-
-                        self.return_addrs.push(self.current);
-
-                        // pointing current to functions starting statement
-                        self.current = func.starting_statement;
+                        let mut args: Vec<DataType> = Vec::new();
+                        for arg in f.arguments.iter() {
+                            args.push(self.interpret_expr(arg.clone())?);
+                        }
+                        self.call_function(func, args, func_name, line, src_path)
                     } else {
-                        return Err(RuntimeError(line, src_path,
-                                                format!("Function '{}' not Declared", func_name)));
+                        // variable exists, but its value can't be called
+                        Err(TypeError(line, src_path, format!("{} is not a function", func_name)))
                     }
                 }
 
@@ -1118,49 +2612,171 @@ impl<'a, T: 'a + IO> Interpreter<'a, T> {
                 return Err(RuntimeError(line, file_name, "Calling undefined function".to_string()));
             },
         }
+    }
+
+    // Calls user-defined function with already evaluated arguments, also used by built-in
+    // functions which take function as argument
+    fn call_function(&mut self,
+                     func: Func,
+                     args: Vec<DataType>,
+                     func_name: String,
+                     line: u32,
+                     src_path: String) -> Result<DataType, PakhiErr>
+    {
+        if let Some(cache_index) = func.memo {
+            let mut key = String::new();
+            for arg in args.iter() {
+                self.memo_key(arg, &mut Vec::new(), &mut key);
+                key.push(',');
+            }
+            if let Some(result) = self.memo_caches[cache_index].get(&key) {
+                return Ok(result.clone());
+            }
+
+            let unmemoized = Func { memo: None, ..func };
+            let result = self.call_function(unmemoized, args, func_name, line, src_path)?;
+            match result {
+                // lists and records aren't cached because gc doesn't see values inside cache
+                DataType::List(_) | DataType::NamelessRecord(_) | DataType::Function(_) | DataType::FileHandle(_) => {},
+                _ => { self.memo_caches[cache_index].insert(key, result.clone()); },
+            }
+            return Ok(result);
+        }
+
+        if self.call_stack.len() >= self.max_call_depth {
+            return Err(RuntimeError(line, src_path, "Maximum recursion depth exceeded".to_string()));
+        }
+
+        let env_count_before_fn_call = self.scopes.len();
+        let loop_count_before_fn_call = self.loops.len();
+        let if_count_before_fn_call = self.previous_if_was_executed.len();
+
+        // sets up environment, inserts args to new environment
+        // and saves return address for function call
+        let mut root_env: HashMap<String, Option<DataType>> = HashMap::new();
+        let mut args = args.into_iter();
+        for arg_name in func.args.iter() {
+            // if not enough arguments passed assigning Nil
+            let arg = args.next().unwrap_or(DataType::Nil);
+            root_env.insert(arg_name.clone(), Option::from(arg));
+        }
+
+        // creating root_envs
+        self.scopes.push(root_env);
+
+        self.return_addrs.push(self.current);
+        self.call_stack.push(CallFrame { function_name: func_name, call_line: line, src_file_path: src_path });
+        self.temporaries.push(Vec::new());
+
+        // pointing current to functions starting statement
+        self.current = func.starting_statement;
 
         // jumping to function start and starting executing statements in function body
 
-        match &self.statements[self.current] {
-            parser::Stmt::BlockStart(_, _) => {},
-            // TODO show file name and line number by matching all enum variant
-            _ => self.io.panic(PakhiErr::UnexpectedError("Expected '{'".to_string())),
+        // cleanup must happen on error path too, so that caller can continue
+        // with correct state if error is handled
+        // function body may call functions again, stack is grown on heap when it is almost full
+        // so that max_call_depth calls don't overflow native stack on any thread
+        let return_val = stacker::maybe_grow(STACK_RED_ZONE, STACK_GROWTH_SIZE, || {
+            match self.statement(self.current) {
+                parser::Stmt::BlockStart(_, _) => self.interpret_function_body(),
+                // TODO show file name and line number by matching all enum variant
+                _ => Err(PakhiErr::UnexpectedError("Expected '{'".to_string())),
+            }
+        });
+
+        self.current = self.return_addrs.pop().unwrap();
+        self.call_stack.pop();
+        self.temporaries.pop();
+        // returned value is a temporary of caller's statement
+        if let Ok(value) = &return_val {
+            self.keep_temporary(value);
+        }
+
+        let env_count_after_fn_call = self.scopes.len();
+        let envs_created_inside_fn = env_count_after_fn_call - env_count_before_fn_call;
+        for _ in 0..envs_created_inside_fn {
+            // return can also happen mid function without reaching blockEnd '}' statement
+            // so half used env must be destroyed manually
+            self.scopes.pop();
         }
+        // same as envs, loops and if statements inside function can be left
+        // unfinished when returning from inside them
+        self.loops.truncate(loop_count_before_fn_call);
+        self.previous_if_was_executed.truncate(if_count_before_fn_call);
+
+        return_val
+    }
 
-        // assert_eq!(parser::Stmt::BlockStart, self.statements[self.current]);
-        // interpreting all statements inside function body
-        // assuming self.current was set at function start
+    // Interprets statements of function body until a return statement and evaluates its
+    // expression, assumes self.current was set at function start
+    fn interpret_function_body(&mut self) -> Result<DataType, PakhiErr> {
         loop {
-            if let parser::Stmt::Return(_, _, _) = self.statements[self.current].clone() {
+            if let parser::Stmt::Return(_, _, _) = self.statement(self.current).clone() {
                 break;
             } else {
                 self.interpret()?;
+                // long running function may allocate a lot before returning, so garbage is
+                // collected between statements of function body too
+                self.temporaries.last_mut().unwrap().clear();
+                self.collect_garbage_if_needed();
             }
         }
 
-        if let parser::Stmt::Return(expr, _, _) = self.statements[self.current].clone() {
-            let return_val = self.interpret_expr(expr);
-            self.current = self.return_addrs.pop().unwrap();
-
-            let env_count_after_fn_call = self.scopes.len();
-            let envs_created_inside_fn = env_count_after_fn_call - env_count_before_fn_call;
-            for _ in 0..envs_created_inside_fn {
-                // return can also happen mid function without reaching blockEnd '}' statement
-                // so half used env must be destroyed manually
-                self.scopes.pop();
-            }
-
-            return return_val;
+        if let parser::Stmt::Return(expr, line, file_name) = self.statement(self.current).clone() {
+            return match self.interpret_expr(expr) {
+                Ok(return_val) => Ok(return_val),
+                // errors without location are reported at return statement
+                Err(PakhiErr::UnexpectedError(err)) => Err(RuntimeError(line, file_name, err)),
+                Err(err) => Err(err),
+            };
         }
 
         let (line, file_name) = self.extract_err_meta_stmt(self.current)?;
         return Err(RuntimeError(line, file_name, "Error calling function".to_string()));
     }
 
+    // Writes a key to out which is equal for structurally equal values, visiting
+    // holds lists and records currently being visited so that cyclic lists terminate
+    fn memo_key(&self, data: &DataType, visiting: &mut Vec<DataType>, out: &mut String) {
+        match data {
+            DataType::Num(n) => out.push_str(&format!("n{}", n)),
+            DataType::Bool(b) => out.push_str(&format!("b{}", b)),
+            DataType::String(s) => out.push_str(&format!("s{}:{}", s.chars().count(), s)),
+            DataType::Nil => out.push('_'),
+            DataType::Function(func) => out.push_str(&format!("f{}", func.starting_statement)),
+            DataType::FileHandle(i) => out.push_str(&format!("h{}", i)),
+            DataType::List(_) | DataType::NamelessRecord(_) if visiting.contains(data) => out.push('^'),
+            DataType::List(i) => {
+                visiting.push(data.clone());
+                out.push('[');
+                for elem in self.lists[*i].iter() {
+                    self.memo_key(elem, visiting, out);
+                    out.push(',');
+                }
+                out.push(']');
+                visiting.pop();
+            },
+            DataType::NamelessRecord(i) => {
+                visiting.push(data.clone());
+                let mut keys: Vec<&String> = self.nameless_records[*i].keys().collect();
+                keys.sort();
+                out.push('{');
+                for k in keys {
+                    out.push_str(&format!("{}:{}", k.chars().count(), k));
+                    self.memo_key(&self.nameless_records[*i][k], visiting, out);
+                    out.push(',');
+                }
+                out.push('}');
+                visiting.pop();
+            },
+        }
+    }
+
     fn interpret_primary_expr(&mut self, p: parser::Primary) -> Result<DataType, PakhiErr> {
         match p {
             parser::Primary::Nil => return Ok(DataType::Nil),
-            parser::Primary::String(s) => return Ok(DataType::String(s)),
+            parser::Primary::String(s) => Ok(DataType::String(s.into())),
             parser::Primary::Num(n) => return Ok(DataType::Num(n)),
             parser::Primary::Bool(b) => return Ok(DataType::Bool(b)),
             parser::Primary::Var(v) => {
@@ -1188,7 +2804,7 @@ impl<'a, T: 'a + IO> Interpreter<'a, T> {
                     let key = self.interpret_expr(k.clone())?;
                     if let DataType::String(string_key) = key {
                         let new_val = self.interpret_expr(key_values.1[i].clone())?;
-                        record.insert(string_key, new_val);
+                        record.insert(string_key.to_string(), new_val);
                     }
                 }
 
@@ -1224,40 +2840,32 @@ impl<'a, T: 'a + IO> Interpreter<'a, T> {
     fn interpret_and_expr(&mut self, and_expr: parser::And) -> Result<DataType, PakhiErr> {
         let (line, file_name) = self.extract_expr_err_meta(&and_expr.left.clone());
 
-        let right_expr_val = self.interpret_expr(*and_expr.right)?;
-        let left_expr_val = self.interpret_expr(*and_expr.left)?;
+        // right side is only evaluated when left side doesn't decide the result
+        match self.interpret_expr(*and_expr.left)? {
+            DataType::Bool(false) => return Ok(DataType::Bool(false)),
+            DataType::Bool(_) => {},
+            _ => return Err(TypeError(line, file_name, "Datatype doesn't support and operation".to_string())),
+        }
 
-        match (right_expr_val, left_expr_val) {
-            (DataType::Bool(right), DataType::Bool(left)) => return Ok(DataType::Bool(right && left)),
-            (DataType::Bool(_), _) => {
-                return Err(TypeError(line, file_name, "Datatype doesn't support and operation".to_string()));
-            },
-            (_, DataType::Bool(_)) => {
-                return Err(TypeError(line, file_name, "Datatype doesn't support and operation".to_string()));
-            }
-            _ => {
-                return Err(TypeError(line, file_name, "Datatype doesn't support and operation".to_string()));
-            }
+        match self.interpret_expr(*and_expr.right)? {
+            DataType::Bool(right) => Ok(DataType::Bool(right)),
+            _ => Err(TypeError(line, file_name, "Datatype doesn't support and operation".to_string())),
         }
     }
 
     fn interpret_or_expr(&mut self, or_expr: parser::Or) -> Result<DataType, PakhiErr> {
         let (line, file_name) = self.extract_expr_err_meta(&or_expr.left.clone());
 
-        let right_expr_val = self.interpret_expr(*or_expr.right)?;
-        let left_expr_val = self.interpret_expr(*or_expr.left)?;
+        // right side is only evaluated when left side doesn't decide the result
+        match self.interpret_expr(*or_expr.left)? {
+            DataType::Bool(true) => return Ok(DataType::Bool(true)),
+            DataType::Bool(_) => {},
+            _ => return Err(TypeError(line, file_name, "Datatype doesn't support or operation".to_string())),
+        }
 
-        match (right_expr_val, left_expr_val) {
-            (DataType::Bool(right), DataType::Bool(left)) => return Ok(DataType::Bool(right || left)),
-            (DataType::Bool(_), _) => {
-                return Err(TypeError(line, file_name, "Datatype doesn't support or operation".to_string()));
-            },
-            (_, DataType::Bool(_)) => {
-                return Err(TypeError(line, file_name, "Datatype doesn't support or operation".to_string()));
-            }
-            _ => {
-                return Err(TypeError(line, file_name, "Datatype doesn't support or operation".to_string()));
-            }
+        match self.interpret_expr(*or_expr.right)? {
+            DataType::Bool(right) => Ok(DataType::Bool(right)),
+            _ => Err(TypeError(line, file_name, "Datatype doesn't support or operation".to_string())),
         }
     }
 
@@ -1279,7 +2887,7 @@ impl<'a, T: 'a + IO> Interpreter<'a, T> {
             },
             (DataType::String(left_str), DataType::String(right_str)) => {
                 if addsub_expr.operator == TokenKind::Plus {
-                    return Ok(DataType::String(format!("{}{}", left_str, right_str)));
+                    return Ok(DataType::String(format!("{}{}", left_str, right_str).into()));
                 }
 
                 return Err(TypeError(line, file_name, "Invalid operation string type".to_string()));
@@ -1311,12 +2919,15 @@ impl<'a, T: 'a + IO> Interpreter<'a, T> {
     fn interpret_muldiv_remainder_expr(&mut self, muldiv_expr: parser::Binary) -> Result<DataType, PakhiErr> {
         let (line, file_name) = self.extract_expr_err_meta(&*muldiv_expr.left);
 
-        let right_expr_val = self.interpret_expr(*muldiv_expr.right)?;
         let left_expr_val = self.interpret_expr(*muldiv_expr.left)?;
+        let right_expr_val = self.interpret_expr(*muldiv_expr.right)?;
 
         if let DataType::Num(right)  = right_expr_val {
             if let DataType::Num(left) = left_expr_val {
                 match muldiv_expr.operator {
+                    TokenKind::Division | TokenKind::Remainder if right == 0.0 => {
+                        return Err(RuntimeError(line, file_name, "Division by zero".to_string()));
+                    },
                     TokenKind::Multiply => return Ok(DataType::Num(left * right)),
                     TokenKind::Division => return Ok(DataType::Num(left / right)),
                     TokenKind::Remainder => return Ok(DataType::Num(left % right)),
@@ -1338,10 +2949,10 @@ impl<'a, T: 'a + IO> Interpreter<'a, T> {
 
         match eq_expr.operator {
             TokenKind::EqualEqual => {
-                return Ok(DataType::Bool(evaluated_left_expr == evaluated_right_expr));
+                Ok(DataType::Bool(Interpreter::<T>::data_eq(&evaluated_left_expr, &evaluated_right_expr)))
             },
             TokenKind::NotEqual =>  {
-                return Ok(DataType::Bool(evaluated_left_expr != evaluated_right_expr ));
+                Ok(DataType::Bool(!Interpreter::<T>::data_eq(&evaluated_left_expr, &evaluated_right_expr)))
             },
             _ => {
                 return Err(TypeError(line, file_name, "Type doesn't support operation".to_string()));
@@ -1349,6 +2960,14 @@ impl<'a, T: 'a + IO> Interpreter<'a, T> {
         }
     }
 
+    fn data_eq(left: &DataType, right: &DataType) -> bool {
+        match (left, right) {
+            // Interned strings share storage, so comparing characters can be skipped
+            (DataType::String(l), DataType::String(r)) if Rc::ptr_eq(l, r) => true,
+            _ => left == right,
+        }
+    }
+
     fn interpret_comp_expr(&mut self, comp_expr: parser::Binary) -> Result<DataType, PakhiErr> {
         let (line, file_name) = self.extract_expr_err_meta(&*comp_expr.left.clone());
 
@@ -1408,37 +3027,74 @@ impl<'a, T: 'a + IO> Interpreter<'a, T> {
         // self.total_allocated_object_count is used as a parameter in gc to determine
         // if its time collect garbage
         self.total_allocated_object_count += new_list.len();
+        let new_list: Vec<DataType> = new_list.into_iter().map(|d| self.intern_string(d)).collect();
 
-        if self.free_lists.len() > 0 {
+        let list = if !self.free_lists.is_empty() {
             let free_index = self.free_lists.pop().unwrap();
+            self.frozen_lists.remove(&free_index);
             self.lists[free_index] = new_list;
-            return DataType::List(free_index);
+            DataType::List(free_index)
         } else {
             self.lists.push(new_list);
-            return DataType::List(self.lists.len() - 1);
-        }
+            DataType::List(self.lists.len() - 1)
+        };
+        // newly allocated list must not be swept by an already running incremental gc
+        self.gc_write_barrier(&list);
+        list
     }
 
     fn create_new_nameless_record_datatype(&mut self, new_record: HashMap<String, DataType>) -> DataType {
         // self.total_allocated_object_count is used as a parameter in gc to determine
         // if its time collect garbage
         self.total_allocated_object_count += new_record.len();
+        let new_record: HashMap<String, DataType> = new_record.into_iter()
+            .map(|(k, v)| (k, self.intern_string(v))).collect();
 
-        if self.free_nameless_records.len() > 0 {
+        let record = if !self.free_nameless_records.is_empty() {
             let free_index = self.free_nameless_records.pop().unwrap();
+            self.frozen_records.remove(&free_index);
             self.nameless_records[free_index] = new_record;
-            return DataType::NamelessRecord(free_index);
+            DataType::NamelessRecord(free_index)
         } else {
             self.nameless_records.push(new_record);
-            return DataType::NamelessRecord(self.nameless_records.len() - 1);
+            DataType::NamelessRecord(self.nameless_records.len() - 1)
+        };
+        // newly allocated record must not be swept by an already running incremental gc
+        self.gc_write_barrier(&record);
+        record
+    }
+
+    // Converts parsed json tree to pakhi datatype, json arrays and objects are allocated
+    // as pakhi list and record
+    fn json_to_datatype(&mut self, json_value: JsonValue) -> DataType {
+        match json_value {
+            JsonValue::Null => DataType::Nil,
+            JsonValue::Bool(b) => DataType::Bool(b),
+            JsonValue::Num(n) => DataType::Num(n),
+            JsonValue::String(s) => DataType::String(s.into()),
+            JsonValue::Array(elems) => {
+                let mut pakhi_list: Vec<DataType> = Vec::new();
+                for elem in elems {
+                    pakhi_list.push(self.json_to_datatype(elem));
+                }
+                self.create_new_list_datatype(pakhi_list)
+            },
+            JsonValue::Object(pairs) => {
+                let mut record: HashMap<String, DataType> = HashMap::new();
+                for (key, value) in pairs {
+                    let value = self.json_to_datatype(value);
+                    record.insert(key, value);
+                }
+                self.create_new_nameless_record_datatype(record)
+            },
         }
     }
 
     fn extract_err_meta_stmt(&self, i: usize) -> Result<(u32, String), PakhiErr> {
-        if self.current >= self.statements.len() {
+        if !self.statement_exists(self.current) {
             return Err(PakhiErr::UnexpectedError("Unexpected error, probably missing ';'".to_string()));
         } else {
-            match &self.statements[i] {
+            match self.statement(i) {
                 parser::Stmt::Print(_, line, file_name) => Ok((line.clone(), file_name.clone())),
                 parser::Stmt::PrintNoEOL(_, line, file_name) => Ok((line.clone(), file_name.clone())),
                 parser::Stmt::Assignment(_, line, file_name) => Ok((line.clone(), file_name.clone())),
@@ -1449,6 +3105,7 @@ impl<'a, T: 'a + IO> Interpreter<'a, T> {
                 parser::Stmt::Return(_, line, file_name) => Ok((line.clone(), file_name.clone())),
                 parser::Stmt::If(_, line, file_name) => Ok((line.clone(), file_name.clone())),
                 parser::Stmt::Loop(line, file_name) => Ok((line.clone(), file_name.clone())),
+                parser::Stmt::ForEach(_, line, file_name) => Ok((*line, file_name.clone())),
                 parser::Stmt::Continue(line, file_name) => Ok((line.clone(), file_name.clone())),
                 parser::Stmt::Break(line, file_name) => Ok((line.clone(), file_name.clone())),
                 parser::Stmt::Else(line, file_name) => Ok((line.clone(), file_name.clone())),
@@ -1469,10 +3126,26 @@ impl<'a, T: 'a + IO> Interpreter<'a, T> {
             parser::Expr::Unary(_, line, file_name) => (line.clone(), file_name.clone()),
             parser::Expr::Call(_, line, file_name) => (line.clone(), file_name.clone()),
             parser::Expr::Primary(_, line, file_name) => (line.clone(), file_name.clone()),
+            parser::Expr::Range(_, _, line, file_name) => (*line, file_name.clone()),
        }
     }
 
+    fn num_to_output_string(&self, n: f64) -> Result<String, PakhiErr> {
+        if self.ascii_digits {
+            Ok(n.to_string())
+        } else {
+            self.to_bn_num(n)
+        }
+    }
+
     fn to_bn_num(&self, n: f64) -> Result<String, PakhiErr> {
+        // overflowed or undefined calculation results don't have any digit to convert
+        if n.is_nan() {
+            return Ok("অসংজ্ঞায়িত".to_string());
+        } else if n.is_infinite() {
+            return Ok(if n > 0.0 { "অসীম".to_string() } else { "-অসীম".to_string() });
+        }
+
         let n_chars: Vec<char> = n.to_string().chars().collect();
 
         let mut bangla_num_string = String::new();
@@ -1480,6 +3153,9 @@ impl<'a, T: 'a + IO> Interpreter<'a, T> {
            match digit {
                '-' => bangla_num_string.push('-'),
                '.' => bangla_num_string.push('.'),
+               // exponent part of scientific notation
+               'e' | 'E' => bangla_num_string.push('e'),
+               '+' => bangla_num_string.push('+'),
                '0' => bangla_num_string.push('০'),
                '1' => bangla_num_string.push('১'),
                '2' => bangla_num_string.push('২'),
@@ -1509,8 +3185,96 @@ impl<'a, T: 'a + IO> Interpreter<'a, T> {
     }
 }
 
-pub fn run(ast: Vec<parser::Stmt>) -> Result<(), PakhiErr> {
-    let mut real_io = RealIO::new();
-    let mut interpreter = Interpreter::new(ast, &mut real_io);
-    return interpreter.run();
-}
\ No newline at end of file
+// Every pakhi function call uses native stack, debug build uses more than 10KB per call.
+// When less than STACK_RED_ZONE is left, new stack of STACK_GROWTH_SIZE is allocated
+const STACK_RED_ZONE: usize = 256 * 1024;
+const STACK_GROWTH_SIZE: usize = 4 * 1024 * 1024;
+
+pub fn run<T: IO>(ast: Vec<parser::Stmt>, io: &mut T, strict: bool) -> Result<(), PakhiErr> {
+    let mut interpreter = Interpreter::new(ast, io);
+    interpreter.set_strict(strict);
+    interpreter.run()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::backend::interpreter::{DataType, Interpreter};
+    use crate::common::io::{IO, MockIO};
+    use crate::frontend::{lexer, parser};
+
+    #[test]
+    fn collect_garbage_now_ignores_threshold() {
+        let src = "নাম রাখা = [১, [২]];\n\
+                   নাম ক = ০;\n\
+                   লুপ {\n\
+                       নাম ফেলা = [ক, [ক]];\n\
+                       ক = ক + ১;\n\
+                       যদি ক == ১০০ {\n\
+                           থামাও;\n\
+                       }\n\
+                   } আবার;";
+        let tokens = lexer::tokenize(src.chars().collect(), "test.pakhi".to_string()).unwrap();
+        let ast = parser::parse("test.pakhi".to_string(), tokens).unwrap();
+        let mut mock_io = MockIO::new();
+        let mut interpreter = Interpreter::new(ast, &mut mock_io);
+        interpreter.set_gc_threshold(usize::MAX);
+        interpreter.run().unwrap();
+        assert!(interpreter.free_lists.is_empty());
+
+        interpreter.collect_garbage_now();
+        // every list created in loop is garbage
+        assert_eq!(200, interpreter.free_lists.len());
+        let kept = match interpreter.scopes[0].get("রাখা") {
+            Some(Some(DataType::List(index))) => interpreter.lists[*index].clone(),
+            _ => panic!("রাখা must be a list"),
+        };
+        match kept.as_slice() {
+            [DataType::Num(n), DataType::List(inner)] => {
+                assert_eq!(1.0, *n);
+                assert_eq!(vec![DataType::Num(2.0)], interpreter.lists[*inner]);
+            },
+            _ => panic!("রাখা was modified by gc"),
+        }
+    }
+
+    #[test]
+    fn eval_keeps_only_function_definitions() {
+        let src = "_ইভাল(\"নাম ক = ১; ফাং এক() { ফেরত ক; } ফেরত; দেখাও ক;\");\n\
+                   দেখাও এক();";
+        let tokens = lexer::tokenize(src.chars().collect(), "test.pakhi".to_string()).unwrap();
+        let ast = parser::parse("test.pakhi".to_string(), tokens).unwrap();
+        let program_len = ast.len();
+        let mut mock_io = MockIO::new();
+        mock_io.expect_println("১");
+        mock_io.expect_println("১");
+        let mut interpreter = Interpreter::new(ast, &mut mock_io);
+        interpreter.run().unwrap();
+        // ফাং, function name and args, {, ফেরত ক, } and ফেরত
+        assert_eq!(program_len + 6, interpreter.statements.len());
+        mock_io.assert_all_true();
+    }
+
+    #[test]
+    fn eval_keeps_same_function_definition_once() {
+        let src = "নাম ক = ০;\n\
+                   লুপ {\n\
+                       _ইভাল(\"ফাং এক() { ফেরত ১; } ফেরত; _ইভাল(\\\"ফাং দুই() { ফেরত ২; } ফেরত;\\\");\");\n\
+                       ক = ক + এক() + দুই();\n\
+                       যদি ক == ৩০ {\n\
+                           থামাও;\n\
+                       }\n\
+                   } আবার;\n\
+                   দেখাও ক;";
+        let tokens = lexer::tokenize(src.chars().collect(), "test.pakhi".to_string()).unwrap();
+        let ast = parser::parse("test.pakhi".to_string(), tokens).unwrap();
+        let program_len = ast.len();
+        let mut mock_io = MockIO::new();
+        mock_io.expect_println("৩০");
+        let mut interpreter = Interpreter::new(ast, &mut mock_io);
+        interpreter.run().unwrap();
+        // each of two definitions is kept once, 6 statements each
+        assert_eq!(program_len + 12, interpreter.statements.len());
+        assert!(interpreter.eval_statements.is_empty());
+        mock_io.assert_all_true();
+    }
+}
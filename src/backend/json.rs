@@ -0,0 +1,235 @@
+// Minimal json parser used by _জেসন-পার্স built-in functions.
+// It only builds an intermediate tree, interpreter converts this tree to pakhi
+// list and record so that allocated objects are tracked by gc
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) enum JsonValue {
+    Null,
+    Bool(bool),
+    Num(f64),
+    String(String),
+    Array(Vec<JsonValue>),
+    // Keeping key-value pairs in source order
+    Object(Vec<(String, JsonValue)>),
+}
+
+pub(crate) fn parse(src: &str) -> Result<JsonValue, String> {
+    let chars: Vec<char> = src.chars().collect();
+    let mut parser = JsonParser { chars, current: 0 };
+
+    let value = parser.value()?;
+    parser.skip_whitespace();
+    if parser.current < parser.chars.len() {
+        return Err(format!("Unexpected character '{}' after json value", parser.chars[parser.current]));
+    }
+    Ok(value)
+}
+
+struct JsonParser {
+    chars: Vec<char>,
+    current: usize,
+}
+
+impl JsonParser {
+    fn value(&mut self) -> Result<JsonValue, String> {
+        self.skip_whitespace();
+        match self.peek() {
+            Some('{') => self.object(),
+            Some('[') => self.array(),
+            Some('"') => Ok(JsonValue::String(self.string()?)),
+            Some('t') => self.literal("true", JsonValue::Bool(true)),
+            Some('f') => self.literal("false", JsonValue::Bool(false)),
+            Some('n') => self.literal("null", JsonValue::Null),
+            Some(c) if c == '-' || c.is_ascii_digit() => self.number(),
+            Some(c) => Err(format!("Unexpected character '{}' in json", c)),
+            None => Err("Unexpected end of json".to_string()),
+        }
+    }
+
+    fn object(&mut self) -> Result<JsonValue, String> {
+        // consuming {
+        self.current += 1;
+        let mut pairs: Vec<(String, JsonValue)> = Vec::new();
+
+        self.skip_whitespace();
+        if self.peek() == Some('}') {
+            self.current += 1;
+            return Ok(JsonValue::Object(pairs));
+        }
+
+        loop {
+            self.skip_whitespace();
+            if self.peek() != Some('"') {
+                return Err("Expected string as json object key".to_string());
+            }
+            let key = self.string()?;
+
+            self.skip_whitespace();
+            if self.peek() != Some(':') {
+                return Err("Expected ':' after json object key".to_string());
+            }
+            // consuming :
+            self.current += 1;
+
+            let value = self.value()?;
+            pairs.push((key, value));
+
+            self.skip_whitespace();
+            match self.peek() {
+                Some(',') => self.current += 1,
+                Some('}') => {
+                    self.current += 1;
+                    return Ok(JsonValue::Object(pairs));
+                },
+                _ => return Err("Expected ',' or '}' in json object".to_string()),
+            }
+        }
+    }
+
+    fn array(&mut self) -> Result<JsonValue, String> {
+        // consuming [
+        self.current += 1;
+        let mut elems: Vec<JsonValue> = Vec::new();
+
+        self.skip_whitespace();
+        if self.peek() == Some(']') {
+            self.current += 1;
+            return Ok(JsonValue::Array(elems));
+        }
+
+        loop {
+            let value = self.value()?;
+            elems.push(value);
+
+            self.skip_whitespace();
+            match self.peek() {
+                Some(',') => self.current += 1,
+                Some(']') => {
+                    self.current += 1;
+                    return Ok(JsonValue::Array(elems));
+                },
+                _ => return Err("Expected ',' or ']' in json array".to_string()),
+            }
+        }
+    }
+
+    fn string(&mut self) -> Result<String, String> {
+        // consuming opening "
+        self.current += 1;
+        let mut val = String::new();
+
+        loop {
+            match self.peek() {
+                Some('"') => {
+                    self.current += 1;
+                    return Ok(val);
+                },
+                Some('\\') => {
+                    self.current += 1;
+                    let escaped = match self.peek() {
+                        Some('"') => '"',
+                        Some('\\') => '\\',
+                        Some('/') => '/',
+                        Some('b') => '\u{8}',
+                        Some('f') => '\u{c}',
+                        Some('n') => '\n',
+                        Some('r') => '\r',
+                        Some('t') => '\t',
+                        Some('u') => {
+                            // consuming u, hex_code consumes all four hex digits
+                            self.current += 1;
+                            let code = self.hex_code()?;
+                            val.push(code);
+                            continue;
+                        },
+                        _ => return Err("Invalid escape sequence in json string".to_string()),
+                    };
+                    val.push(escaped);
+                    self.current += 1;
+                },
+                Some(c) => {
+                    val.push(c);
+                    self.current += 1;
+                },
+                None => return Err("Json string wasn't closed".to_string()),
+            }
+        }
+    }
+
+    // Characters outside basic multilingual plane are escaped as utf-16 surrogate pair
+    // like \uD83D\uDE00, high surrogate must be followed by low surrogate
+    fn hex_code(&mut self) -> Result<char, String> {
+        let (code, hex) = self.hex_unit()?;
+        if (0xD800..0xDC00).contains(&code) && self.chars.get(self.current) == Some(&'\\')
+            && self.chars.get(self.current + 1) == Some(&'u') {
+            let low_start = self.current;
+            // consuming \u of low surrogate
+            self.current += 2;
+            let (low, _) = self.hex_unit()?;
+            if (0xDC00..0xE000).contains(&low) {
+                let combined = 0x10000 + ((code - 0xD800) << 10) + (low - 0xDC00);
+                if let Some(c) = std::char::from_u32(combined) {
+                    return Ok(c);
+                }
+            }
+            self.current = low_start;
+        }
+        match std::char::from_u32(code) {
+            Some(c) => Ok(c),
+            None => Err(format!("Invalid unicode code point \\u{} in json string", hex)),
+        }
+    }
+
+    // Consumes four hex digits, returns their value and digits
+    fn hex_unit(&mut self) -> Result<(u32, String), String> {
+        if self.current + 4 > self.chars.len() {
+            return Err("Invalid unicode escape in json string".to_string());
+        }
+        let hex: String = self.chars[self.current..(self.current + 4)].iter().collect();
+        self.current += 4;
+        match u32::from_str_radix(&hex, 16) {
+            Ok(code) => Ok((code, hex)),
+            Err(_) => Err(format!("Invalid unicode escape \\u{} in json string", hex)),
+        }
+    }
+
+    fn number(&mut self) -> Result<JsonValue, String> {
+        let start = self.current;
+        while let Some(c) = self.peek() {
+            if c.is_ascii_digit() || c == '-' || c == '+' || c == '.' || c == 'e' || c == 'E' {
+                self.current += 1;
+            } else {
+                break;
+            }
+        }
+        let num_string: String = self.chars[start..self.current].iter().collect();
+        match num_string.parse::<f64>() {
+            Ok(n) => Ok(JsonValue::Num(n)),
+            Err(_) => Err(format!("Invalid json number {}", num_string)),
+        }
+    }
+
+    fn literal(&mut self, expected: &str, value: JsonValue) -> Result<JsonValue, String> {
+        let expected_chars: Vec<char> = expected.chars().collect();
+        let end = self.current + expected_chars.len();
+        if end <= self.chars.len() && self.chars[self.current..end] == expected_chars[..] {
+            self.current = end;
+            Ok(value)
+        } else {
+            Err(format!("Invalid json literal, expected {}", expected))
+        }
+    }
+
+    fn skip_whitespace(&mut self) {
+        while let Some(c) = self.peek() {
+            if c == ' ' || c == '\n' || c == '\r' || c == '\t' {
+                self.current += 1;
+            } else {
+                break;
+            }
+        }
+    }
+
+    fn peek(&self) -> Option<char> {
+        self.chars.get(self.current).copied()
+    }
+}
@@ -1,6 +1,9 @@
 use std::collections::HashMap;
 use crate::backend::interpreter::DataType;
 
+// Maximum number of objects scanned in one step of incremental collection
+pub(crate) const INCREMENTAL_STEP_BUDGET: usize = 100;
+
 // Implementation of a mark-sweep garbage collector
 pub(crate) struct GC<'a> {
     envs: &'a mut Vec<HashMap<String, Option<DataType>>>,
@@ -31,6 +34,70 @@ impl<'a> GC<'a> {
         self.gc_sweep(marked_lists, marked_nameless_records);
     }
 
+    // Starts an incremental collection, only root objects are marked here. Rest of the
+    // marking is done by incremental_step so that program isn't paused for whole collection
+    pub(crate) fn start_incremental(&mut self) -> IncrementalState {
+        let mut state = IncrementalState {
+            gray_lists: Vec::new(),
+            gray_records: Vec::new(),
+            marked_lists: vec![false; self.lists.len()],
+            marked_records: vec![false; self.nameless_records.len()],
+        };
+        let (root_lists, root_records) = self.find_root_objects();
+        for list_index in root_lists {
+            state.shade(&DataType::List(list_index));
+        }
+        for record_index in root_records {
+            state.shade(&DataType::NamelessRecord(record_index));
+        }
+        state
+    }
+
+    // Scans at most budget gray objects. Returns true if marking finished and garbage was swept
+    pub(crate) fn incremental_step(&mut self, state: &mut IncrementalState, budget: usize) -> bool {
+        let mut scanned = 0;
+        while scanned < budget {
+            if let Some(list_index) = state.gray_lists.pop() {
+                for elem in self.lists[list_index].iter() {
+                    state.shade(elem);
+                }
+            } else if let Some(record_index) = state.gray_records.pop() {
+                for (_, elem) in self.nameless_records[record_index].iter() {
+                    state.shade(elem);
+                }
+            } else {
+                break;
+            }
+            scanned += 1;
+        }
+
+        if !state.gray_lists.is_empty() || !state.gray_records.is_empty() {
+            return false;
+        }
+
+        // Variables could have been reassigned while marking was in progress, so roots
+        // are scanned again. If any unmarked object is found marking must continue
+        let (root_lists, root_records) = self.find_root_objects();
+        for list_index in root_lists {
+            state.shade(&DataType::List(list_index));
+        }
+        for record_index in root_records {
+            state.shade(&DataType::NamelessRecord(record_index));
+        }
+        if !state.gray_lists.is_empty() || !state.gray_records.is_empty() {
+            return false;
+        }
+
+        // Objects allocated after marking started are always marked, objects that weren't
+        // tracked by marking at all are kept alive
+        let mut marked_lists = state.marked_lists.clone();
+        marked_lists.resize(self.lists.len(), true);
+        let mut marked_records = state.marked_records.clone();
+        marked_records.resize(self.nameless_records.len(), true);
+        self.gc_sweep(marked_lists, marked_records);
+        true
+    }
+
     fn gc_sweep(&mut self, marked_lists: Vec<bool>, marked_record: Vec<bool>) {
         for (index, alive) in marked_lists.iter().enumerate() {
             if !alive {
@@ -138,4 +205,132 @@ impl<'a> GC<'a> {
         }
         (root_lists, root_records)
     }
-}
\ No newline at end of file
+}
+
+// Tri-color marking state of an incremental collection. Objects which are marked and
+// in a gray list are gray, marked and not in a gray list are black, rest are white
+pub(crate) struct IncrementalState {
+    gray_lists: Vec<usize>,
+    gray_records: Vec<usize>,
+    marked_lists: Vec<bool>,
+    marked_records: Vec<bool>,
+}
+
+impl IncrementalState {
+    // Makes a white object gray. This must be called for every newly allocated object and for
+    // every value stored inside a list or record while marking is in progress, otherwise an
+    // object only reachable from an already scanned object would be swept
+    pub(crate) fn shade(&mut self, data: &DataType) {
+        match data {
+            DataType::List(index) => {
+                let index = *index;
+                if index >= self.marked_lists.len() {
+                    // lists that were pushed after marking started are not known to marking,
+                    // so treating all of them as gray
+                    for new_index in self.marked_lists.len()..=index {
+                        self.marked_lists.push(true);
+                        self.gray_lists.push(new_index);
+                    }
+                } else if !self.marked_lists[index] {
+                    self.marked_lists[index] = true;
+                    self.gray_lists.push(index);
+                }
+            },
+            DataType::NamelessRecord(index) => {
+                let index = *index;
+                if index >= self.marked_records.len() {
+                    for new_index in self.marked_records.len()..=index {
+                        self.marked_records.push(true);
+                        self.gray_records.push(new_index);
+                    }
+                } else if !self.marked_records[index] {
+                    self.marked_records[index] = true;
+                    self.gray_records.push(index);
+                }
+            },
+            _ => {},
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+    use crate::backend::interpreter::DataType;
+    use crate::backend::mark_sweep::GC;
+
+    type Heap = (Vec<HashMap<String, Option<DataType>>>, Vec<Vec<DataType>>, Vec<HashMap<String, DataType>>);
+
+    fn heap_with_garbage() -> Heap {
+        let mut root_env: HashMap<String, Option<DataType>> = HashMap::new();
+        root_env.insert("ক".to_string(), Some(DataType::List(0)));
+
+        let lists = vec![
+            vec![DataType::List(1), DataType::NamelessRecord(0)],
+            vec![DataType::Num(1.0)],
+            vec![DataType::String("reachable".into())],
+            // list 3 and 4 references each other but unreachable from root
+            vec![DataType::List(4)],
+            vec![DataType::List(3), DataType::NamelessRecord(1)],
+        ];
+
+        let mut reachable_record: HashMap<String, DataType> = HashMap::new();
+        reachable_record.insert("key".to_string(), DataType::List(2));
+        let mut unreachable_record: HashMap<String, DataType> = HashMap::new();
+        unreachable_record.insert("key".to_string(), DataType::List(3));
+
+        (vec![root_env], lists, vec![reachable_record, unreachable_record])
+    }
+
+    #[test]
+    fn gc_incremental_frees_same_garbage_as_stop_the_world() {
+        let (mut envs, mut lists, mut records) = heap_with_garbage();
+        let (mut free_lists, mut free_records) = (Vec::new(), Vec::new());
+        let mut gc = GC::new(&mut envs, &mut lists, &mut free_lists, &mut records, &mut free_records);
+        gc.collect_garbage();
+
+        let (mut inc_envs, mut inc_lists, mut inc_records) = heap_with_garbage();
+        let (mut inc_free_lists, mut inc_free_records) = (Vec::new(), Vec::new());
+        let mut inc_gc = GC::new(&mut inc_envs, &mut inc_lists, &mut inc_free_lists,
+                                 &mut inc_records, &mut inc_free_records);
+        let mut state = inc_gc.start_incremental();
+        let mut steps = 0;
+        // with budget of one object every step must make progress, so collection will finish
+        while !inc_gc.incremental_step(&mut state, 1) {
+            steps += 1;
+        }
+
+        assert!(steps > 1);
+        free_lists.sort();
+        inc_free_lists.sort();
+        assert_eq!(vec![3, 4], free_lists);
+        assert_eq!(free_lists, inc_free_lists);
+        assert_eq!(vec![1], free_records);
+        assert_eq!(free_records, inc_free_records);
+        assert_eq!(lists, inc_lists);
+    }
+
+    #[test]
+    fn gc_incremental_keeps_value_stored_during_marking() {
+        let (mut envs, mut lists, mut records) = heap_with_garbage();
+        let (mut free_lists, mut free_records) = (Vec::new(), Vec::new());
+        let mut state = {
+            let mut gc = GC::new(&mut envs, &mut lists, &mut free_lists, &mut records, &mut free_records);
+            let mut state = gc.start_incremental();
+            // scanning only root list, so list 0 is black and list 2 is still white
+            gc.incremental_step(&mut state, 1);
+            state
+        };
+
+        // moving list 2 into black list 0 and removing it from record 0, like an index
+        // assignment would do while marking is in progress
+        lists[0].push(DataType::List(2));
+        state.shade(&DataType::List(2));
+        records[0].remove("key");
+
+        let mut gc = GC::new(&mut envs, &mut lists, &mut free_lists, &mut records, &mut free_records);
+        while !gc.incremental_step(&mut state, 1) {}
+        assert!(!free_lists.contains(&2));
+        assert_eq!(vec![DataType::String("reachable".into())], lists[2]);
+    }
+}
@@ -1,3 +1,4 @@
 pub mod interpreter;
 pub mod built_ins;
-mod mark_sweep;
\ No newline at end of file
+mod mark_sweep;
+mod json;
\ No newline at end of file
@@ -1,4 +1,4 @@
-use crate::common::pakhi_error::PakhiErr;
+use crate::common::pakhi_error::{PakhiErr, PakhiWarning};
 
 pub trait IO {
     fn new() -> Self;
@@ -10,14 +10,47 @@ pub trait IO {
             Err(e) => Err(e)
         }
     }
+    // Reads one line from input without line ending, returns None at end of input
+    fn read_line(&mut self) -> Result<Option<String>, std::io::Error> {
+        let mut input = String::new();
+        match std::io::stdin().read_line(&mut input)? {
+            0 => Ok(None),
+            _ => Ok(Some(input.trim_end_matches(['\n', '\r']).to_string())),
+        }
+    }
+    // Clears terminal screen and moves cursor to top left corner using ANSI escape codes
+    fn clear_screen(&mut self) {
+        self.print("\x1b[2J\x1b[H");
+    }
+    // Moves terminal cursor, row and column starts from 1
+    fn move_cursor(&mut self, row: usize, col: usize) {
+        self.print(&format!("\x1b[{};{}H", row, col));
+    }
+    // Warnings are printed to stderr and program keeps running
+    fn warn(&mut self, warning: &PakhiWarning) {
+        eprintln!("Warning: {}", warning.message);
+        eprintln!("    at file: {}, line: {}", warning.file_path, warning.line);
+    }
     fn panic(&mut self, err: PakhiErr);
 }
 
-pub struct RealIO;
+// Receives fatal error instead of RealIO printing it and exiting process
+pub type PanicHandler = Box<dyn FnMut(PakhiErr)>;
+
+pub struct RealIO {
+    panic_handler: Option<PanicHandler>,
+}
+
+impl RealIO {
+    // For embedding interpreter in another program, exiting process on error would stop host program too
+    pub fn with_panic_handler(handler: PanicHandler) -> RealIO {
+        RealIO { panic_handler: Some(handler) }
+    }
+}
 
 impl IO for RealIO {
     fn new() -> RealIO {
-        RealIO
+        RealIO { panic_handler: None }
     }
 
     fn print(&mut self, m: &str) {
@@ -29,6 +62,10 @@ impl IO for RealIO {
     }
 
     fn panic(&mut self, err: PakhiErr) {
+        if let Some(handler) = &mut self.panic_handler {
+            handler(err);
+            return;
+        }
         match err {
             PakhiErr::SyntaxError(line, file_name, err_message) => {
                 eprintln!("SyntaxError: {}", err_message);
@@ -63,6 +100,8 @@ pub struct MockIO {
     expected_println: Vec<String>,
     expected_panic: Vec<PakhiErr>,
     expected_op_order: Vec<String>,
+    // Lines returned by read_line, in order
+    input: std::collections::VecDeque<String>,
 }
 
 impl MockIO {
@@ -76,6 +115,10 @@ impl MockIO {
         self.expected_op_order.push(String::from("println"));
     }
 
+    pub fn push_input_line(&mut self, line: &str) {
+        self.input.push_back(String::from(line));
+    }
+
     pub fn expect_panic(&mut self, err: PakhiErr) {
         self.expected_panic.push(err);
         self.expected_op_order.push(String::from("panic"));
@@ -108,6 +151,7 @@ impl IO for MockIO {
             expected_println: Vec::new(),
             expected_panic: Vec::new(),
             expected_op_order: Vec::new(),
+            input: std::collections::VecDeque::new(),
         }
     }
 
@@ -121,6 +165,10 @@ impl IO for MockIO {
         self.op_order.push(String::from("println"));
     }
 
+    fn read_line(&mut self) -> Result<Option<String>, std::io::Error> {
+        Ok(self.input.pop_front())
+    }
+
     fn panic(&mut self, err: PakhiErr) {
         self.panic.push(err);
         self.op_order.push("panic".to_string());
@@ -5,4 +5,12 @@ pub enum PakhiErr {
     TypeError(u32, String, String),
     RuntimeError(u32, String, String),
     UnexpectedError(String), // Here only string will contain error message
-}
\ No newline at end of file
+}
+
+// Non fatal problems found in source code, reported to user but program still runs
+#[derive(Debug, Clone, PartialEq)]
+pub struct PakhiWarning {
+    pub line: u32,
+    pub file_path: String,
+    pub message: String,
+}
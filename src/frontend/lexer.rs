@@ -18,6 +18,9 @@ pub enum TokenKind {
     If,
     Else,
     Loop,
+    ForEach,
+    CountLoop,
+    WhileLoop,
     Var,        // নাম keyword is TokenKind of Var. Not variable identifier
     Function,
     Plus,
@@ -25,11 +28,16 @@ pub enum TokenKind {
     Multiply,
     Division,
     Remainder,
+    PlusEqual,
+    MinusEqual,
+    MultiplyEqual,
+    DivisionEqual,
     At,
     Semicolon,
     Map,
     Comment,
     Comma,
+    Range,      // '..' used in list slice
     ParenStart,
     ParenEnd,
     CurlyBraceStart,
@@ -53,6 +61,12 @@ pub enum TokenKind {
     Print,
     Import,
     PrintNoEOL,
+    // Interpolated string "আগে ${expr} পরে" is tokenized as InterpolatedStringStart, String("আগে "),
+    // InterpolationStart, tokens of expr, InterpolationEnd, String(" পরে"), InterpolatedStringEnd
+    InterpolatedStringStart,
+    InterpolationStart,
+    InterpolationEnd,
+    InterpolatedStringEnd,
     EOT, // represents end of token, only needed for parsing to indicate
          // all previous tokens were consumed
 }
@@ -67,9 +81,7 @@ pub fn tokenize(src: Vec<char>, src_file_path: String) -> Result<Vec<Token>, Pak
         // c represents total chars consumed by token t
         // l represents total line consumed by token t
         let (t, c, l) = consume(&src, current_i, line, src_file_path.clone())?;
-        if let Some(token) = t {
-            tokens.push(token);
-        }
+        tokens.extend(t);
         current_i += c;
         line += l;
     }
@@ -83,14 +95,14 @@ pub fn tokenize(src: Vec<char>, src_file_path: String) -> Result<Vec<Token>, Pak
     Ok(tokens)
 }
 
-fn consume(src: &Vec<char>, start: usize, line: u32, src_file_path: String) -> Result<(Option<Token>, usize, u32), PakhiErr> {
+fn consume(src: &Vec<char>, start: usize, line: u32, src_file_path: String) -> Result<(Vec<Token>, usize, u32), PakhiErr> {
     let consumed_char: usize;
     let consumed_line: u32;
     let token: Token;
 
     match src[start] {
         '-'|'০'|'১'|'২'|'৩'|'৪'|'৫'|'৬'|'৭'|'৮'|'৯' => {
-            if src[start+1].is_numeric() || src[start].is_numeric() {
+            if src[start].is_numeric() || (start + 1 < src.len() && src[start+1].is_numeric()) {
                 // negative number, unary '-' operator
                 let (val, consumed) = consume_num(src, start, line, &src_file_path)?;
 
@@ -103,9 +115,18 @@ fn consume(src: &Vec<char>, start: usize, line: u32, src_file_path: String) -> R
                     src_file_path,
                 }
             } else {
-                // not a negative number, binary '-' operator or map operator '->' in record
+                // not a negative number, binary '-' operator, '-=' or map operator '->' in record
 
-                if src[start+1] == '>' {
+                if start + 1 < src.len() && src[start+1] == '=' {
+                    consumed_char = 2;
+                    consumed_line = 0;
+                    token = Token {
+                        kind: TokenKind::MinusEqual,
+                        lexeme: src[start..(start+2)].to_vec(),
+                        line,
+                        src_file_path,
+                    }
+                } else if start + 1 < src.len() && src[start+1] == '>' {
                     // map operator '->' in record
                     consumed_char = 2;
                     consumed_line = 0;
@@ -129,33 +150,66 @@ fn consume(src: &Vec<char>, start: usize, line: u32, src_file_path: String) -> R
             }
         },
         '+' => {
-            consumed_char = 1;
-            consumed_line = 0;
-            token = Token {
-                kind: TokenKind::Plus,
-                lexeme: src[start..(start+1)].to_vec(),
-                line,
-                src_file_path,
+            if start + 1 < src.len() && src[start+1] == '=' {
+                consumed_char = 2;
+                consumed_line = 0;
+                token = Token {
+                    kind: TokenKind::PlusEqual,
+                    lexeme: src[start..(start+2)].to_vec(),
+                    line,
+                    src_file_path,
+                }
+            } else {
+                consumed_char = 1;
+                consumed_line = 0;
+                token = Token {
+                    kind: TokenKind::Plus,
+                    lexeme: src[start..(start+1)].to_vec(),
+                    line,
+                    src_file_path,
+                }
             }
         },
         '*' => {
-            consumed_char = 1;
-            consumed_line = 0;
-            token = Token {
-                kind: TokenKind::Multiply,
-                lexeme: src[start..(start+1)].to_vec(),
-                line,
-                src_file_path,
+            if start + 1 < src.len() && src[start+1] == '=' {
+                consumed_char = 2;
+                consumed_line = 0;
+                token = Token {
+                    kind: TokenKind::MultiplyEqual,
+                    lexeme: src[start..(start+2)].to_vec(),
+                    line,
+                    src_file_path,
+                }
+            } else {
+                consumed_char = 1;
+                consumed_line = 0;
+                token = Token {
+                    kind: TokenKind::Multiply,
+                    lexeme: src[start..(start+1)].to_vec(),
+                    line,
+                    src_file_path,
+                }
             }
         },
         '/' => {
-            consumed_char = 1;
-            consumed_line = 0;
-            token = Token {
-                kind: TokenKind::Division,
-                lexeme: src[start..(start+1)].to_vec(),
-                line,
-                src_file_path,
+            if start + 1 < src.len() && src[start+1] == '=' {
+                consumed_char = 2;
+                consumed_line = 0;
+                token = Token {
+                    kind: TokenKind::DivisionEqual,
+                    lexeme: src[start..(start+2)].to_vec(),
+                    line,
+                    src_file_path,
+                }
+            } else {
+                consumed_char = 1;
+                consumed_line = 0;
+                token = Token {
+                    kind: TokenKind::Division,
+                    lexeme: src[start..(start+1)].to_vec(),
+                    line,
+                    src_file_path,
+                }
             }
         },
         '%' => {
@@ -189,7 +243,7 @@ fn consume(src: &Vec<char>, start: usize, line: u32, src_file_path: String) -> R
             }
         },
         '!' => {
-            if start < src.len() && src[start+1] == '=' {
+            if start + 1 < src.len() && src[start+1] == '=' {
                 consumed_char = 2;
                 consumed_line = 0;
                 token = Token {
@@ -251,7 +305,10 @@ fn consume(src: &Vec<char>, start: usize, line: u32, src_file_path: String) -> R
             }
         },
         '"' => {
-            let (val, consumed) = consume_string(src, start);
+            if let Some(interpolated) = consume_interpolated_string(src, start, line, &src_file_path)? {
+                return Ok(interpolated);
+            }
+            let (val, consumed) = consume_string(src, start, line, &src_file_path)?;
 
             consumed_char = consumed;
             consumed_line = 0;
@@ -324,7 +381,7 @@ fn consume(src: &Vec<char>, start: usize, line: u32, src_file_path: String) -> R
             }
         },
         '=' => {
-            if start < src.len() && src[start+1] == '=' {
+            if start + 1 < src.len() && src[start+1] == '=' {
                 consumed_char = 2;
                 consumed_line = 0;
                 token = Token {
@@ -345,7 +402,7 @@ fn consume(src: &Vec<char>, start: usize, line: u32, src_file_path: String) -> R
             }
         },
         '<' => {
-            if start < src.len() && src[start+1] == '=' {
+            if start + 1 < src.len() && src[start+1] == '=' {
                 consumed_char = 2;
                 consumed_line = 0;
                 token = Token {
@@ -366,7 +423,7 @@ fn consume(src: &Vec<char>, start: usize, line: u32, src_file_path: String) -> R
             }
         },
         '>' => {
-            if start < src.len() && src[start+1] == '=' {
+            if start + 1 < src.len() && src[start+1] == '=' {
                 consumed_char = 2;
                 consumed_line = 0;
                 token = Token {
@@ -386,19 +443,33 @@ fn consume(src: &Vec<char>, start: usize, line: u32, src_file_path: String) -> R
                 }
             }
         },
+        '.' if start + 1 < src.len() && src[start+1] == '.' => {
+            consumed_char = 2;
+            consumed_line = 0;
+            token = Token {
+                kind: TokenKind::Range,
+                lexeme: src[start..(start+2)].to_vec(),
+                line,
+                src_file_path,
+            }
+        },
         ' ' | '\r' | '\t' => {
             consumed_char = 1;
             consumed_line = 0;
-            return Ok((None, consumed_char, consumed_line));
+            return Ok((Vec::new(), consumed_char, consumed_line));
         },
         '\n' => {
             consumed_char = 1;
             consumed_line = 1;
-            return Ok((None, consumed_char, consumed_line));
+            return Ok((Vec::new(), consumed_char, consumed_line));
         },
         _ => {
             // if nothing matches must be an identifier
-            let (t, consumed) = consume_identifier(src, start, line, src_file_path);
+            let (t, consumed) = consume_identifier(src, start, line, src_file_path.clone());
+            if consumed == 0 {
+                // character can't start any token, without this error tokenizer would never advance
+                return Err(PakhiErr::SyntaxError(line, src_file_path, format!("Unexpected character '{}'", src[start])));
+            }
 
             consumed_char = consumed;
             consumed_line = 0;
@@ -406,7 +477,7 @@ fn consume(src: &Vec<char>, start: usize, line: u32, src_file_path: String) -> R
         },
     }
 
-    Ok((Some(token), consumed_char, consumed_line))
+    Ok((vec![token], consumed_char, consumed_line))
 }
 
 fn consume_num(src: &Vec<char>, start: usize, line: u32, src_file_path: &str) -> Result<(f64, usize), PakhiErr> {
@@ -429,6 +500,10 @@ fn consume_num(src: &Vec<char>, start: usize, line: u32, src_file_path: &str) ->
 
     while i < src.len() && (src[i].clone().is_numeric() || src[i] == '.') {
         if src[i] == '.' {
+            if i + 1 < src.len() && src[i+1] == '.' {
+                // '..' is range operator, so number ends here
+                break;
+            }
             if in_fractional_part {
                 return Err(SyntaxError(line, src_file_path.to_string(),
                                        "Number is not properly formatted".to_string()));
@@ -458,7 +533,21 @@ fn consume_num(src: &Vec<char>, start: usize, line: u32, src_file_path: &str) ->
     }
 }
 
-fn consume_string(src: &Vec<char>, start: usize) -> (String, usize) {
+// Returns character represented by escape sequence \c, None if c can't be escaped
+fn escaped_char(c: char) -> Option<char> {
+    match c {
+        'n' => Some('\n'),
+        't' => Some('\t'),
+        'r' => Some('\r'),
+        '"' => Some('"'),
+        '\\' => Some('\\'),
+        // escaped \$ is kept as literal $, so that it doesn't start interpolation
+        '$' => Some('$'),
+        _ => None,
+    }
+}
+
+fn consume_string(src: &[char], start: usize, line: u32, src_file_path: &str) -> Result<(String, usize), PakhiErr> {
     assert_eq!('"', src[start]);
 
     let mut consumed = 0;
@@ -466,14 +555,143 @@ fn consume_string(src: &Vec<char>, start: usize) -> (String, usize) {
 
     let mut i = start + 1;
     while i < src.len() && (src[i].clone() != '"') {
+        if src[i] == '\\' && i + 1 < src.len() {
+            if let Some(c) = escaped_char(src[i+1]) {
+                val.push(c);
+                consumed += 2;
+                i += 2;
+                continue;
+            }
+        }
         val.push(src[i]);
         consumed += 1;
         i += 1;
     }
+    if i >= src.len() {
+        return Err(SyntaxError(line, src_file_path.to_string(), "Expected '\"' at the end of string".to_string()));
+    }
     // adding extra 2 for first " and last "
     consumed += 2;
 
-    (val, consumed)
+    Ok((val, consumed))
+}
+
+enum StringPart {
+    Literal(String),
+    // code inside ${ and } with line number where it starts inside string
+    Code(Vec<char>, u32),
+}
+
+// Returns None if string doesn't contain any ${expr}, otherwise tokenizes literal parts
+// and expressions of string
+fn consume_interpolated_string(src: &[char], start: usize, line: u32, src_file_path: &str)
+    -> Result<Option<(Vec<Token>, usize, u32)>, PakhiErr>
+{
+    let mut parts: Vec<StringPart> = Vec::new();
+    let mut literal = String::new();
+    let mut consumed_line = 0;
+
+    let mut i = start + 1;
+    while i < src.len() && src[i] != '"' {
+        if src[i] == '\\' && i + 1 < src.len() {
+            if let Some(c) = escaped_char(src[i+1]) {
+                literal.push(c);
+                i += 2;
+                continue;
+            }
+        }
+        if src[i] == '$' && i + 1 < src.len() && src[i+1] == '{' {
+            parts.push(StringPart::Literal(literal));
+            literal = String::new();
+            // consuming ${
+            i += 2;
+
+            let code_line = consumed_line;
+            let mut code: Vec<char> = Vec::new();
+            // tracking nested {} so that record literal inside expression doesn't end interpolation
+            let mut depth = 1;
+            let mut in_inner_string = false;
+            while i < src.len() {
+                let c = src[i];
+                if in_inner_string && c == '\\' && i + 1 < src.len() {
+                    // escaped character can't end inner string
+                    code.push(c);
+                    code.push(src[i+1]);
+                    i += 2;
+                    continue;
+                } else if c == '"' {
+                    in_inner_string = !in_inner_string;
+                } else if !in_inner_string && c == '{' {
+                    depth += 1;
+                } else if !in_inner_string && c == '}' {
+                    depth -= 1;
+                    if depth == 0 {
+                        break;
+                    }
+                } else if c == '\n' {
+                    consumed_line += 1;
+                }
+                code.push(c);
+                i += 1;
+            }
+            if i >= src.len() {
+                return Err(SyntaxError(line + code_line, src_file_path.to_string(),
+                                       "Expected '}' after interpolated expression".to_string()));
+            }
+            // consuming }
+            i += 1;
+            parts.push(StringPart::Code(code, code_line));
+            continue;
+        }
+        if src[i] == '\n' {
+            consumed_line += 1;
+        }
+        literal.push(src[i]);
+        i += 1;
+    }
+    parts.push(StringPart::Literal(literal));
+
+    // unterminated string without interpolation is reported by consume_string
+    if parts.len() == 1 {
+        return Ok(None);
+    }
+    if i >= src.len() {
+        return Err(SyntaxError(line, src_file_path.to_string(), "Expected '\"' at the end of string".to_string()));
+    }
+    // consuming closing "
+    let consumed_char = i - start + 1;
+
+    let token = |kind: TokenKind, lexeme: &str, line: u32| Token {
+        kind,
+        lexeme: lexeme.chars().collect(),
+        line,
+        src_file_path: src_file_path.to_string(),
+    };
+    let mut tokens: Vec<Token> = vec![token(TokenKind::InterpolatedStringStart, "\"", line)];
+    for part in parts {
+        match part {
+            StringPart::Literal(literal) => {
+                if !literal.is_empty() {
+                    tokens.push(token(TokenKind::String(literal.clone()), &literal, line));
+                }
+            },
+            StringPart::Code(code, code_line) => {
+                tokens.push(token(TokenKind::InterpolationStart, "${", line + code_line));
+                let mut code_tokens = tokenize(code, src_file_path.to_string())?;
+                // removing EOT token
+                code_tokens.pop();
+                for mut code_token in code_tokens {
+                    // tokenize counts line from 1
+                    code_token.line += line + code_line - 1;
+                    tokens.push(code_token);
+                }
+                tokens.push(token(TokenKind::InterpolationEnd, "}", line + code_line));
+            },
+        }
+    }
+    tokens.push(token(TokenKind::InterpolatedStringEnd, "\"", line + consumed_line));
+
+    Ok(Some((tokens, consumed_char, consumed_line)))
 }
 
 fn consume_identifier(src: &Vec<char>, start: usize, line: u32, src_file_path: String) -> (Token, usize) {
@@ -513,6 +731,9 @@ fn keyword(char_vec: &Vec<char>, line: u32, src_file_path: String) -> Option<Tok
     keyword_map.insert("যদি".chars().collect(), TokenKind::If);
     keyword_map.insert("অথবা".chars().collect(), TokenKind::Else);
     keyword_map.insert("লুপ".chars().collect(), TokenKind::Loop);
+    keyword_map.insert("ফরইচ".chars().collect(), TokenKind::ForEach);
+    keyword_map.insert("গুনলুপ".chars().collect(), TokenKind::CountLoop);
+    keyword_map.insert("যতক্ষণ".chars().collect(), TokenKind::WhileLoop);
     keyword_map.insert("ফাং".chars().collect(), TokenKind::Function);
     keyword_map.insert("ফেরত".chars().collect(), TokenKind::Return);
     keyword_map.insert("থামাও".chars().collect(), TokenKind::Break);
@@ -628,11 +849,30 @@ mod tests {
     fn lexer_consume_string_test() {
         let string: Vec<char> = "\" var a = 45;\"".chars().collect();
 
-        let (val, consumed) = consume_string(&string, 0);
+        let (val, consumed) = consume_string(&string, 0, 1, "test.pakhi").unwrap();
         assert_eq!(" var a = 45;", val);
         assert_eq!(14, consumed);
     }
 
+    #[test]
+    fn lexer_consume_string_escape_test() {
+        for (src, expected) in [
+            ("\"a\\nb\"", "a\nb"),
+            ("\"a\\tb\"", "a\tb"),
+            ("\"a\\rb\"", "a\rb"),
+            ("\"a\\\"b\"", "a\"b"),
+            ("\"a\\\\b\"", "a\\b"),
+            ("\"a\\$b\"", "a$b"),
+            // unknown escape is kept as it is
+            ("\"a\\db\"", "a\\db"),
+        ] {
+            let string: Vec<char> = src.chars().collect();
+            let (val, consumed) = consume_string(&string, 0, 1, "test.pakhi").unwrap();
+            assert_eq!(expected, val);
+            assert_eq!(string.len(), consumed);
+        }
+    }
+
     #[test]
     fn lexer_keyword_test_1() {
         let kword: Vec<char> = "ফাং".chars().collect();
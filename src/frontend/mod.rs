@@ -1,2 +1,3 @@
 pub mod parser;
-pub mod lexer;
\ No newline at end of file
+pub mod lexer;
+pub mod warning;
\ No newline at end of file
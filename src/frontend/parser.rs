@@ -4,7 +4,7 @@ use crate::frontend::lexer::TokenKind;
 use crate::common::io;
 use crate::common::io::IO;
 use std::path::Path;
-use std::collections::HashMap;
+use std::collections::{HashMap, VecDeque};
 use std::ffi::OsStr;
 use crate::backend::built_ins::BuiltInFunctionList;
 use crate::common::pakhi_error::PakhiErr;
@@ -23,6 +23,7 @@ pub enum Stmt {
     Return(Expr, u32, String),
     If(Expr, u32, String),
     Loop(u32, String),
+    ForEach(ForEach, u32, String),
     Continue(u32, String),
     Break(u32, String),
     Else(u32, String),
@@ -39,6 +40,13 @@ pub struct Assignment {
     pub init_value: Option<Expr>,
 }
 
+// ফরইচ loop, body and end of loop are same as লুপ
+#[derive(Debug, PartialEq, Clone)]
+pub struct ForEach {
+    pub iterable: Expr,
+    pub var_name: Token,
+}
+
 #[derive(Debug, PartialEq, Clone)]
 pub enum AssignmentKind {
     FirstAssignment,
@@ -59,6 +67,8 @@ pub enum Expr {
     Unary(Unary, u32, String),
     Call(FunctionCall, u32, String),
     Primary(Primary, u32, String),
+    // start..end inside square brackets, only used for list slice assignment
+    Range(Box<Expr>, Box<Expr>, u32, String),
 }
 
 #[derive(Debug, PartialEq, Clone)]
@@ -115,6 +125,13 @@ struct Parser {
     // Storing all built-in function names because when modules identifiers are renamed
     // we don't want to rename built-in functions
     built_in_functions: BuiltInFunctionList,
+    // Some statements are desugared into multiple statements, statements after first one
+    // are kept here and returned before parsing next token
+    pending_statements: VecDeque<Stmt>,
+    // One entry for every loop being parsed, true if loop's scope must be closed after আবার
+    open_loops: Vec<bool>,
+    // Used for making names of hidden variables created while desugaring unique
+    hidden_var_count: usize,
 }
 
 impl Parser {
@@ -125,6 +142,9 @@ impl Parser {
             main_module_path: String::new(),
             parent_child_relationship: HashMap::new(),
             built_in_functions: BuiltInFunctionList::new(),
+            pending_statements: VecDeque::new(),
+            open_loops: Vec::new(),
+            hidden_var_count: 0,
         }
     }
 
@@ -167,6 +187,9 @@ impl Parser {
     }
 
     fn statements(&mut self) -> Result<Stmt, PakhiErr> {
+        if let Some(stmt) = self.pending_statements.pop_front() {
+            return Ok(stmt);
+        }
         let (line, file_name) = self.get_token_line_file_name(self.current)?;
 
         match self.tokens[self.current].kind {
@@ -179,6 +202,9 @@ impl Parser {
             TokenKind::If => self.if_statement(),
             TokenKind::Else => self.else_statement(),
             TokenKind::Loop => self.loop_stmt(),
+            TokenKind::ForEach => self.for_each_stmt(),
+            TokenKind::CountLoop => self.count_loop_stmt(),
+            TokenKind::WhileLoop => self.while_loop_stmt(),
             TokenKind::Continue => self.continue_stmt(),
             TokenKind::Break => self.break_stmt(),
             TokenKind::Function => self.func_def_stmt(),
@@ -556,7 +582,7 @@ impl Parser {
     fn re_assignment_stmt(&mut self) -> Result<Stmt, PakhiErr> {
         let (line, file_name) = self.get_token_line_file_name(self.current)?;
 
-        if self.tokens[self.current+1].kind != TokenKind::Equal &&
+        if !is_assignment_operator(&self.tokens[self.current+1].kind) &&
             self.tokens[self.current+1].kind != TokenKind::SquareBraceStart {
             // not a reassignment, only expression statement;
             return self.expression_stmt();
@@ -568,7 +594,11 @@ impl Parser {
 
         // indexes will be populated only if assigning to array element, otherwise it will be empty
         let mut indexes: Vec<Expr> = Vec::new();
-        while self.tokens[self.current].kind != TokenKind::Equal {
+        while !is_assignment_operator(&self.tokens[self.current].kind) {
+            if let Some(slice) = self.slice_index()? {
+                indexes.push(slice);
+                continue;
+            }
             let index = self.expression()?;
             if let Expr::Primary(Primary::List(_), _, _) = index {
                 indexes.push(index);
@@ -578,14 +608,46 @@ impl Parser {
             }
         }
 
-        if self.tokens[self.current].kind != TokenKind::Equal {
+        let operator = self.tokens[self.current].kind.clone();
+        let has_slice = indexes.iter().any(|index| matches!(index,
+            Expr::Primary(Primary::List(elems), _, _) if matches!(elems.first(), Some(Expr::Range(_, _, _, _)))));
+        if has_slice && (indexes.len() != 1 || operator != TokenKind::Equal) {
             let (line, file_name) = self.extract_err_meta()?;
-            return Err(PakhiErr::SyntaxError(line, file_name, "Expected '='".to_string()));
+            return Err(PakhiErr::SyntaxError(line, file_name,
+                                             "Slice assignment is only supported for one dimensional list with '='".to_string()));
         }
-        // consuming '=' token
+        // consuming '=' token or compound assignment token like '+='
         self.current += 1;
 
-        let expr = self.expression()?;
+        let mut expr = self.expression()?;
+        // ক[১] += ২; is desugared to ক[১] = ক[১] + ২;
+        if operator != TokenKind::Equal {
+            let mut current_value = Expr::Primary(Primary::Var(var_name.clone()), line, file_name.clone());
+            for index in &indexes {
+                if let Expr::Primary(Primary::List(elems), index_line, index_file_name) = index {
+                    if elems.len() == 1 {
+                        current_value = Expr::Indexing(Box::new(current_value), Box::new(elems[0].clone()),
+                                                       *index_line, index_file_name.clone());
+                    }
+                }
+            }
+            let (binary_operator, is_add_or_sub) = match operator {
+                TokenKind::PlusEqual => (TokenKind::Plus, true),
+                TokenKind::MinusEqual => (TokenKind::Minus, true),
+                TokenKind::MultiplyEqual => (TokenKind::Multiply, false),
+                _ => (TokenKind::Division, false),
+            };
+            let binary = Binary {
+                operator: binary_operator,
+                left: Box::new(current_value),
+                right: Box::new(Expr::Primary(Primary::Group(Box::new(expr)), line, file_name.clone())),
+            };
+            expr = if is_add_or_sub {
+                Expr::AddOrSub(binary, line, file_name.clone())
+            } else {
+                Expr::MulOrDivOrRemainder(binary, line, file_name.clone())
+            };
+        }
 
         // consuming ; token
         self.current += 1;
@@ -619,6 +681,37 @@ impl Parser {
         return Ok(Stmt::Print(expr, line, file_name));
     }
 
+    // Parses [start..end] index of slice assignment target, returns None without consuming
+    // anything if index isn't a range
+    fn slice_index(&mut self) -> Result<Option<Expr>, PakhiErr> {
+        if self.tokens[self.current].kind != TokenKind::SquareBraceStart {
+            return Ok(None);
+        }
+        let index_start = self.current;
+        // consuming [ token
+        self.current += 1;
+        let start = self.expression()?;
+        if self.tokens[self.current].kind != TokenKind::Range {
+            // ordinary index, parsed again as list literal
+            self.current = index_start;
+            return Ok(None);
+        }
+        let (line, file_name) = self.extract_err_meta()?;
+        // consuming .. token
+        self.current += 1;
+        let end = self.expression()?;
+        if self.tokens[self.current].kind != TokenKind::SquareBraceEnd {
+            let (line, file_name) = self.extract_err_meta()?;
+            return Err(PakhiErr::SyntaxError(line, file_name, "Expecting ']'".to_string()));
+        }
+        // consuming ] token
+        self.current += 1;
+
+        let range = Expr::Range(Box::new(start), Box::new(end), line, file_name);
+        let (line, file_name) = self.get_token_line_file_name(index_start)?;
+        Ok(Some(Expr::Primary(Primary::List(vec![range]), line, file_name)))
+    }
+
     fn func_def_stmt(&mut self) -> Result<Stmt, PakhiErr> {
         // consuming function token
         self.current += 1;
@@ -658,16 +751,188 @@ impl Parser {
     }
 
     fn loop_stmt(&mut self) -> Result<Stmt, PakhiErr> {
+        self.open_loops.push(false);
         // consuming loop token
         self.current += 1;
         let (line, file_name) = self.get_token_line_file_name(self.current - 1)?;
         Ok(Stmt::Loop(line, file_name))
     }
 
+    // ফরইচ তালিকা -> উপাদান { ... } আবার;
+    fn for_each_stmt(&mut self) -> Result<Stmt, PakhiErr> {
+        let (line, file_name) = self.get_token_line_file_name(self.current)?;
+        // consuming ফরইচ token
+        self.current += 1;
+
+        let iterable = self.expression()?;
+
+        if self.tokens[self.current].kind != TokenKind::Map {
+            let (line, file_name) = self.extract_err_meta()?;
+            return Err(PakhiErr::SyntaxError(line, file_name, "Expected '->' after ফরইচ list or string".to_string()));
+        }
+        // consuming -> token
+        self.current += 1;
+
+        if self.tokens[self.current].kind != TokenKind::Identifier {
+            let (line, file_name) = self.extract_err_meta()?;
+            return Err(PakhiErr::SyntaxError(line, file_name, "Expected a variable name after '->'".to_string()));
+        }
+        let var_name = self.tokens[self.current].clone();
+        // consuming variable name, loop body starting with '{' is parsed as separate statements
+        self.current += 1;
+
+        if self.tokens[self.current].kind != TokenKind::CurlyBraceStart {
+            let (line, file_name) = self.extract_err_meta()?;
+            return Err(PakhiErr::SyntaxError(line, file_name, "Expected '{' after ফরইচ variable name".to_string()));
+        }
+        self.open_loops.push(false);
+        Ok(Stmt::ForEach(ForEach { iterable, var_name }, line, file_name))
+    }
+
+    // গুনলুপ ই = শুরু..শেষ { ... } আবার;
+    // ই starts from শুরু and increases by ১ until শেষ, শেষ is not included. It is desugared to
+    //
+    // {
+    //     নাম #গুনলুপ-পরের-১ = শুরু;
+    //     নাম #গুনলুপ-শেষ-১ = শেষ;
+    //     লুপ {
+    //         যদি #গুনলুপ-পরের-১ >= #গুনলুপ-শেষ-১ {
+    //             থামাও;
+    //         }
+    //         নাম ই = #গুনলুপ-পরের-১;
+    //         #গুনলুপ-পরের-১ = #গুনলুপ-পরের-১ + ১;
+    //         ...
+    //     } আবার;
+    // }
+    //
+    // so that থামাও and আবার work same as লুপ. Hidden variables start with #, so program can't
+    // use them, and they are scoped by the outer block
+    fn count_loop_stmt(&mut self) -> Result<Stmt, PakhiErr> {
+        let (line, file_name) = self.get_token_line_file_name(self.current)?;
+        // consuming গুনলুপ token
+        self.current += 1;
+
+        if self.tokens[self.current].kind != TokenKind::Identifier {
+            let (line, file_name) = self.extract_err_meta()?;
+            return Err(PakhiErr::SyntaxError(line, file_name, "Expected a variable name after গুনলুপ".to_string()));
+        }
+        let var_name = self.tokens[self.current].clone();
+        self.current += 1;
+
+        if self.tokens[self.current].kind != TokenKind::Equal {
+            let (line, file_name) = self.extract_err_meta()?;
+            return Err(PakhiErr::SyntaxError(line, file_name, "Expected '=' after গুনলুপ variable name".to_string()));
+        }
+        self.current += 1;
+        let start = self.expression()?;
+
+        if self.tokens[self.current].kind != TokenKind::Range {
+            let (line, file_name) = self.extract_err_meta()?;
+            return Err(PakhiErr::SyntaxError(line, file_name, "Expected '..' between গুনলুপ start and end".to_string()));
+        }
+        self.current += 1;
+        let end = self.expression()?;
+
+        if self.tokens[self.current].kind != TokenKind::CurlyBraceStart {
+            let (line, file_name) = self.extract_err_meta()?;
+            return Err(PakhiErr::SyntaxError(line, file_name, "Expected '{' after গুনলুপ end".to_string()));
+        }
+        // consuming { token, it is loop body's block start
+        self.current += 1;
+
+        self.hidden_var_count += 1;
+        let next = self.hidden_var_token(&format!("#গুনলুপ-পরের-{}", self.hidden_var_count), line, &file_name);
+        let end_var = self.hidden_var_token(&format!("#গুনলুপ-শেষ-{}", self.hidden_var_count), line, &file_name);
+        let var_expr = |token: &Token| Expr::Primary(Primary::Var(token.clone()), line, file_name.clone());
+        let assignment = |kind: AssignmentKind, var_name: &Token, init_value: Expr| {
+            Stmt::Assignment(Assignment { kind, var_name: var_name.clone(), indexes: Vec::new(),
+                                          init_value: Some(init_value) }, line, file_name.clone())
+        };
+
+        let finished = Expr::Comparison(Binary {
+            operator: TokenKind::GreaterThanOrEqual,
+            left: Box::new(var_expr(&next)),
+            right: Box::new(var_expr(&end_var)),
+        }, line, file_name.clone());
+        let increment = Expr::AddOrSub(Binary {
+            operator: TokenKind::Plus,
+            left: Box::new(var_expr(&next)),
+            right: Box::new(Expr::Primary(Primary::Num(1.0), line, file_name.clone())),
+        }, line, file_name.clone());
+
+        self.pending_statements.extend(vec![
+            assignment(AssignmentKind::FirstAssignment, &next, start),
+            assignment(AssignmentKind::FirstAssignment, &end_var, end),
+            Stmt::Loop(line, file_name.clone()),
+            Stmt::BlockStart(line, file_name.clone()),
+            Stmt::If(finished, line, file_name.clone()),
+            Stmt::BlockStart(line, file_name.clone()),
+            Stmt::Break(line, file_name.clone()),
+            Stmt::BlockEnd(line, file_name.clone()),
+            assignment(AssignmentKind::FirstAssignment, &var_name, var_expr(&next)),
+            assignment(AssignmentKind::Reassignment, &next, increment),
+        ]);
+        // outer block holding hidden variables is closed after আবার
+        self.open_loops.push(true);
+        Ok(Stmt::BlockStart(line, file_name))
+    }
+
+    // যতক্ষণ শর্ত { ... } আবার; is desugared to
+    //
+    // লুপ {
+    //     যদি !(শর্ত) {
+    //         থামাও;
+    //     }
+    //     ...
+    // } আবার;
+    //
+    // condition is evaluated before every iteration, including first one
+    fn while_loop_stmt(&mut self) -> Result<Stmt, PakhiErr> {
+        let (line, file_name) = self.get_token_line_file_name(self.current)?;
+        // consuming যতক্ষণ token
+        self.current += 1;
+
+        let condition = self.expression()?;
+
+        if self.tokens[self.current].kind != TokenKind::CurlyBraceStart {
+            let (line, file_name) = self.extract_err_meta()?;
+            return Err(PakhiErr::SyntaxError(line, file_name, "Expected '{' after যতক্ষণ condition".to_string()));
+        }
+        // consuming { token, it is loop body's block start
+        self.current += 1;
+
+        let finished = Expr::Unary(Unary {
+            operator: TokenKind::Not,
+            right: Box::new(Expr::Primary(Primary::Group(Box::new(condition)), line, file_name.clone())),
+        }, line, file_name.clone());
+        self.pending_statements.extend(vec![
+            Stmt::BlockStart(line, file_name.clone()),
+            Stmt::If(finished, line, file_name.clone()),
+            Stmt::BlockStart(line, file_name.clone()),
+            Stmt::Break(line, file_name.clone()),
+            Stmt::BlockEnd(line, file_name.clone()),
+        ]);
+        self.open_loops.push(false);
+        Ok(Stmt::Loop(line, file_name))
+    }
+
+    fn hidden_var_token(&self, name: &str, line: u32, file_name: &str) -> Token {
+        Token {
+            kind: TokenKind::Identifier,
+            lexeme: name.chars().collect(),
+            line,
+            src_file_path: file_name.to_string(),
+        }
+    }
+
     fn continue_stmt(&mut self) -> Result<Stmt, PakhiErr> {
         let (line, file_name) = self.get_token_line_file_name(self.current)?;
         // consuming loop token
         self.current += 2;
+        if let Some(true) = self.open_loops.pop() {
+            // closing block created by desugared loop
+            self.pending_statements.push_back(Stmt::BlockEnd(line, file_name.clone()));
+        }
         Ok(Stmt::Continue(line, file_name))
     }
 
@@ -900,6 +1165,60 @@ impl Parser {
                 let (line, file_name) = self.get_token_line_file_name(self.current - 1)?;
                 return Ok(Expr::Primary(Primary::String(s), line, file_name));
             },
+            TokenKind::InterpolatedStringStart => {
+                let exprs_first_token_index = self.current;
+                let (line, file_name) = self.get_token_line_file_name(exprs_first_token_index)?;
+
+                // consuming InterpolatedStringStart token
+                self.current += 1;
+
+                // literal parts and interpolated expressions in order they appear in string
+                let mut parts: Vec<Expr> = Vec::new();
+                loop {
+                    match self.tokens[self.current].kind.clone() {
+                        TokenKind::String(s) => {
+                            let (line, file_name) = self.get_token_line_file_name(self.current)?;
+                            parts.push(Expr::Primary(Primary::String(s), line, file_name));
+                            self.current += 1;
+                        },
+                        TokenKind::InterpolationStart => {
+                            // consuming ${ token
+                            self.current += 1;
+                            parts.push(self.expression()?);
+                            if self.tokens[self.current].kind != TokenKind::InterpolationEnd {
+                                let (line, file_name) = self.extract_err_meta()?;
+                                return Err(PakhiErr::SyntaxError(line, file_name,
+                                                                 "Expected '}' after interpolated expression".to_string()));
+                            }
+                            // consuming } token
+                            self.current += 1;
+                        },
+                        TokenKind::InterpolatedStringEnd => {
+                            self.current += 1;
+                            break;
+                        },
+                        _ => {
+                            let (line, file_name) = self.extract_err_meta()?;
+                            return Err(PakhiErr::SyntaxError(line, file_name,
+                                                             "Unexpected token inside interpolated string".to_string()));
+                        },
+                    }
+                }
+
+                // interpolated string is converted to a call to _ইন্টারপোলেট built-in function,
+                // which joins all parts as a string
+                let interpolate_fn = Token {
+                    kind: TokenKind::Identifier,
+                    lexeme: "_ইন্টারপোলেট".chars().collect(),
+                    line,
+                    src_file_path: file_name.clone(),
+                };
+                let call = FunctionCall {
+                    expr: Box::new(Expr::Primary(Primary::Var(interpolate_fn), line, file_name.clone())),
+                    arguments: parts,
+                };
+                Ok(Expr::Call(call, line, file_name))
+            },
             TokenKind::Identifier => {
                 let exprs_first_token_index = self.current;
 
@@ -952,11 +1271,21 @@ impl Parser {
 
                 while self.tokens[self.current].kind != TokenKind::SquareBraceEnd {
                     let expr = self.expression()?;
+                    if self.tokens[self.current].kind == TokenKind::Range {
+                        let (line, file_name) = self.extract_err_meta()?;
+                        return Err(PakhiErr::SyntaxError(line, file_name,
+                                                         "Range can only be used for list slice assignment".to_string()));
+                    }
                     array_literal.push(expr);
 
+                    // elements must be separated by comma, trailing comma is optional
                     if self.tokens[self.current].kind == TokenKind::Comma {
                         //consuming comma token
                         self.current += 1;
+                    } else if self.tokens[self.current].kind != TokenKind::SquareBraceEnd {
+                        let (line, file_name) = self.extract_err_meta()?;
+                        return Err(PakhiErr::SyntaxError(line, file_name,
+                                                         "Expected ',' or ']' after list element".to_string()));
                     }
                 }
 
@@ -1007,9 +1336,14 @@ impl Parser {
                     let expr = self.expression()?;
                     values.push(expr);
 
+                    // key-value pairs must be separated by comma, trailing comma is optional
                     if self.tokens[self.current].kind == TokenKind::Comma {
                         // consuming , token
                         self.current += 1
+                    } else if self.tokens[self.current].kind != TokenKind::CurlyBraceEnd {
+                        let (line, file_name) = self.extract_err_meta()?;
+                        return Err(PakhiErr::SyntaxError(line, file_name,
+                                                         "Expected ',' or '}' after record value".to_string()));
                     }
                 }
 
@@ -1059,4 +1393,10 @@ pub fn parse(main_module_path: String, tokens: Vec<Token>) -> Result<Vec<Stmt>,
     let mut parser = Parser::new(tokens);
     parser.main_module_path = main_module_path;
     parser.parse()
-}
\ No newline at end of file
+}
+
+// '=' or compound assignment operators '+=', '-=', '*=' and '/='
+fn is_assignment_operator(kind: &TokenKind) -> bool {
+    matches!(kind, TokenKind::Equal | TokenKind::PlusEqual | TokenKind::MinusEqual
+        | TokenKind::MultiplyEqual | TokenKind::DivisionEqual)
+}
@@ -0,0 +1,157 @@
+use std::collections::HashSet;
+use crate::frontend::parser::{Stmt, Expr, Primary, AssignmentKind};
+use crate::common::pakhi_error::PakhiWarning;
+
+// Finds non fatal problems in parsed statements.
+// Warnings are only reported, they never stop program from running
+pub fn check(statements: &[Stmt]) -> Vec<PakhiWarning> {
+    let mut warnings = unreachable_after_return(statements);
+    warnings.append(&mut unused_variables(statements));
+    warnings.sort_by(|a, b| a.file_path.cmp(&b.file_path).then(a.line.cmp(&b.line)));
+    warnings
+}
+
+// Any statement after ফেরত and before end of the block can never be executed
+fn unreachable_after_return(statements: &[Stmt]) -> Vec<PakhiWarning> {
+    let mut warnings: Vec<PakhiWarning> = Vec::new();
+    // true for every open block that is a function body
+    let mut blocks: Vec<bool> = Vec::new();
+    let mut func_def_seen = false;
+    // function definition always ends with "} ফেরত;", that return is not a real return
+    let mut func_body_closed = false;
+
+    for (i, stmt) in statements.iter().enumerate() {
+        match stmt {
+            Stmt::FuncDef(_, _) => func_def_seen = true,
+            Stmt::BlockStart(_, _) => {
+                blocks.push(func_def_seen);
+                func_def_seen = false;
+            },
+            Stmt::BlockEnd(_, _) => {
+                func_body_closed = blocks.pop().unwrap_or(false);
+                continue;
+            },
+            Stmt::Return(_, _, _) if !func_body_closed => {
+                match statements.get(i + 1) {
+                    Some(Stmt::BlockEnd(_, _)) | Some(Stmt::EOS(_, _)) | None => {},
+                    Some(next) => {
+                        let (line, file_path) = stmt_meta(next);
+                        warnings.push(PakhiWarning {
+                            line,
+                            file_path,
+                            message: "Statement after ফেরত is unreachable".to_string(),
+                        });
+                    },
+                }
+            },
+            _ => {},
+        }
+        func_body_closed = false;
+    }
+
+    warnings
+}
+
+// Scoping is dynamic, so a variable is considered used if its name is
+// read anywhere in the program
+fn unused_variables(statements: &[Stmt]) -> Vec<PakhiWarning> {
+    let mut used: HashSet<String> = HashSet::new();
+    for stmt in statements {
+        match stmt {
+            Stmt::Print(expr, _, _) | Stmt::PrintNoEOL(expr, _, _) | Stmt::Expression(expr, _, _)
+            | Stmt::Return(expr, _, _) | Stmt::If(expr, _, _) => collect_var_names(expr, &mut used),
+            Stmt::ForEach(for_each, _, _) => collect_var_names(&for_each.iterable, &mut used),
+            Stmt::Assignment(assignment, _, _) => {
+                // assigning to an element reads the variable
+                if assignment.kind == AssignmentKind::Reassignment && !assignment.indexes.is_empty() {
+                    used.insert(assignment.var_name.lexeme.iter().collect());
+                }
+                for index in &assignment.indexes {
+                    collect_var_names(index, &mut used);
+                }
+                if let Some(init_value) = &assignment.init_value {
+                    collect_var_names(init_value, &mut used);
+                }
+            },
+            _ => {},
+        }
+    }
+
+    let mut warnings: Vec<PakhiWarning> = Vec::new();
+    for stmt in statements {
+        if let Stmt::Assignment(assignment, line, file_path) = stmt {
+            if assignment.kind != AssignmentKind::FirstAssignment {
+                continue;
+            }
+            let var_name: String = assignment.var_name.lexeme.iter().collect();
+            // imported module's variables are prefixed with module name, those may be used by importer
+            // so they are not checked
+            if var_name.contains('/') || used.contains(&var_name) {
+                continue;
+            }
+            warnings.push(PakhiWarning {
+                line: *line,
+                file_path: file_path.clone(),
+                message: format!("Variable {} declared but never used", var_name),
+            });
+        }
+    }
+
+    warnings
+}
+
+fn collect_var_names(expr: &Expr, names: &mut HashSet<String>) {
+    match expr {
+        Expr::Indexing(left, right, _, _) | Expr::Range(left, right, _, _) => {
+            collect_var_names(left, names);
+            collect_var_names(right, names);
+        },
+        Expr::Or(or, _, _) => {
+            collect_var_names(&or.left, names);
+            collect_var_names(&or.right, names);
+        },
+        Expr::And(and, _, _) => {
+            collect_var_names(&and.left, names);
+            collect_var_names(&and.right, names);
+        },
+        Expr::Equality(binary, _, _) | Expr::Comparison(binary, _, _)
+        | Expr::AddOrSub(binary, _, _) | Expr::MulOrDivOrRemainder(binary, _, _) => {
+            collect_var_names(&binary.left, names);
+            collect_var_names(&binary.right, names);
+        },
+        Expr::Unary(unary, _, _) => collect_var_names(&unary.right, names),
+        Expr::Call(call, _, _) => {
+            collect_var_names(&call.expr, names);
+            for argument in &call.arguments {
+                collect_var_names(argument, names);
+            }
+        },
+        Expr::Primary(primary, _, _) => match primary {
+            Primary::Var(token) => { names.insert(token.lexeme.iter().collect()); },
+            Primary::Group(expr) => collect_var_names(expr, names),
+            Primary::List(elements) => {
+                for element in elements {
+                    collect_var_names(element, names);
+                }
+            },
+            Primary::NamelessRecord((keys, values)) => {
+                for expr in keys.iter().chain(values.iter()) {
+                    collect_var_names(expr, names);
+                }
+            },
+            _ => {},
+        },
+    }
+}
+
+fn stmt_meta(stmt: &Stmt) -> (u32, String) {
+    match stmt {
+        Stmt::Print(_, line, file_path) | Stmt::PrintNoEOL(_, line, file_path)
+        | Stmt::Assignment(_, line, file_path) | Stmt::Expression(_, line, file_path)
+        | Stmt::Return(_, line, file_path) | Stmt::If(_, line, file_path)
+        | Stmt::ForEach(_, line, file_path) => (*line, file_path.clone()),
+        Stmt::BlockStart(line, file_path) | Stmt::BlockEnd(line, file_path) | Stmt::FuncDef(line, file_path)
+        | Stmt::Loop(line, file_path) | Stmt::Continue(line, file_path) | Stmt::Break(line, file_path)
+        | Stmt::Else(line, file_path) | Stmt::EOS(line, file_path) => (*line, file_path.clone()),
+    }
+}
@@ -2,13 +2,24 @@ pub mod frontend;
 pub mod backend;
 pub mod common;
 
-use crate::frontend::{lexer, parser};
+use crate::frontend::{lexer, parser, warning};
 use crate::backend::interpreter;
 use crate::common::io::IO;
 use crate::common::pakhi_error::PakhiErr;
 use crate::common::pakhi_error::PakhiErr::UnexpectedError;
 
+// Configuration of a pakhi run, set from command line flags
+#[derive(Debug, Clone, Default)]
+pub struct Options {
+    // Enables all correctness checks, see Interpreter::set_strict
+    pub strict: bool,
+}
+
 pub fn start_pakhi<T: IO>(main_module_path: String, io: &mut T) -> Result<(), PakhiErr>{
+    start_pakhi_with_options(main_module_path, io, &Options::default())
+}
+
+pub fn start_pakhi_with_options<T: IO>(main_module_path: String, io: &mut T, options: &Options) -> Result<(), PakhiErr>{
     //println!("Source file: {}", filename);
     match io.read_src_code_from_file(&main_module_path) {
         Ok(src_string) => {
@@ -18,11 +29,14 @@ pub fn start_pakhi<T: IO>(main_module_path: String, io: &mut T) -> Result<(), Pa
             //println!("{:#?}", tokens);
             let ast_tree = parser::parse(main_module_path, tokens)?;
             //println!("Ast : {:#?}", ast_tree);
+            for w in warning::check(&ast_tree) {
+                io.warn(&w);
+            }
 
             // println!();
             // println!("Interpreter");
             // println!("____________");
-            return interpreter::run(ast_tree);
+            interpreter::run(ast_tree, io, options.strict)
         },
         Err(e) => return Err(UnexpectedError(format!("{}", e))),
     }
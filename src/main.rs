@@ -1,13 +1,22 @@
 use std::env;
-use pakhi::start_pakhi;
+use pakhi::{start_pakhi_with_options, Options};
 use pakhi::common::io::{RealIO, IO};
 
 fn main() {
-    let main_module_path = get_main_module_path();
+    let args: Vec<String> = env::args().collect();
+    let (flags, args): (Vec<String>, Vec<String>) = args.into_iter().partition(|arg| arg.starts_with("--"));
+    let options = match get_options(&flags) {
+        Ok(options) => options,
+        Err(e) => {
+            eprintln!("Err: {}", e);
+            return;
+        },
+    };
+    let main_module_path = get_main_module_path(&args);
     match main_module_path {
         Ok(path) => {
             let mut io = RealIO::new();
-            if let Err(err) = start_pakhi(path, &mut io) {
+            if let Err(err) = start_pakhi_with_options(path, &mut io, &options) {
                 io.panic(err);
             }
         },
@@ -15,9 +24,20 @@ fn main() {
     }
 }
 
-fn get_main_module_path() -> Result<String, &'static str> {
-    let args: Vec<String> = env::args().collect();
+// Supported flags
+// --strict: enables all correctness checks (strict redeclaration)
+fn get_options(flags: &[String]) -> Result<Options, String> {
+    let mut options = Options::default();
+    for flag in flags {
+        match flag.as_str() {
+            "--strict" => options.strict = true,
+            _ => return Err(format!("Unknown flag {}", flag)),
+        }
+    }
+    Ok(options)
+}
 
+fn get_main_module_path(args: &[String]) -> Result<String, &'static str> {
     if args.len() < 2 {
         Err("Needs src filename.")
     } else if args.len() > 2 {
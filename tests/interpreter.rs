@@ -1,7 +1,7 @@
 use pakhi::frontend::{lexer, parser};
 use pakhi::frontend::parser::Stmt;
 use pakhi::common::io::{MockIO, IO};
-use pakhi::backend::interpreter::Interpreter;
+use pakhi::backend::interpreter::{Interpreter, DataType};
 use pakhi::common::pakhi_error::PakhiErr;
 
 fn src_to_ast(src_lines: Vec<&str>) -> Vec<Stmt> {
@@ -116,6 +116,288 @@ fn list_multi_dim_mixed_indexing() {
     }
 }
 
+#[test]
+fn and_or_short_circuit() {
+    let ast = src_to_ast(vec![
+        "ফাং চালানো() {",
+        "   দেখাও \"চালানো হয়েছে\";",
+        "   ফেরত সত্য;",
+        "} ফেরত;",
+        "নাম ক = [];",
+        "দেখাও _লিস্ট-লেন(ক) > ০ & ক[০] > ০;",
+        "দেখাও মিথ্যা & চালানো();",
+        "দেখাও সত্য | চালানো();",
+        "দেখাও সত্য & চালানো();",
+        "দেখাও মিথ্যা | চালানো();",
+    ]);
+    let mut mock_io: MockIO = MockIO::new();
+    mock_io.expect_println("মিথ্যা");
+    mock_io.expect_println("মিথ্যা");
+    mock_io.expect_println("সত্য");
+    mock_io.expect_println("চালানো হয়েছে");
+    mock_io.expect_println("সত্য");
+    mock_io.expect_println("চালানো হয়েছে");
+    mock_io.expect_println("সত্য");
+    if let Err(err) = run_assert_all_true(ast, mock_io) {
+        panic!("{:?}", err);
+    }
+}
+
+#[test]
+fn operands_evaluated_left_to_right() {
+    let ast = src_to_ast(vec![
+        "নাম গণনা = ০;",
+        "ফাং পরের() {",
+        "   গণনা = গণনা + ১;",
+        "   ফেরত গণনা;",
+        "} ফেরত;",
+        "ফাং পরের-সত্য() {",
+        "   গণনা = গণনা + ১;",
+        "   দেখাও গণনা;",
+        "   ফেরত সত্য;",
+        "} ফেরত;",
+        // left operand is called first so it gets smaller count
+        "দেখাও পরের() * ১০ + পরের();",
+        "দেখাও পরের() / পরের();",
+        "দেখাও পরের() % পরের();",
+        "দেখাও পরের-সত্য() & পরের-সত্য();",
+        "দেখাও পরের-সত্য() == মিথ্যা | পরের-সত্য();",
+    ]);
+    let mut mock_io: MockIO = MockIO::new();
+    mock_io.expect_println("১২");
+    mock_io.expect_println("০.৭৫");
+    mock_io.expect_println("৫");
+    mock_io.expect_println("৭");
+    mock_io.expect_println("৮");
+    mock_io.expect_println("সত্য");
+    mock_io.expect_println("৯");
+    mock_io.expect_println("১০");
+    mock_io.expect_println("সত্য");
+    if let Err(err) = run_assert_all_true(ast, mock_io) {
+        panic!("{:?}", err);
+    }
+}
+
+#[test]
+fn and_or_non_bool_operand() {
+    let ast = src_to_ast(vec![
+        "দেখাও সত্য & ১;",
+    ]);
+    let result = run_assert_all_true(ast, MockIO::new());
+    assert_eq!(Err(PakhiErr::TypeError(1, "test.pakhi".to_string(),
+        "Datatype doesn't support and operation".to_string())), result);
+
+    let ast = src_to_ast(vec![
+        "দেখাও ১ | সত্য;",
+    ]);
+    let result = run_assert_all_true(ast, MockIO::new());
+    assert_eq!(Err(PakhiErr::TypeError(1, "test.pakhi".to_string(),
+        "Datatype doesn't support or operation".to_string())), result);
+}
+
+#[test]
+fn division_by_zero() {
+    let ast = src_to_ast(vec![
+        "দেখাও ৫ / ০;",
+    ]);
+    let result = run_assert_all_true(ast, MockIO::new());
+    assert_eq!(Err(PakhiErr::RuntimeError(1, "test.pakhi".to_string(),
+        "Division by zero".to_string())), result);
+
+    let ast = src_to_ast(vec![
+        "নাম ক = ০;",
+        "দেখাও ৫ % ক;",
+    ]);
+    let result = run_assert_all_true(ast, MockIO::new());
+    assert_eq!(Err(PakhiErr::RuntimeError(2, "test.pakhi".to_string(),
+        "Division by zero".to_string())), result);
+}
+
+#[test]
+fn print_non_finite_num() {
+    let ast = src_to_ast(vec![
+        "নাম ক = ১০;",
+        "নাম খ = ০;",
+        "লুপ {",
+        "   ক = ক * ক;",
+        "   খ = খ + ১;",
+        "   যদি খ >= ১০ {",
+        "       থামাও;",
+        "   }",
+        "} আবার;",
+        "দেখাও ক;",
+        "দেখাও -ক;",
+        "দেখাও ক - ক;",
+    ]);
+    let mut mock_io: MockIO = MockIO::new();
+    mock_io.expect_println("অসীম");
+    mock_io.expect_println("-অসীম");
+    mock_io.expect_println("অসংজ্ঞায়িত");
+    if let Err(err) = run_assert_all_true(ast, mock_io) {
+        panic!("{:?}", err);
+    }
+}
+
+#[test]
+fn remainder_and_modulo_negative() {
+    let ast = src_to_ast(vec![
+        "দেখাও -৭ % ৩;",
+        "দেখাও _মড(-৭, ৩);",
+        "দেখাও _মড(৭, ৩);",
+        "দেখাও _মড(৭, -৩);",
+        "দেখাও _মড(৭, ০);",
+    ]);
+    let mut mock_io: MockIO = MockIO::new();
+    mock_io.expect_println("-১");
+    mock_io.expect_println("২");
+    mock_io.expect_println("১");
+    mock_io.expect_println("-২");
+    let result = run_assert_all_true(ast, mock_io);
+    assert_eq!(Err(PakhiErr::RuntimeError(5, "test.pakhi".to_string(),
+        "_মড() division by zero".to_string())), result);
+}
+
+#[test]
+fn built_in_fn_floor_ceil_round() {
+    let ast = src_to_ast(vec![
+        "দেখাও _ফ্লোর(২.৭);",
+        "দেখাও _ফ্লোর(-২.২);",
+        "দেখাও _সিল(২.২);",
+        "দেখাও _সিল(-২.৭);",
+        "দেখাও _রাউন্ড(২.৫);",
+        "দেখাও _রাউন্ড(২.৪);",
+        "দেখাও _রাউন্ড(-২.৫);",
+        "দেখাও _ফ্লোর(\"২.৫\");",
+    ]);
+    let mut mock_io: MockIO = MockIO::new();
+    for expected in ["২", "-৩", "৩", "-২", "৩", "২", "-৩"] {
+        mock_io.expect_println(expected);
+    }
+    let result = run_assert_all_true(ast, mock_io);
+    assert_eq!(Err(PakhiErr::RuntimeError(8, "test.pakhi".to_string(),
+        "_ফ্লোর() function's argument must be number".to_string())), result);
+}
+
+#[test]
+fn built_in_fn_square_root_power() {
+    let ast = src_to_ast(vec![
+        "দেখাও _স্কয়ার-রুট(১৬);",
+        "দেখাও _স্কয়ার-রুট(২.২৫);",
+        "দেখাও _পাওয়ার(২, ১০);",
+        "দেখাও _পাওয়ার(৪, ০.৫);",
+        "দেখাও _পাওয়ার(২, -১);",
+        "দেখাও _স্কয়ার-রুট(-৪);",
+    ]);
+    let mut mock_io: MockIO = MockIO::new();
+    for expected in ["৪", "১.৫", "১০২৪", "২", "০.৫"] {
+        mock_io.expect_println(expected);
+    }
+    let result = run_assert_all_true(ast, mock_io);
+    assert_eq!(Err(PakhiErr::RuntimeError(6, "test.pakhi".to_string(),
+        "_স্কয়ার-রুট() can't find square root of negative number".to_string())), result);
+}
+
+#[test]
+fn built_in_fn_abs_max_min() {
+    let ast = src_to_ast(vec![
+        "দেখাও _অ্যাবস(-৩.৫);",
+        "দেখাও _অ্যাবস(২);",
+        "দেখাও _ম্যাক্স(৩, ৭);",
+        "দেখাও _মিন(৩, ৭);",
+        "দেখাও _ম্যাক্স([৪, -২, ৯, ১]);",
+        "দেখাও _মিন([৪, -২, ৯, ১]);",
+        "দেখাও _মিন([৪, \"২\"]);",
+    ]);
+    let mut mock_io: MockIO = MockIO::new();
+    for expected in ["৩.৫", "২", "৭", "৩", "৯", "-২"] {
+        mock_io.expect_println(expected);
+    }
+    let result = run_assert_all_true(ast, mock_io);
+    assert_eq!(Err(PakhiErr::RuntimeError(7, "test.pakhi".to_string(),
+        "_মিন() list must only contain numbers".to_string())), result);
+
+    let ast = src_to_ast(vec![
+        "দেখাও _ম্যাক্স([]);",
+    ]);
+    let result = run_assert_all_true(ast, MockIO::new());
+    assert_eq!(Err(PakhiErr::RuntimeError(1, "test.pakhi".to_string(),
+        "_ম্যাক্স() list is empty".to_string())), result);
+}
+
+#[test]
+fn built_in_fn_valid_utf8() {
+    let ast = src_to_ast(vec![
+        // "পাখি" encoded as utf-8
+        "দেখাও _বৈধ-ইউটিএফ৮([২২৪, ১৬৬, ১৭০, ২২৪, ১৬৬, ১৯০, ২২৪, ১৬৬, ১৫০, ২২৪, ১৬৬, ১৯১]);",
+        "দেখাও _বৈধ-ইউটিএফ৮([]);",
+        "দেখাও _বৈধ-ইউটিএফ৮([২২৪, ১৬৬]);",
+        "দেখাও _বৈধ-ইউটিএফ৮([২৫৫, ৬৫]);",
+        "দেখাও _বৈধ-ইউটিএফ৮([৩০০]);",
+        "দেখাও _বৈধ-ইউটিএফ৮([\"ক\"]);",
+    ]);
+    let mut mock_io: MockIO = MockIO::new();
+    for expected in ["সত্য", "সত্য", "মিথ্যা", "মিথ্যা", "মিথ্যা", "মিথ্যা"] {
+        mock_io.expect_println(expected);
+    }
+    if let Err(err) = run_assert_all_true(ast, mock_io) {
+        panic!("{:?}", err);
+    }
+}
+
+#[test]
+fn list_index_out_of_range() {
+    let ast = src_to_ast(vec![
+        "নাম ক = [১, ২, ৩];",
+        "দেখাও ক[১০];",
+    ]);
+    let result = run_assert_all_true(ast, MockIO::new());
+    assert_eq!(Err(PakhiErr::RuntimeError(2, "test.pakhi".to_string(),
+        "List index ১০ out of range, list length is ৩".to_string())), result);
+
+    let ast = src_to_ast(vec![
+        "নাম ক = [১, ২, ৩];",
+        "দেখাও ক[-১];",
+    ]);
+    let result = run_assert_all_true(ast, MockIO::new());
+    assert_eq!(Err(PakhiErr::RuntimeError(2, "test.pakhi".to_string(),
+        "List index -১ out of range, list length is ৩".to_string())), result);
+}
+
+#[test]
+fn string_indexing() {
+    let ast = src_to_ast(vec![
+        "নাম ক = \"কখগ\";",
+        "দেখাও ক[১];",
+        "নাম খ = \"abc\";",
+        "দেখাও খ[২];",
+        "দেখাও ক[৩];",
+    ]);
+    let mut mock_io: MockIO = MockIO::new();
+    mock_io.expect_println("খ");
+    mock_io.expect_println("c");
+    let result = run_assert_all_true(ast, mock_io);
+    assert_eq!(Err(PakhiErr::RuntimeError(5, "test.pakhi".to_string(),
+        "String index ৩ out of range, string length is ৩".to_string())), result);
+}
+
+#[test]
+fn record_missing_key() {
+    let ast = src_to_ast(vec![
+        "নাম ক = @{\"আছে\" -> ১};",
+        "দেখাও ক[\"আছে\"];",
+        "দেখাও ক[\"নেই\"];",
+    ]);
+    let mut mock_io: MockIO = MockIO::new();
+    mock_io.expect_println("১");
+    match run_assert_all_true(ast, mock_io) {
+        Err(PakhiErr::RuntimeError(line, _, msg)) => {
+            assert_eq!(3, line);
+            assert!(msg.contains("নেই"), "{}", msg);
+        },
+        result => panic!("expected runtime error, got {:?}", result),
+    }
+}
+
 #[test]
 fn built_in_fn_list_mutate_push() {
     let ast = src_to_ast(vec![
@@ -158,6 +440,27 @@ fn built_in_fn_list_pop_middle() {
     }
 }
 
+#[test]
+fn built_in_fn_list_pop_returns_removed() {
+    let ast = src_to_ast(vec![
+        "নাম ক = [১, ২, ৩];",
+        "নাম শেষ = _লিস্ট-পপ(ক);",
+        "দেখাও শেষ;",
+        "দেখাও _লিস্ট-পপ(ক, ০);",
+        "দেখাও _লিস্ট-পপ(ক);",
+        "দেখাও _টাইপ(_লিস্ট-পপ(ক));",
+        "_লিস্ট-পপ([১], ৫);",
+    ]);
+    let mut mock_io: MockIO = MockIO::new();
+    mock_io.expect_println("৩");
+    mock_io.expect_println("১");
+    mock_io.expect_println("২");
+    mock_io.expect_println("_শূন্য");
+    let result = run_assert_all_true(ast, mock_io);
+    assert_eq!(Err(PakhiErr::RuntimeError(7, "test.pakhi".to_string(),
+        "_লিস্ট-পপ() index ৫ out of range, list length is ১".to_string())), result);
+}
+
 #[test]
 fn built_in_fn_list_len() {
     let ast = src_to_ast(vec![
@@ -398,6 +701,154 @@ fn loop_test() {
     }
 }
 
+#[test]
+fn for_each_list() {
+    let ast = src_to_ast(vec![
+        "নাম মোট = ০;",
+        "ফরইচ [১, ২, ৩, ৪] -> ক {",
+        "   যদি ক == ৪ {",
+        "       থামাও;",
+        "   }",
+        "   মোট = মোট + ক;",
+        "   ফরইচ [] -> খ {",
+        "       দেখাও খ;",
+        "   } আবার;",
+        "} আবার;",
+        "দেখাও মোট;",
+    ]);
+    let mut mock_io: MockIO = MockIO::new();
+    mock_io.expect_println("৬");
+    if let Err(err) = run_assert_all_true(ast, mock_io) {
+        panic!("{:?}", err);
+    }
+}
+
+#[test]
+fn for_each_string() {
+    let ast = src_to_ast(vec![
+        "নাম অক্ষরগুলো = [];",
+        "ফরইচ \"পাখি\" -> অক্ষর {",
+        "   _লিস্ট-পুশ(অক্ষরগুলো, অক্ষর);",
+        "} আবার;",
+        "দেখাও অক্ষরগুলো;",
+        "ফরইচ ১ -> ক {",
+        "} আবার;",
+    ]);
+    let mut mock_io: MockIO = MockIO::new();
+    for piece in ["[", "প", ", ", "া", ", ", "খ", ", ", "ি"] {
+        mock_io.expect_print(piece);
+    }
+    mock_io.expect_println("]");
+    let result = run_assert_all_true(ast, mock_io);
+    assert_eq!(Err(PakhiErr::RuntimeError(6, "test.pakhi".to_string(),
+        "ফরইচ can only iterate list or string".to_string())), result);
+}
+
+#[test]
+fn count_loop_test() {
+    let ast = src_to_ast(vec![
+        "গুনলুপ ই = ০..৩ {",
+        "   দেখাও ই;",
+        "} আবার;",
+        "নাম শেষ = ৫;",
+        "গুনলুপ ই = ১..শেষ {",
+        "   যদি ই == ৩ {",
+        "       থামাও;",
+        "   }",
+        "   গুনলুপ জ = ০..ই {",
+        "       _দেখাও জ;",
+        "   } আবার;",
+        "   দেখাও \"\";",
+        "} আবার;",
+        "গুনলুপ ই = ৩..১ {",
+        "   দেখাও ই;",
+        "} আবার;",
+    ]);
+    let mut mock_io: MockIO = MockIO::new();
+    mock_io.expect_println("০");
+    mock_io.expect_println("১");
+    mock_io.expect_println("২");
+    mock_io.expect_print("০");
+    mock_io.expect_println("");
+    mock_io.expect_print("০");
+    mock_io.expect_print("১");
+    mock_io.expect_println("");
+    if let Err(err) = run_assert_all_true(ast, mock_io) {
+        panic!("{:?}", err);
+    }
+}
+
+#[test]
+fn while_loop_test() {
+    let ast = src_to_ast(vec![
+        "নাম ক = ০;",
+        "যতক্ষণ ক < ৫ {",
+        "   দেখাও ক;",
+        "   ক = ক + ১;",
+        "} আবার;",
+        "যতক্ষণ সত্য {",
+        "   থামাও;",
+        "} আবার;",
+        "যতক্ষণ ক < ৫ {",
+        "   দেখাও ক;",
+        "} আবার;",
+        "দেখাও ক;",
+    ]);
+    let mut mock_io: MockIO = MockIO::new();
+    for expected in ["০", "১", "২", "৩", "৪", "৫"] {
+        mock_io.expect_println(expected);
+    }
+    if let Err(err) = run_assert_all_true(ast, mock_io) {
+        panic!("{:?}", err);
+    }
+}
+
+#[test]
+fn compound_assignment() {
+    let ast = src_to_ast(vec![
+        "নাম ক = ১০;",
+        "ক += ৫;",
+        "দেখাও ক;",
+        "ক -= ৩;",
+        "দেখাও ক;",
+        "ক *= ২ + ১;",
+        "দেখাও ক;",
+        "ক /= ৪;",
+        "দেখাও ক;",
+        "নাম স = \"পা\";",
+        "স += \"খি\";",
+        "দেখাও স;",
+    ]);
+    let mut mock_io: MockIO = MockIO::new();
+    for expected in ["১৫", "১২", "৩৬", "৯", "পাখি"] {
+        mock_io.expect_println(expected);
+    }
+    if let Err(err) = run_assert_all_true(ast, mock_io) {
+        panic!("{:?}", err);
+    }
+}
+
+#[test]
+fn compound_assignment_indexed() {
+    let ast = src_to_ast(vec![
+        "নাম ক = [১, ২, [৩, ৪]];",
+        "ক[১] += ২;",
+        "দেখাও ক[১];",
+        "ক[২][০] *= ক[১];",
+        "দেখাও ক[২][০];",
+        "নাম র = @{\"গণনা\" -> ১};",
+        "র[\"গণনা\"] -= ১;",
+        "দেখাও র[\"গণনা\"];",
+    ]);
+    let mut mock_io: MockIO = MockIO::new();
+    for expected in ["৪", "১২", "০"] {
+        mock_io.expect_println(expected);
+    }
+    if let Err(err) = run_assert_all_true(ast, mock_io) {
+        panic!("{:?}", err);
+    }
+}
+
 #[test]
 fn loop_no_new_env() {
     let ast = src_to_ast(vec![
@@ -451,6 +902,64 @@ fn recursive_function_call() {
     }
 }
 
+#[test]
+fn function_call_argument_count() {
+    let ast = src_to_ast(vec![
+        "ফাং যোগ(ক, খ) {",
+        "   ফেরত ক + খ;",
+        "} ফেরত;",
+        "দেখাও যোগ(১, ২);",
+        "দেখাও যোগ(১);",
+    ]);
+    let mut mock_io: MockIO = MockIO::new();
+    mock_io.expect_println("৩");
+    let result = run_assert_all_true(ast, mock_io);
+    assert_eq!(Err(PakhiErr::RuntimeError(5, "test.pakhi".to_string(),
+        "Function 'যোগ' expects 2 argument, but 1 passed".to_string())), result);
+
+    let ast = src_to_ast(vec![
+        "ফাং যোগ(ক, খ) {",
+        "   ফেরত ক + খ;",
+        "} ফেরত;",
+        "যোগ(১, ২, ৩);",
+    ]);
+    let result = run_assert_all_true(ast, MockIO::new());
+    assert_eq!(Err(PakhiErr::RuntimeError(4, "test.pakhi".to_string(),
+        "Function 'যোগ' expects 2 argument, but 3 passed".to_string())), result);
+}
+
+#[test]
+fn call_non_function_value() {
+    let ast = src_to_ast(vec![
+        "নাম ক = ৫;",
+        "ক();",
+    ]);
+    let result = run_assert_all_true(ast, MockIO::new());
+    assert_eq!(Err(PakhiErr::TypeError(2, "test.pakhi".to_string(),
+        "ক is not a function".to_string())), result);
+
+    let ast = src_to_ast(vec![
+        "নাম ক = ৫;",
+        "খ();",
+    ]);
+    let result = run_assert_all_true(ast, MockIO::new());
+    assert_eq!(Err(PakhiErr::RuntimeError(2, "test.pakhi".to_string(),
+        "Function 'খ' not Declared".to_string())), result);
+}
+
+#[test]
+fn recursion_depth_limit() {
+    let ast = src_to_ast(vec![
+        "ফাং অসীম(ক) {",
+        "   ফেরত অসীম(ক + ১);",
+        "} ফেরত;",
+        "অসীম(০);",
+    ]);
+    let result = run_assert_all_true(ast, MockIO::new());
+    assert_eq!(Err(PakhiErr::RuntimeError(2, "test.pakhi".to_string(),
+        "Maximum recursion depth exceeded".to_string())), result);
+}
+
 #[test]
 #[should_panic]
 fn built_in_fn_error() {
@@ -574,4 +1083,2050 @@ fn built_in_const_platform() {
     if let Err(err) = run_assert_all_true(ast, mock_io) {
         panic!("{:?}", err);
     }
-}
\ No newline at end of file
+}
+#[test]
+fn built_in_fn_json_parse() {
+    let ast = src_to_ast(vec![
+        r#"নাম ক = _জেসন-পার্স("[1, 2.5, -3, [true, null]]");"#,
+        r#"দেখাও ক[২];"#,
+        r#"দেখাও ক[৩][০];"#,
+        r#"দেখাও _টাইপ(ক[৩][১]);"#,
+    ]);
+    let mut mock_io: MockIO = MockIO::new();
+    mock_io.expect_println("-৩");
+    mock_io.expect_println("সত্য");
+    mock_io.expect_println("_শূন্য");
+    if let Err(err) = run_assert_all_true(ast, mock_io) {
+        panic!("{:?}", err);
+    }
+}
+
+#[test]
+fn built_in_fn_json_parse_surrogate_pair() {
+    let ast = src_to_ast(vec![
+        r#"দেখাও _জেসন-পার্স("\"\\uD83D\\uDE00 \\u0995\"");"#,
+        r#"_জেসন-পার্স("\"\\uD83D\"");"#,
+    ]);
+    let mut mock_io: MockIO = MockIO::new();
+    mock_io.expect_println("😀 ক");
+    let result = run_assert_all_true(ast, mock_io);
+    assert_eq!(Err(PakhiErr::RuntimeError(2, "test.pakhi".to_string(),
+        "_জেসন-পার্স(): Invalid unicode code point \\uD83D in json string".to_string())), result);
+}
+
+#[test]
+fn built_in_fn_json_parse_invalid() {
+    let ast = src_to_ast(vec![
+        r#"নাম ক = _জেসন-পার্স("[1, 2");"#,
+    ]);
+    let mock_io: MockIO = MockIO::new();
+    let result = run_assert_all_true(ast, mock_io);
+    assert_eq!(Err(PakhiErr::RuntimeError(1, "test.pakhi".to_string(),
+                                          "_জেসন-পার্স(): Expected ',' or ']' in json array".to_string())), result);
+}
+
+#[test]
+fn built_in_fn_json_parse_safe() {
+    let ast = src_to_ast(vec![
+        r#"নাম ক = _জেসন-পার্স-নিরাপদ("[1, [2, 3]]");"#,
+        r#"দেখাও ক[১][১];"#,
+        r#"নাম খ = _জেসন-পার্স-নিরাপদ("[1, ");"#,
+        r#"দেখাও _টাইপ(খ);"#,
+    ]);
+    let mut mock_io: MockIO = MockIO::new();
+    mock_io.expect_println("৩");
+    mock_io.expect_println("_শূন্য");
+    if let Err(err) = run_assert_all_true(ast, mock_io) {
+        panic!("{:?}", err);
+    }
+}
+
+#[test]
+fn incremental_gc_keeps_reachable_objects() {
+    let ast = src_to_ast(vec![
+        "নাম রাখা = [];",
+        "নাম ক = ০;",
+        "লুপ {",
+        "   নাম ফেলা = [১, ২, ৩, ৪, ৫, ৬, ৭, ৮, ৯, ১০];",
+        "   _লিস্ট-পুশ(রাখা, [ক, @{\"মান\" -> ক,}]);",
+        "   ক = ক + ১;",
+        "   যদি ক >= ৫০০ {",
+        "       থামাও;",
+        "   }",
+        "} আবার;",
+        "দেখাও _লিস্ট-লেন(রাখা);",
+        "দেখাও রাখা[১০০][০];",
+        "দেখাও রাখা[৪৯৯][১][\"মান\"];",
+    ]);
+    let mut mock_io: MockIO = MockIO::new();
+    mock_io.expect_println("৫০০");
+    mock_io.expect_println("১০০");
+    mock_io.expect_println("৪৯৯");
+
+    let mut interpreter = Interpreter::new(ast, &mut mock_io);
+    interpreter.set_incremental_gc(true);
+    if let Err(err) = interpreter.run() {
+        panic!("{:?}", err);
+    }
+    mock_io.assert_all_true();
+}
+
+#[test]
+fn built_in_fn_memory_info() {
+    let ast = src_to_ast(vec![
+        "নাম আগে = _মেমোরি-তথ্য();",
+        "দেখাও আগে[\"তালিকা\"];",
+        "দেখাও আগে[\"রেকর্ড\"];",
+        "দেখাও আগে[\"মুক্ত-তালিকা\"];",
+        "নাম ক = ০;",
+        "লুপ {",
+        "   নাম ফেলা = [১, ২, ৩, ৪, ৫, ৬, ৭, ৮, ৯, ১০];",
+        "   ক = ক + ১;",
+        "   যদি ক >= ১২০ {",
+        "       থামাও;",
+        "   }",
+        "} আবার;",
+        "নাম পরে = _মেমোরি-তথ্য();",
+        "দেখাও পরে[\"তালিকা\"] < ১২০;",
+        "দেখাও পরে[\"মুক্ত-তালিকা\"] > ০;",
+        "দেখাও পরে[\"রেকর্ড\"];",
+    ]);
+    let mut mock_io: MockIO = MockIO::new();
+    mock_io.expect_println("০");
+    mock_io.expect_println("০");
+    mock_io.expect_println("০");
+    mock_io.expect_println("সত্য");
+    mock_io.expect_println("সত্য");
+    mock_io.expect_println("১");
+    if let Err(err) = run_assert_all_true(ast, mock_io) {
+        panic!("{:?}", err);
+    }
+}
+
+#[test]
+fn string_interning_shares_repeated_strings() {
+    let src = vec![
+        "নাম ফল = _স্ট্রিং-স্প্লিট(\"আম,জাম,আম,আম\", \",\");",
+        "_লিস্ট-পুশ(ফল, \"আম\");",
+        "দেখাও ফল[৩];",
+        "দেখাও ফল[০] == ফল[৪];",
+        "দেখাও ফল[১] == ফল[২];",
+        "নাম তথ্য = _মেমোরি-তথ্য();",
+        "দেখাও তথ্য[\"স্ট্রিং-বাইট\"];",
+    ];
+
+    let mut mock_io: MockIO = MockIO::new();
+    mock_io.expect_println("আম");
+    mock_io.expect_println("সত্য");
+    mock_io.expect_println("মিথ্যা");
+    // five strings stored separately
+    mock_io.expect_println("৩৩");
+    if let Err(err) = run_assert_all_true(src_to_ast(src.clone()), mock_io) {
+        panic!("{:?}", err);
+    }
+
+    let mut mock_io: MockIO = MockIO::new();
+    mock_io.expect_println("আম");
+    mock_io.expect_println("সত্য");
+    mock_io.expect_println("মিথ্যা");
+    // only one copy of আম and জাম
+    mock_io.expect_println("১৫");
+    let mut interpreter = Interpreter::new(src_to_ast(src), &mut mock_io);
+    interpreter.set_string_interning(true);
+    if let Err(err) = interpreter.run() {
+        panic!("{:?}", err);
+    }
+    mock_io.assert_all_true();
+}
+
+#[test]
+fn built_in_fn_print_table() {
+    let ast = src_to_ast(vec![
+        "নাম সারি = [@{\"a\" -> \"x\", \"bb\" -> ১০,}, @{\"a\" -> \"yyy\", \"bb\" -> সত্য,}];",
+        "_প্রিন্ট-সারণি(সারি);",
+    ]);
+    let mut mock_io: MockIO = MockIO::new();
+    mock_io.expect_println("a   | bb");
+    mock_io.expect_println("----+-----");
+    mock_io.expect_println("x   | ১০");
+    mock_io.expect_println("yyy | সত্য");
+    if let Err(err) = run_assert_all_true(ast, mock_io) {
+        panic!("{:?}", err);
+    }
+}
+
+#[test]
+fn built_in_fn_print_table_mismatched_keys() {
+    let ast = src_to_ast(vec![
+        "নাম সারি = [@{\"a\" -> ১,}, @{\"b\" -> ২,}];",
+        "_প্রিন্ট-সারণি(সারি);",
+    ]);
+    let mock_io: MockIO = MockIO::new();
+    let result = run_assert_all_true(ast, mock_io);
+    assert_eq!(Err(PakhiErr::RuntimeError(2, "test.pakhi".to_string(),
+        "_প্রিন্ট-সারণি() row 1 has keys [b] but first row has keys [a]".to_string())), result);
+}
+
+#[test]
+fn redeclaration_overwrites_by_default() {
+    let ast = src_to_ast(vec![
+        "নাম ক = ১;",
+        "নাম ক = ২;",
+        "দেখাও ক;",
+    ]);
+    let mut mock_io: MockIO = MockIO::new();
+    mock_io.expect_println("২");
+    if let Err(err) = run_assert_all_true(ast, mock_io) {
+        panic!("{:?}", err);
+    }
+}
+
+#[test]
+fn strict_redeclaration_errors_in_same_scope() {
+    let ast = src_to_ast(vec![
+        "নাম ক = ১;",
+        "ক = ২;",
+        "যদি সত্য {",
+        "   নাম ক = ৩;",
+        "   দেখাও ক;",
+        "}",
+        "নাম ক = ৪;",
+    ]);
+    let mut mock_io: MockIO = MockIO::new();
+    mock_io.expect_println("৩");
+    let mut interpreter = Interpreter::new(ast, &mut mock_io);
+    interpreter.set_strict_redeclaration(true);
+    let result = interpreter.run();
+    assert_eq!(Err(PakhiErr::RuntimeError(7, "test.pakhi".to_string(),
+        "Variable was already declared in this scope ক".to_string())), result);
+    mock_io.assert_all_true();
+}
+
+#[test]
+fn built_in_fn_stack_trace() {
+    let ast = src_to_ast(vec![
+        "দেখাও _লিস্ট-লেন(_স্ট্যাক-ট্রেস());",
+        "ফাং ভিতর() {",
+        "   নাম ট্রেস = _স্ট্যাক-ট্রেস();",
+        "   দেখাও _লিস্ট-লেন(ট্রেস);",
+        "   দেখাও ট্রেস[০];",
+        "   দেখাও ট্রেস[১];",
+        "} ফেরত;",
+        "ফাং বাহির() {",
+        "   ভিতর();",
+        "} ফেরত;",
+        "বাহির();",
+        "দেখাও _লিস্ট-লেন(_স্ট্যাক-ট্রেস());",
+    ]);
+    let mut mock_io: MockIO = MockIO::new();
+    mock_io.expect_println("০");
+    mock_io.expect_println("২");
+    mock_io.expect_println("ভিতর (test.pakhi: লাইন ৯)");
+    mock_io.expect_println("বাহির (test.pakhi: লাইন ১১)");
+    mock_io.expect_println("০");
+    if let Err(err) = run_assert_all_true(ast, mock_io) {
+        panic!("{:?}", err);
+    }
+}
+
+#[test]
+fn list_slice_assignment() {
+    let ast = src_to_ast(vec![
+        "নাম ক = [১, ২, ৩, ৪];",
+        "ক[১..৩] = [৮, ৯];",
+        "দেখাও ক[১];",
+        "দেখাও ক[২];",
+        "ক[০..২] = [৭];",
+        "দেখাও _লিস্ট-লেন(ক);",
+        "দেখাও ক[০];",
+        "দেখাও ক[১];",
+    ]);
+    let mut mock_io: MockIO = MockIO::new();
+    mock_io.expect_println("৮");
+    mock_io.expect_println("৯");
+    mock_io.expect_println("৩");
+    mock_io.expect_println("৭");
+    mock_io.expect_println("৯");
+    if let Err(err) = run_assert_all_true(ast, mock_io) {
+        panic!("{:?}", err);
+    }
+}
+
+#[test]
+fn list_slice_assignment_out_of_bounds() {
+    let ast = src_to_ast(vec![
+        "নাম ক = [১, ২];",
+        "ক[১..৩] = [৮, ৯];",
+    ]);
+    let mock_io: MockIO = MockIO::new();
+    let result = run_assert_all_true(ast, mock_io);
+    assert_eq!(Err(PakhiErr::RuntimeError(2, "test.pakhi".to_string(),
+        "Slice range is out of list bounds".to_string())), result);
+}
+
+#[test]
+fn list_slice_assignment_not_whole_bounds() {
+    for (range, shown) in [("-১..২", "-১..২"), ("১.৫..২", "১.৫..২")] {
+        let ast = src_to_ast(vec![
+            "নাম ক = [১, ২, ৩, ৪];",
+            &format!("ক[{}] = [৯];", range),
+        ]);
+        let mock_io: MockIO = MockIO::new();
+        let result = run_assert_all_true(ast, mock_io);
+        assert_eq!(Err(PakhiErr::RuntimeError(2, "test.pakhi".to_string(),
+            format!("Slice range {} must be whole numbers", shown))), result);
+    }
+}
+
+#[test]
+fn built_in_fn_format_num() {
+    let ast = src_to_ast(vec![
+        "দেখাও _স্ট্রিং-ফরম্যাট-সংখ্যা(১০০০০০০);",
+        "দেখাও _স্ট্রিং-ফরম্যাট-সংখ্যা(১২৩৪৫৬৭, ৩);",
+        "দেখাও _স্ট্রিং-ফরম্যাট-সংখ্যা(-১২৩৪৫.৬৭);",
+        "দেখাও _স্ট্রিং-ফরম্যাট-সংখ্যা(৯৯৯);",
+    ]);
+    let mut mock_io: MockIO = MockIO::new();
+    mock_io.expect_println("১০,০০,০০০");
+    mock_io.expect_println("১,২৩৪,৫৬৭");
+    mock_io.expect_println("-১২,৩৪৫");
+    mock_io.expect_println("৯৯৯");
+    if let Err(err) = run_assert_all_true(ast, mock_io) {
+        panic!("{:?}", err);
+    }
+}
+
+#[test]
+fn built_in_fn_list_position_max_min() {
+    let ast = src_to_ast(vec![
+        "নাম ক = [৩, ১, ৪, ১];",
+        "দেখাও _লিস্ট-পজিশন-ম্যাক্স(ক);",
+        "দেখাও _লিস্ট-পজিশন-মিন(ক);",
+    ]);
+    let mut mock_io: MockIO = MockIO::new();
+    mock_io.expect_println("২");
+    mock_io.expect_println("১");
+    if let Err(err) = run_assert_all_true(ast, mock_io) {
+        panic!("{:?}", err);
+    }
+}
+
+#[test]
+fn built_in_fn_list_position_max_empty() {
+    let ast = src_to_ast(vec![
+        "_লিস্ট-পজিশন-ম্যাক্স([]);",
+    ]);
+    let mock_io: MockIO = MockIO::new();
+    let result = run_assert_all_true(ast, mock_io);
+    assert_eq!(Err(PakhiErr::RuntimeError(1, "test.pakhi".to_string(),
+        "_লিস্ট-পজিশন-ম্যাক্স() list is empty".to_string())), result);
+}
+
+#[test]
+fn built_in_fn_count() {
+    let ast = src_to_ast(vec![
+        "নাম ক = [১, ২, ১, \"১\", ১];",
+        "দেখাও _গণনা(ক, ১);",
+        "দেখাও _গণনা(ক, ৫);",
+    ]);
+    let mut mock_io: MockIO = MockIO::new();
+    mock_io.expect_println("৩");
+    mock_io.expect_println("০");
+    if let Err(err) = run_assert_all_true(ast, mock_io) {
+        panic!("{:?}", err);
+    }
+}
+
+#[test]
+fn built_in_fn_list_group() {
+    let ast = src_to_ast(vec![
+        "ফাং জোড়-বিজোড়(ক) {",
+        "   যদি ক % ২ == ০ {",
+        "       ফেরত \"জোড়\";",
+        "   }",
+        "   ফেরত \"বিজোড়\";",
+        "} ফেরত;",
+        "নাম দল = _লিস্ট-গ্রুপ([১, ২, ৩, ৪, ৫], জোড়-বিজোড়);",
+        "দেখাও _লিস্ট-লেন(দল[\"জোড়\"]);",
+        "দেখাও দল[\"জোড়\"][১];",
+        "দেখাও _লিস্ট-লেন(দল[\"বিজোড়\"]);",
+        "দেখাও দল[\"বিজোড়\"][২];",
+    ]);
+    let mut mock_io: MockIO = MockIO::new();
+    mock_io.expect_println("২");
+    mock_io.expect_println("৪");
+    mock_io.expect_println("৩");
+    mock_io.expect_println("৫");
+    if let Err(err) = run_assert_all_true(ast, mock_io) {
+        panic!("{:?}", err);
+    }
+}
+
+#[test]
+fn built_in_fn_list_group_non_string_key() {
+    let ast = src_to_ast(vec![
+        "ফাং চাবি(ক) {",
+        "   ফেরত ক;",
+        "} ফেরত;",
+        "_লিস্ট-গ্রুপ([১, ২], চাবি);",
+    ]);
+    let mock_io: MockIO = MockIO::new();
+    let result = run_assert_all_true(ast, mock_io);
+    assert_eq!(Err(PakhiErr::RuntimeError(4, "test.pakhi".to_string(),
+        "_লিস্ট-গ্রুপ() key function must return string".to_string())), result);
+}
+
+#[test]
+fn string_interpolation() {
+    let ast = src_to_ast(vec![
+        "নাম ক = ৫;",
+        "দেখাও \"মান ${ক}\";",
+        "দেখাও \"${ক + ১} এবং ${ক * ২}\";",
+        "দেখাও \"${ক > ১} ${\"ভিতরে\"}\";",
+        "দেখাও \"দাম \\${ক}\";",
+    ]);
+    let mut mock_io: MockIO = MockIO::new();
+    mock_io.expect_println("মান ৫");
+    mock_io.expect_println("৬ এবং ১০");
+    mock_io.expect_println("সত্য ভিতরে");
+    mock_io.expect_println("দাম ${ক}");
+    if let Err(err) = run_assert_all_true(ast, mock_io) {
+        panic!("{:?}", err);
+    }
+}
+
+#[test]
+fn string_interpolation_of_list() {
+    let ast = src_to_ast(vec![
+        "নাম ক = [১, ২];",
+        "দেখাও \"মান ${ক}\";",
+    ]);
+    let mock_io: MockIO = MockIO::new();
+    let result = run_assert_all_true(ast, mock_io);
+    assert_eq!(Err(PakhiErr::RuntimeError(2, "test.pakhi".to_string(),
+        "_ইন্টারপোলেট() only number, bool, string or nil can be interpolated".to_string())), result);
+}
+
+#[test]
+fn return_from_nested_if_inside_loop() {
+    let ast = src_to_ast(vec![
+        "ফাং খোঁজো(লিস্ট, মান) {",
+        "   নাম ই = ০;",
+        "   লুপ {",
+        "       যদি ই >= _লিস্ট-লেন(লিস্ট) {",
+        "           থামাও;",
+        "       }",
+        "       যদি লিস্ট[ই] == মান {",
+        "           যদি সত্য {",
+        "               ফেরত ই;",
+        "           }",
+        "       }",
+        "       ই = ই + ১;",
+        "   } আবার;",
+        "   ফেরত -১;",
+        "} ফেরত;",
+        "নাম গণনা = ০;",
+        "লুপ {",
+        "   যদি গণনা == ৩ {",
+        "       থামাও;",
+        "   }",
+        "   দেখাও খোঁজো([৫, ৬, ৭], ৬);",
+        "   গণনা = গণনা + ১;",
+        "} আবার;",
+        "দেখাও গণনা;",
+        "দেখাও খোঁজো([৫, ৬, ৭], ৯);",
+    ]);
+    let mut mock_io: MockIO = MockIO::new();
+    mock_io.expect_println("১");
+    mock_io.expect_println("১");
+    mock_io.expect_println("১");
+    mock_io.expect_println("৩");
+    mock_io.expect_println("-১");
+    if let Err(err) = run_assert_all_true(ast, mock_io) {
+        panic!("{:?}", err);
+    }
+}
+
+#[test]
+fn built_in_fn_list_all_any() {
+    let ast = src_to_ast(vec![
+        "ফাং ধনাত্মক(ক) {",
+        "   ফেরত ক > ০;",
+        "} ফেরত;",
+        "দেখাও _লিস্ট-সব([১, ২, ৩], ধনাত্মক);",
+        "দেখাও _লিস্ট-সব([১, -২, ৩], ধনাত্মক);",
+        "দেখাও _লিস্ট-সব([-১, -২], ধনাত্মক);",
+        "দেখাও _লিস্ট-কোনো([১, ২, ৩], ধনাত্মক);",
+        "দেখাও _লিস্ট-কোনো([-১, ২, -৩], ধনাত্মক);",
+        "দেখাও _লিস্ট-কোনো([-১, -২], ধনাত্মক);",
+        "দেখাও _লিস্ট-সব([], ধনাত্মক);",
+        // predicate is not called after result is known, "ক" > ০ would be an error
+        "দেখাও _লিস্ট-কোনো([১, \"ক\"], ধনাত্মক);",
+        "দেখাও _লিস্ট-সব([-১, \"ক\"], ধনাত্মক);",
+    ]);
+    let mut mock_io: MockIO = MockIO::new();
+    mock_io.expect_println("সত্য");
+    mock_io.expect_println("মিথ্যা");
+    mock_io.expect_println("মিথ্যা");
+    mock_io.expect_println("সত্য");
+    mock_io.expect_println("সত্য");
+    mock_io.expect_println("মিথ্যা");
+    mock_io.expect_println("সত্য");
+    mock_io.expect_println("সত্য");
+    mock_io.expect_println("মিথ্যা");
+    if let Err(err) = run_assert_all_true(ast, mock_io) {
+        panic!("{:?}", err);
+    }
+}
+
+#[test]
+fn built_in_fn_list_flat_map() {
+    let ast = src_to_ast(vec![
+        "ফাং দুইবার(ক) {",
+        "   ফেরত [ক, ক];",
+        "} ফেরত;",
+        "ফাং নিজে(ক) {",
+        "   ফেরত ক;",
+        "} ফেরত;",
+        "নাম ফল = _লিস্ট-ফ্ল্যাট-ম্যাপ([১, ২, ৩], দুইবার);",
+        "দেখাও ফল;",
+        "দেখাও _লিস্ট-লেন(_লিস্ট-ফ্ল্যাট-ম্যাপ([], দুইবার));",
+        // non list result is kept as single element, only one level is flattened
+        "ফল = _লিস্ট-ফ্ল্যাট-ম্যাপ([১, [২, [৩]]], নিজে);",
+        "দেখাও _লিস্ট-লেন(ফল);",
+        "দেখাও ফল[২][০];",
+    ]);
+    let mut mock_io: MockIO = MockIO::new();
+    for piece in vec!["[", "১", ", ", "১", ", ", "২", ", ", "২", ", ", "৩", ", "] {
+        mock_io.expect_print(piece);
+    }
+    mock_io.expect_print("৩");
+    mock_io.expect_println("]");
+    mock_io.expect_println("০");
+    mock_io.expect_println("৩");
+    mock_io.expect_println("৩");
+    if let Err(err) = run_assert_all_true(ast, mock_io) {
+        panic!("{:?}", err);
+    }
+}
+
+#[test]
+fn built_in_fn_list_scan() {
+    let ast = src_to_ast(vec![
+        "ফাং যোগ(মোট, ক) {",
+        "   ফেরত মোট + ক;",
+        "} ফেরত;",
+        "দেখাও _লিস্ট-স্ক্যান([১, ২, ৩], যোগ, ০);",
+        "দেখাও _লিস্ট-লেন(_লিস্ট-স্ক্যান([], যোগ, ০));",
+    ]);
+    let mut mock_io: MockIO = MockIO::new();
+    for piece in ["[", "১", ", ", "৩", ", ", "৬"] {
+        mock_io.expect_print(piece);
+    }
+    mock_io.expect_println("]");
+    mock_io.expect_println("০");
+    if let Err(err) = run_assert_all_true(ast, mock_io) {
+        panic!("{:?}", err);
+    }
+}
+
+#[test]
+fn built_in_fn_zip_with() {
+    let ast = src_to_ast(vec![
+        "ফাং যোগ(ক, খ) {",
+        "   ফেরত ক + খ;",
+        "} ফেরত;",
+        "দেখাও _জিপ-দিয়ে([১, ২, ৩], [১০, ২০, ৩০], যোগ);",
+        "দেখাও _জিপ-দিয়ে([১, ২], [১০, ২০, ৩০], যোগ);",
+        "দেখাও _লিস্ট-লেন(_জিপ-দিয়ে([], [১], যোগ));",
+        "_জিপ-দিয়ে([১], ২, যোগ);",
+    ]);
+    let mut mock_io: MockIO = MockIO::new();
+    for piece in ["[", "১১", ", ", "২২", ", ", "৩৩"] {
+        mock_io.expect_print(piece);
+    }
+    mock_io.expect_println("]");
+    for piece in ["[", "১১", ", ", "২২"] {
+        mock_io.expect_print(piece);
+    }
+    mock_io.expect_println("]");
+    mock_io.expect_println("০");
+    let result = run_assert_all_true(ast, mock_io);
+    assert_eq!(Err(PakhiErr::RuntimeError(7, "test.pakhi".to_string(),
+        "_জিপ-দিয়ে() function's first two arguments must be list".to_string())), result);
+}
+
+#[test]
+fn built_in_fn_random_shuffle() {
+    let ast = src_to_ast(vec![
+        "ফাং নিজে(ক) {",
+        "   ফেরত ক;",
+        "} ফেরত;",
+        "নাম ক = [৫, ৩, ১, ৪, ২, ৩];",
+        "_রান্ডম-শাফল(ক);",
+        "দেখাও _লিস্ট-লেন(ক);",
+        "দেখাও _লিস্ট-সাজাও-কী(ক, নিজে);",
+        "নাম খ = _র‍্যান্ডম();",
+        "দেখাও খ >= ০ & খ < ১;",
+        "_রান্ডম-শাফল([]);",
+    ]);
+    let mut mock_io: MockIO = MockIO::new();
+    mock_io.expect_println("৬");
+    for piece in vec!["[", "১", ", ", "২", ", ", "৩", ", ", "৩", ", ", "৪", ", ", "৫"] {
+        mock_io.expect_print(piece);
+    }
+    mock_io.expect_println("]");
+    mock_io.expect_println("সত্য");
+    if let Err(err) = run_assert_all_true(ast, mock_io) {
+        panic!("{:?}", err);
+    }
+}
+
+#[test]
+fn built_in_fn_random_choice() {
+    let ast = src_to_ast(vec![
+        "নাম রঙ = [\"লাল\", \"নীল\", \"সবুজ\"];",
+        "নাম সব-আছে = সত্য;",
+        "নাম ই = ০;",
+        "লুপ {",
+        "   যদি ই >= ৫০ {",
+        "       থামাও;",
+        "   }",
+        "   নাম বাছাই = _র‍্যান্ডম-নির্বাচন(রঙ);",
+        "   যদি _গণনা(রঙ, বাছাই) != ১ {",
+        "       সব-আছে = মিথ্যা;",
+        "   }",
+        "   ই = ই + ১;",
+        "} আবার;",
+        "দেখাও সব-আছে;",
+        "_র‍্যান্ডম-নির্বাচন([]);",
+    ]);
+    let mut mock_io: MockIO = MockIO::new();
+    mock_io.expect_println("সত্য");
+    let result = run_assert_all_true(ast, mock_io);
+    assert_eq!(Err(PakhiErr::RuntimeError(15, "test.pakhi".to_string(),
+        "_র‍্যান্ডম-নির্বাচন() list is empty".to_string())), result);
+}
+
+#[test]
+fn built_in_fn_seed() {
+    let ast = src_to_ast(vec![
+        "_সিড(৪২);",
+        "নাম ক১ = _র‍্যান্ডম();",
+        "নাম ক২ = _র‍্যান্ডম();",
+        "নাম তাস১ = [১, ২, ৩, ৪, ৫, ৬, ৭, ৮];",
+        "_রান্ডম-শাফল(তাস১);",
+        "_সিড(৪২);",
+        "নাম খ১ = _র‍্যান্ডম();",
+        "নাম খ২ = _র‍্যান্ডম();",
+        "নাম তাস২ = [১, ২, ৩, ৪, ৫, ৬, ৭, ৮];",
+        "_রান্ডম-শাফল(তাস২);",
+        "দেখাও ক১ == খ১ & ক২ == খ২ & ক১ != ক২;",
+        "নাম ই = ০;",
+        "নাম সমান = সত্য;",
+        "ফরইচ তাস১ -> তাস {",
+        "   যদি তাস != তাস২[ই] {",
+        "       সমান = মিথ্যা;",
+        "   }",
+        "   ই = ই + ১;",
+        "} আবার;",
+        "দেখাও সমান;",
+    ]);
+    let mut mock_io: MockIO = MockIO::new();
+    mock_io.expect_println("সত্য");
+    mock_io.expect_println("সত্য");
+    if let Err(err) = run_assert_all_true(ast, mock_io) {
+        panic!("{:?}", err);
+    }
+}
+
+#[test]
+fn built_in_fn_url_query() {
+    let ast = src_to_ast(vec![
+        "দেখাও _ইউআরএল-কোয়েরি(@{\"q\" -> \"pakhi bhasha\", \"page\" -> \"a&b\"});",
+        "দেখাও _ইউআরএল-কোয়েরি(@{\"নাম\" -> \"ক\"});",
+        "দেখাও _ইউআরএল-কোয়েরি(@{\"ক\" -> [১]});",
+    ]);
+    let mut mock_io: MockIO = MockIO::new();
+    mock_io.expect_println("page=a%26b&q=pakhi%20bhasha");
+    mock_io.expect_println("%E0%A6%A8%E0%A6%BE%E0%A6%AE=%E0%A6%95");
+    let result = run_assert_all_true(ast, mock_io);
+    assert_eq!(Err(PakhiErr::RuntimeError(3, "test.pakhi".to_string(),
+        "_ইউআরএল-কোয়েরি() record values must be number, bool, string or nil".to_string())), result);
+}
+
+#[test]
+fn built_in_fn_tokenize() {
+    let ast = src_to_ast(vec![
+        "নাম টোকেন = _টোকেনাইজ(\"নাম ক = ১; দেখাও ক;\");",
+        "দেখাও _লিস্ট-লেন(টোকেন);",
+        "নাম ই = ০;",
+        "লুপ {",
+        "   যদি ই == _লিস্ট-লেন(টোকেন) {",
+        "       থামাও;",
+        "   }",
+        "   নাম ট = টোকেন[ই];",
+        "   দেখাও _স্ট্রিং-জয়েন([ট[\"ধরন\"], ট[\"লেক্সিম\"], _স্ট্রিং(ট[\"লাইন\"])], \" \");",
+        "   ই = ই + ১;",
+        "} আবার;",
+    ]);
+    let mut mock_io: MockIO = MockIO::new();
+    mock_io.expect_println("৮");
+    mock_io.expect_println("Var নাম ১");
+    mock_io.expect_println("Identifier ক ১");
+    mock_io.expect_println("Equal = ১");
+    mock_io.expect_println("Num ১ ১");
+    mock_io.expect_println("Semicolon ; ১");
+    mock_io.expect_println("Print দেখাও ১");
+    mock_io.expect_println("Identifier ক ১");
+    mock_io.expect_println("Semicolon ; ১");
+    if let Err(err) = run_assert_all_true(ast, mock_io) {
+        panic!("{:?}", err);
+    }
+}
+
+#[test]
+fn built_in_fn_tokenize_invalid_source() {
+    let ast = src_to_ast(vec![
+        "_টোকেনাইজ(\"ক \\ খ\");",
+    ]);
+    let mock_io: MockIO = MockIO::new();
+    let result = run_assert_all_true(ast, mock_io);
+    assert_eq!(Err(PakhiErr::RuntimeError(1, "test.pakhi".to_string(),
+        "_টোকেনাইজ() couldn't tokenize source at line 1: Unexpected character '\\'".to_string())), result);
+}
+
+#[test]
+fn built_in_fn_eval() {
+    let ast = src_to_ast(vec![
+        "_ইভাল(\"দেখাও ১ + ১;\");",
+        "_ইভাল(\"নাম ক = ৪০;\");",
+        "দেখাও ক + ২;",
+        "দেখাও _ইভাল(\"ক * ২\");",
+        "_ইভাল(\"ফাং দ্বিগুণ(খ) { ফেরত খ * ২; } ফেরত;\");",
+        "দেখাও দ্বিগুণ(৩);",
+        "দেখাও _টাইপ(_ইভাল(\"নাম গ = ১;\"));",
+        "_ইভাল(\"নাম ঘ = ০; লুপ { ঘ = ঘ + ১; যদি ঘ == ২ { থামাও; } } আবার; ফাং বাইরে(ঙ) { ফাং ভিতরে(চ) { ফেরত চ + ঘ; } ফেরত; ফেরত ভিতরে(ঙ); } ফেরত;\");",
+        "দেখাও বাইরে(১০);",
+    ]);
+    let mut mock_io: MockIO = MockIO::new();
+    mock_io.expect_println("২");
+    mock_io.expect_println("৪২");
+    mock_io.expect_println("৮০");
+    mock_io.expect_println("৬");
+    mock_io.expect_println("_শূন্য");
+    mock_io.expect_println("১২");
+    if let Err(err) = run_assert_all_true(ast, mock_io) {
+        panic!("{:?}", err);
+    }
+}
+
+#[test]
+fn built_in_fn_eval_error() {
+    let ast = src_to_ast(vec![
+        "নাম ক = ১;",
+        "_ইভাল(\"দেখাও খ;\");",
+    ]);
+    let mock_io: MockIO = MockIO::new();
+    let result = run_assert_all_true(ast, mock_io);
+    assert_eq!(Err(PakhiErr::RuntimeError(2, "test.pakhi".to_string(),
+        "_ইভাল() error at line 1: Variable wasn't initialized খ".to_string())), result);
+}
+
+#[test]
+fn built_in_fn_eval_sandbox() {
+    let ast = src_to_ast(vec![
+        "_ইভাল(\"দেখাও ১;\");",
+    ]);
+    let mut mock_io: MockIO = MockIO::new();
+    let mut interpreter = Interpreter::new(ast, &mut mock_io);
+    interpreter.set_sandbox(true);
+    let result = interpreter.run();
+    assert_eq!(Err(PakhiErr::RuntimeError(1, "test.pakhi".to_string(),
+        "_ইভাল() function is not available in sandbox mode".to_string())), result);
+    mock_io.assert_all_true();
+}
+
+#[test]
+fn built_in_fn_change_dir_sandbox() {
+    let ast = src_to_ast(vec![
+        "_ডাইরেক্টরি-বদল(\"..\");",
+    ]);
+    let mut mock_io: MockIO = MockIO::new();
+    let mut interpreter = Interpreter::new(ast, &mut mock_io);
+    interpreter.set_sandbox(true);
+    let result = interpreter.run();
+    assert_eq!(Err(PakhiErr::RuntimeError(1, "test.pakhi".to_string(),
+        "_ডাইরেক্টরি-বদল() function is not available in sandbox mode".to_string())), result);
+    mock_io.assert_all_true();
+}
+
+#[test]
+fn built_in_fn_file_system_sandbox() {
+    let blocked = [
+        ("_রাইট-ফাইল(\"sandbox.txt\", \"ক\");", "_রাইট-ফাইল"),
+        ("_ডিলিট-ফাইল(\"sandbox.txt\");", "_ডিলিট-ফাইল"),
+        ("_নতুন-ডাইরেক্টরি(\"sandbox\");", "_নতুন-ডাইরেক্টরি"),
+        ("_ডিলিট-ডাইরেক্টরি(\"sandbox\");", "_ডিলিট-ডাইরেক্টরি"),
+        ("_ফাইল-খোলো(\"sandbox.txt\", \"লেখা\");", "_ফাইল-খোলো"),
+    ];
+    for (src, name) in blocked {
+        let ast = src_to_ast(vec![src]);
+        let mut mock_io: MockIO = MockIO::new();
+        let mut interpreter = Interpreter::new(ast, &mut mock_io);
+        interpreter.set_sandbox(true);
+        let result = interpreter.run();
+        assert_eq!(Err(PakhiErr::RuntimeError(1, "test.pakhi".to_string(),
+            format!("{}() function is not available in sandbox mode", name))), result);
+        assert!(!std::path::Path::new("sandbox.txt").exists());
+        assert!(!std::path::Path::new("sandbox").exists());
+    }
+}
+
+#[test]
+fn built_in_fn_list_partition() {
+    let ast = src_to_ast(vec![
+        "ফাং জোড়(ক) {",
+        "   ফেরত ক % ২ == ০;",
+        "} ফেরত;",
+        "নাম ভাগ = _লিস্ট-পার্টিশন([১, ২, ৩, ৪, ৫], জোড়);",
+        "দেখাও ভাগ[০];",
+        "দেখাও ভাগ[১];",
+        "দেখাও _লিস্ট-পার্টিশন([], জোড়);",
+    ]);
+    let mut mock_io: MockIO = MockIO::new();
+    for piece in ["[", "২", ", "] {
+        mock_io.expect_print(piece);
+    }
+    mock_io.expect_print("৪");
+    mock_io.expect_println("]");
+    for piece in ["[", "১", ", ", "৩", ", "] {
+        mock_io.expect_print(piece);
+    }
+    mock_io.expect_print("৫");
+    mock_io.expect_println("]");
+    for piece in ["[", "[", "]", ", ", "[", "]"] {
+        mock_io.expect_print(piece);
+    }
+    mock_io.expect_println("]");
+    if let Err(err) = run_assert_all_true(ast, mock_io) {
+        panic!("{:?}", err);
+    }
+}
+
+#[test]
+fn built_in_fn_list_partition_non_bool_predicate() {
+    let ast = src_to_ast(vec![
+        "ফাং দ্বিগুণ(ক) {",
+        "   ফেরত ক * ২;",
+        "} ফেরত;",
+        "_লিস্ট-পার্টিশন([১], দ্বিগুণ);",
+    ]);
+    let mock_io: MockIO = MockIO::new();
+    let result = run_assert_all_true(ast, mock_io);
+    assert_eq!(Err(PakhiErr::RuntimeError(4, "test.pakhi".to_string(),
+        "_লিস্ট-পার্টিশন() predicate function must return bool".to_string())), result);
+}
+
+#[test]
+fn built_in_fn_string_template() {
+    let ast = src_to_ast(vec![
+        "নাম তথ্য = @{\"নাম\" -> \"সিফাত\", \"বয়স\" -> ৪২};",
+        "দেখাও _স্ট্রিং-টেমপ্লেট(\"{নাম} এর বয়স {বয়স}\", তথ্য);",
+        // unknown key and unclosed brace are kept as it is
+        "দেখাও _স্ট্রিং-টেমপ্লেট(\"{ফোন} {নাম\", তথ্য);",
+    ]);
+    let mut mock_io: MockIO = MockIO::new();
+    mock_io.expect_println("সিফাত এর বয়স ৪২");
+    mock_io.expect_println("{ফোন} {নাম");
+    if let Err(err) = run_assert_all_true(ast, mock_io) {
+        panic!("{:?}", err);
+    }
+}
+
+#[test]
+fn built_in_fn_list_take_drop() {
+    let ast = src_to_ast(vec![
+        "নাম ক = [১, ২, ৩];",
+        "দেখাও _লিস্ট-টেক(ক, ২);",
+        "দেখাও _লিস্ট-ড্রপ(ক, ২);",
+        "দেখাও _লিস্ট-টেক(ক, ৩);",
+        "দেখাও _লিস্ট-লেন(_লিস্ট-ড্রপ(ক, ৩));",
+        "দেখাও _লিস্ট-টেক(ক, ১০);",
+        "দেখাও _লিস্ট-লেন(_লিস্ট-ড্রপ(ক, ১০));",
+        "দেখাও _লিস্ট-লেন(_লিস্ট-টেক(ক, ০));",
+    ]);
+    let mut mock_io: MockIO = MockIO::new();
+    for piece in ["[", "১", ", ", "২"] {
+        mock_io.expect_print(piece);
+    }
+    mock_io.expect_println("]");
+    mock_io.expect_print("[");
+    mock_io.expect_print("৩");
+    mock_io.expect_println("]");
+    for _ in 0..2 {
+        for piece in ["[", "১", ", ", "২", ", ", "৩"] {
+            mock_io.expect_print(piece);
+        }
+        mock_io.expect_println("]");
+        mock_io.expect_println("০");
+    }
+    mock_io.expect_println("০");
+    if let Err(err) = run_assert_all_true(ast, mock_io) {
+        panic!("{:?}", err);
+    }
+}
+
+#[test]
+fn built_in_fn_list_split_even() {
+    let ast = src_to_ast(vec![
+        "নাম ভাগ = _লিস্ট-সমান-ভাগ([১, ২, ৩, ৪, ৫, ৬, ৭], ৩);",
+        "দেখাও _লিস্ট-লেন(ভাগ);",
+        "ফরইচ ভাগ -> অংশ {",
+        "   দেখাও _লিস্ট-লেন(অংশ);",
+        "} আবার;",
+        "দেখাও ভাগ[০];",
+        "দেখাও ভাগ[২];",
+    ]);
+    let mut mock_io: MockIO = MockIO::new();
+    for expected in ["৩", "৩", "২", "২"] {
+        mock_io.expect_println(expected);
+    }
+    for piece in ["[", "১", ", ", "২", ", ", "৩"] {
+        mock_io.expect_print(piece);
+    }
+    mock_io.expect_println("]");
+    for piece in ["[", "৬", ", ", "৭"] {
+        mock_io.expect_print(piece);
+    }
+    mock_io.expect_println("]");
+    if let Err(err) = run_assert_all_true(ast, mock_io) {
+        panic!("{:?}", err);
+    }
+
+    let ast = src_to_ast(vec![
+        "_লিস্ট-সমান-ভাগ([১, ২], ০);",
+    ]);
+    let result = run_assert_all_true(ast, MockIO::new());
+    assert_eq!(Err(PakhiErr::RuntimeError(1, "test.pakhi".to_string(),
+        "_লিস্ট-সমান-ভাগ() function's second argument must be positive whole number".to_string())), result);
+
+    let ast = src_to_ast(vec![
+        "দেখাও _লিস্ট-সমান-ভাগ([১, ২], ৩);",
+        "_লিস্ট-সমান-ভাগ([১, ২], ১০০০০০০০০০০০০০০০০০০০);",
+    ]);
+    let mut mock_io: MockIO = MockIO::new();
+    for piece in ["[", "[", "১"] {
+        mock_io.expect_print(piece);
+    }
+    mock_io.expect_print("]");
+    for piece in [", ", "[", "২"] {
+        mock_io.expect_print(piece);
+    }
+    mock_io.expect_print("]");
+    mock_io.expect_print(", ");
+    mock_io.expect_print("[]");
+    mock_io.expect_println("]");
+    let result = run_assert_all_true(ast, mock_io);
+    assert_eq!(Err(PakhiErr::RuntimeError(2, "test.pakhi".to_string(),
+        "_লিস্ট-সমান-ভাগ() couldn't allocate memory for that many parts".to_string())), result);
+}
+
+#[test]
+fn built_in_fn_list_take_negative() {
+    let ast = src_to_ast(vec![
+        "_লিস্ট-টেক([১, ২], -১);",
+    ]);
+    let result = run_assert_all_true(ast, MockIO::new());
+    assert_eq!(Err(PakhiErr::RuntimeError(1, "test.pakhi".to_string(),
+        "_লিস্ট-টেক() function's second argument must be non negative whole number".to_string())), result);
+}
+
+#[test]
+fn ascii_digits_output() {
+    let src = vec![
+        "নাম ক = [১২, -৩.৫];",
+        "দেখাও ৪২;",
+        "_দেখাও ৭;",
+        "দেখাও ক;",
+        "দেখাও _স্ট্রিং(১০০);",
+    ];
+    let mut mock_io: MockIO = MockIO::new();
+    mock_io.expect_println("৪২");
+    mock_io.expect_print("৭");
+    for piece in ["[", "১২", ", ", "-৩.৫"] {
+        mock_io.expect_print(piece);
+    }
+    mock_io.expect_println("]");
+    mock_io.expect_println("১০০");
+    if let Err(err) = run_assert_all_true(src_to_ast(src.clone()), mock_io) {
+        panic!("{:?}", err);
+    }
+
+    let mut mock_io: MockIO = MockIO::new();
+    mock_io.expect_println("42");
+    mock_io.expect_print("7");
+    for piece in ["[", "12", ", ", "-3.5"] {
+        mock_io.expect_print(piece);
+    }
+    mock_io.expect_println("]");
+    mock_io.expect_println("100");
+    let mut interpreter = Interpreter::new(src_to_ast(src), &mut mock_io);
+    interpreter.set_ascii_digits(true);
+    if let Err(err) = interpreter.run() {
+        panic!("{:?}", err);
+    }
+    mock_io.assert_all_true();
+}
+
+#[test]
+fn built_in_fn_system_info() {
+    let ast = src_to_ast(vec![
+        "নাম তথ্য = _সিস্টেম-তথ্য();",
+        "দেখাও _রেকর্ড-আকার(তথ্য);",
+        "দেখাও তথ্য[\"ওএস\"] == _প্ল্যাটফর্ম;",
+        "দেখাও _টাইপ(তথ্য[\"আর্কিটেকচার\"]);",
+        "দেখাও তথ্য[\"সিপিইউ\"] >= ১;",
+    ]);
+    let mut mock_io: MockIO = MockIO::new();
+    mock_io.expect_println("৩");
+    mock_io.expect_println("সত্য");
+    mock_io.expect_println("_স্ট্রিং");
+    mock_io.expect_println("সত্য");
+    if let Err(err) = run_assert_all_true(ast, mock_io) {
+        panic!("{:?}", err);
+    }
+}
+
+#[test]
+fn built_in_fn_record_keys() {
+    let ast = src_to_ast(vec![
+        "নাম ক = @{\"খ\" -> ২, \"ক\" -> ১, \"গ\" -> ৩};",
+        "দেখাও _রেকর্ড-কী(ক);",
+        "দেখাও _লিস্ট-লেন(_রেকর্ড-কী(@{}));",
+    ]);
+    let mut mock_io: MockIO = MockIO::new();
+    for piece in ["[", "ক", ", ", "খ", ", ", "গ"] {
+        mock_io.expect_print(piece);
+    }
+    mock_io.expect_println("]");
+    mock_io.expect_println("০");
+    if let Err(err) = run_assert_all_true(ast, mock_io) {
+        panic!("{:?}", err);
+    }
+}
+
+#[test]
+fn built_in_fn_time_parse_round_trip() {
+    let ast = src_to_ast(vec![
+        "নাম সময় = _সময়-পার্স(\"2024-02-29T13:45:30Z\");",
+        "দেখাও সময়;",
+        "দেখাও _সময়-স্ট্রিং(সময়);",
+        "দেখাও _সময়-পার্স(\"২০২৪-০২-২৯T১৯:৪৫:৩০+06:00\") == সময়;",
+        "দেখাও _সময়-স্ট্রিং(০);",
+        "দেখাও _সময়-স্ট্রিং(-১);",
+        "_সময়-পার্স(\"2023-02-29T00:00:00Z\");",
+    ]);
+    let mut mock_io: MockIO = MockIO::new();
+    mock_io.expect_println("১৭০৯২১৪৩৩০");
+    mock_io.expect_println("2024-02-29T13:45:30Z");
+    mock_io.expect_println("সত্য");
+    mock_io.expect_println("1970-01-01T00:00:00Z");
+    mock_io.expect_println("1969-12-31T23:59:59Z");
+    let result = run_assert_all_true(ast, mock_io);
+    assert_eq!(Err(PakhiErr::RuntimeError(7, "test.pakhi".to_string(),
+        "_সময়-পার্স() invalid date-time 2023-02-29T00:00:00Z, expected format YYYY-MM-DDTHH:MM:SSZ".to_string())), result);
+}
+
+#[test]
+fn built_in_fn_record_values() {
+    let ast = src_to_ast(vec![
+        "নাম ক = @{\"খ\" -> ২, \"ক\" -> ১, \"গ\" -> [৩]};",
+        "নাম মান = _রেকর্ড-ভ্যালু(ক);",
+        "দেখাও মান[০];",
+        "দেখাও মান[১];",
+        "দেখাও মান[২][০];",
+        "_রেকর্ড-ভ্যালু([১]);",
+    ]);
+    let mut mock_io: MockIO = MockIO::new();
+    mock_io.expect_println("১");
+    mock_io.expect_println("২");
+    mock_io.expect_println("৩");
+    let result = run_assert_all_true(ast, mock_io);
+    assert_eq!(Err(PakhiErr::RuntimeError(6, "test.pakhi".to_string(),
+        "_রেকর্ড-ভ্যালু() function's argument must be record".to_string())), result);
+}
+
+#[test]
+fn built_in_fn_record_has() {
+    let ast = src_to_ast(vec![
+        "নাম ক = @{\"আছে\" -> ১, \"শূন্য\" -> _লিস্ট-পপ([])};",
+        "দেখাও _রেকর্ড-আছে(ক, \"আছে\");",
+        "দেখাও _রেকর্ড-আছে(ক, \"শূন্য\");",
+        "দেখাও _রেকর্ড-আছে(ক, \"নেই\");",
+    ]);
+    let mut mock_io: MockIO = MockIO::new();
+    mock_io.expect_println("সত্য");
+    mock_io.expect_println("সত্য");
+    mock_io.expect_println("মিথ্যা");
+    if let Err(err) = run_assert_all_true(ast, mock_io) {
+        panic!("{:?}", err);
+    }
+}
+
+#[test]
+fn built_in_fn_record_update() {
+    let ast = src_to_ast(vec![
+        "নাম ক = @{\"নাম\" -> \"সিফাত\", \"বয়স\" -> ৪১};",
+        "নাম খ = _রেকর্ড-আপডেট(ক, \"বয়স\", ৪২);",
+        "নাম গ = _রেকর্ড-আপডেট(খ, \"শহর\", \"ঢাকা\");",
+        "দেখাও ক[\"বয়স\"];",
+        "দেখাও _রেকর্ড-আকার(ক);",
+        "দেখাও খ[\"বয়স\"];",
+        "দেখাও খ[\"নাম\"];",
+        "দেখাও _রেকর্ড-আছে(খ, \"শহর\");",
+        "দেখাও গ[\"শহর\"];",
+        "দেখাও _ইনস্পেক্ট(ক) == _ইনস্পেক্ট(খ);",
+    ]);
+    let mut mock_io: MockIO = MockIO::new();
+    for expected in ["৪১", "২", "৪২", "সিফাত", "মিথ্যা", "ঢাকা", "মিথ্যা"] {
+        mock_io.expect_println(expected);
+    }
+    if let Err(err) = run_assert_all_true(ast, mock_io) {
+        panic!("{:?}", err);
+    }
+}
+
+#[test]
+fn built_in_fn_process_id() {
+    let ast = src_to_ast(vec![
+        "দেখাও _প্রসেস-আইডি() > ০;",
+    ]);
+    let mut mock_io: MockIO = MockIO::new();
+    mock_io.expect_println("সত্য");
+    if let Err(err) = run_assert_all_true(ast, mock_io) {
+        panic!("{:?}", err);
+    }
+}
+
+#[test]
+fn built_in_fn_hex_round_trip() {
+    let ast = src_to_ast(vec![
+        "দেখাও _হেক্স(০);",
+        "দেখাও _হেক্স(২৫৫);",
+        "দেখাও _হেক্স(-২৫৫);",
+        "দেখাও _হেক্স(৯০০৭১৯৯২৫৪৭৪০৯৯১);",
+        "দেখাও _হেক্স-ডিকোড(\"0\");",
+        "দেখাও _হেক্স-ডিকোড(\"FF\");",
+        "দেখাও _হেক্স-ডিকোড(_হেক্স(৯০০৭১৯৯২৫৪৭৪০৯৯১)) == ৯০০৭১৯৯২৫৪৭৪০৯৯১;",
+        "দেখাও _হেক্স-ডিকোড(_হেক্স(-১২৩৪৫৬));",
+        "_হেক্স-ডিকোড(\"১২\");",
+    ]);
+    let mut mock_io: MockIO = MockIO::new();
+    mock_io.expect_println("0");
+    mock_io.expect_println("ff");
+    mock_io.expect_println("-ff");
+    mock_io.expect_println("1fffffffffffff");
+    mock_io.expect_println("০");
+    mock_io.expect_println("২৫৫");
+    mock_io.expect_println("সত্য");
+    mock_io.expect_println("-১২৩৪৫৬");
+    let result = run_assert_all_true(ast, mock_io);
+    assert_eq!(Err(PakhiErr::RuntimeError(9, "test.pakhi".to_string(),
+        "_হেক্স-ডিকোড() ১২ is not a valid number".to_string())), result);
+}
+
+#[test]
+fn built_in_fn_binary_octal_round_trip() {
+    let ast = src_to_ast(vec![
+        "দেখাও _বাইনারি(০);",
+        "দেখাও _বাইনারি(১০);",
+        "দেখাও _অক্টাল(৮);",
+        "দেখাও _অক্টাল(-৬৪);",
+        "নাম সংখ্যা = [০, ১, ৭, ২৫৫, ১০২৪, -৯৯, ৯০০৭১৯৯২৫৪৭৪০৯৯১];",
+        "নাম ই = ০;",
+        "নাম সব-ঠিক = সত্য;",
+        "লুপ {",
+        "   যদি ই == _লিস্ট-লেন(সংখ্যা) {",
+        "       থামাও;",
+        "   }",
+        "   নাম স = সংখ্যা[ই];",
+        "   সব-ঠিক = সব-ঠিক & _বাইনারি-সংখ্যা(_বাইনারি(স)) == স & _অক্টাল-সংখ্যা(_অক্টাল(স)) == স;",
+        "   ই = ই + ১;",
+        "} আবার;",
+        "দেখাও সব-ঠিক;",
+        "_বাইনারি-সংখ্যা(\"102\");",
+    ]);
+    let mut mock_io: MockIO = MockIO::new();
+    mock_io.expect_println("0");
+    mock_io.expect_println("1010");
+    mock_io.expect_println("10");
+    mock_io.expect_println("-100");
+    mock_io.expect_println("সত্য");
+    let result = run_assert_all_true(ast, mock_io);
+    assert_eq!(Err(PakhiErr::RuntimeError(17, "test.pakhi".to_string(),
+        "_বাইনারি-সংখ্যা() 102 is not a valid number".to_string())), result);
+}
+
+#[test]
+fn built_in_fn_binary_non_integer() {
+    let ast = src_to_ast(vec![
+        "_বাইনারি(১.৫);",
+    ]);
+    let result = run_assert_all_true(ast, MockIO::new());
+    assert_eq!(Err(PakhiErr::RuntimeError(1, "test.pakhi".to_string(),
+        "_বাইনারি() function's argument must be whole number".to_string())), result);
+}
+
+#[test]
+fn built_in_fn_string_len() {
+    let ast = src_to_ast(vec![
+        "দেখাও _স্ট্রিং-লেন(\"\");",
+        "দেখাও _স্ট্রিং-লেন(\"abc\");",
+        "দেখাও _স্ট্রিং-লেন(\"কখগ\");",
+        "_স্ট্রিং-লেন(১২);",
+    ]);
+    let mut mock_io: MockIO = MockIO::new();
+    mock_io.expect_println("০");
+    mock_io.expect_println("৩");
+    mock_io.expect_println("৩");
+    let result = run_assert_all_true(ast, mock_io);
+    assert_eq!(Err(PakhiErr::RuntimeError(4, "test.pakhi".to_string(),
+        "_স্ট্রিং-লেন() function's argument must be string".to_string())), result);
+}
+
+#[test]
+fn built_in_fn_string_sub() {
+    let ast = src_to_ast(vec![
+        "নাম ক = \"আমার সোনার বাংলা\";",
+        "দেখাও _স্ট্রিং-সাব(ক, ০, ৪);",
+        "দেখাও _স্ট্রিং-সাব(ক, ৫, ৫);",
+        "দেখাও _স্ট্রিং-সাব(ক, _স্ট্রিং-লেন(ক), ০) == \"\";",
+        "_স্ট্রিং-সাব(ক, ১০, ১০);",
+    ]);
+    let mut mock_io: MockIO = MockIO::new();
+    mock_io.expect_println("আমার");
+    mock_io.expect_println("সোনার");
+    mock_io.expect_println("সত্য");
+    let result = run_assert_all_true(ast, mock_io);
+    assert_eq!(Err(PakhiErr::RuntimeError(5, "test.pakhi".to_string(),
+        "_স্ট্রিং-সাব() start + length ২০ is larger than string length ১৬".to_string())), result);
+
+    let ast = src_to_ast(vec![
+        "_স্ট্রিং-সাব(\"আমি\", ১০০০০০০০০০০০০০০০০০০০০, ১০০০০০০০০০০০০০০০০০০০০);",
+    ]);
+    let result = run_assert_all_true(ast, MockIO::new());
+    assert_eq!(Err(PakhiErr::RuntimeError(1, "test.pakhi".to_string(),
+        "_স্ট্রিং-সাব() start + length ২০০০০০০০০০০০০০০০০০০০০ is larger than string length ৩".to_string())), result);
+}
+
+#[test]
+fn built_in_fn_terminal_color() {
+    std::env::remove_var("NO_COLOR");
+    let ast = src_to_ast(vec![
+        "দেখাও _টার্মিনাল-রঙ(\"সতর্কতা\", \"হলুদ\");",
+    ]);
+    let mut mock_io: MockIO = MockIO::new();
+    mock_io.expect_println("\x1b[33mসতর্কতা\x1b[0m");
+    if let Err(err) = run_assert_all_true(ast, mock_io) {
+        panic!("{:?}", err);
+    }
+}
+
+#[test]
+fn built_in_fn_clear_screen_move_cursor() {
+    let ast = src_to_ast(vec![
+        "_ক্লিয়ার-স্ক্রিন();",
+        "_কার্সর-অবস্থান(৩, ১০);",
+        "_দেখাও \"মেনু\";",
+        "_কার্সর-অবস্থান(০, ১);",
+    ]);
+    let mut mock_io: MockIO = MockIO::new();
+    mock_io.expect_print("\x1b[2J\x1b[H");
+    mock_io.expect_print("\x1b[3;10H");
+    mock_io.expect_print("মেনু");
+    let result = run_assert_all_true(ast, mock_io);
+    assert_eq!(Err(PakhiErr::RuntimeError(4, "test.pakhi".to_string(),
+        "_কার্সর-অবস্থান() row and column must be whole number starting from ১".to_string())), result);
+}
+
+#[test]
+fn built_in_fn_list_all_non_bool_predicate() {
+    let ast = src_to_ast(vec![
+        "ফাং দ্বিগুণ(ক) {",
+        "   ফেরত ক * ২;",
+        "} ফেরত;",
+        "_লিস্ট-সব([১, ২], দ্বিগুণ);",
+    ]);
+    let mock_io: MockIO = MockIO::new();
+    let result = run_assert_all_true(ast, mock_io);
+    assert_eq!(Err(PakhiErr::RuntimeError(4, "test.pakhi".to_string(),
+        "_লিস্ট-সব() predicate function must return bool".to_string())), result);
+}
+
+#[test]
+fn built_in_fn_memoize() {
+    let ast = src_to_ast(vec![
+        "নাম গণনা = ০;",
+        "ফাং ফিব(ন) {",
+        "   গণনা = গণনা + ১;",
+        "   যদি ন < ২ {",
+        "       ফেরত ন;",
+        "   }",
+        "   ফেরত মেমো-ফিব(ন - ১) + মেমো-ফিব(ন - ২);",
+        "} ফেরত;",
+        "নাম মেমো-ফিব = _মেমোআইজ(ফিব);",
+        "দেখাও মেমো-ফিব(২৫);",
+        // without memoization ফিব would be called 242785 times
+        "দেখাও গণনা;",
+        "দেখাও মেমো-ফিব(২০);",
+        "দেখাও গণনা;",
+    ]);
+    let mut mock_io: MockIO = MockIO::new();
+    mock_io.expect_println("৭৫০২৫");
+    mock_io.expect_println("২৬");
+    mock_io.expect_println("৬৭৬৫");
+    mock_io.expect_println("২৬");
+    if let Err(err) = run_assert_all_true(ast, mock_io) {
+        panic!("{:?}", err);
+    }
+}
+
+#[test]
+fn built_in_fn_memoize_structural_key() {
+    let ast = src_to_ast(vec![
+        "নাম গণনা = ০;",
+        "ফাং যোগফল(লিস্ট) {",
+        "   গণনা = গণনা + ১;",
+        "   ফেরত লিস্ট[০] + লিস্ট[১];",
+        "} ফেরত;",
+        "নাম মেমো-যোগফল = _মেমোআইজ(যোগফল);",
+        "দেখাও মেমো-যোগফল([১, ২]);",
+        "দেখাও মেমো-যোগফল([১, ২]);",
+        "দেখাও মেমো-যোগফল([২, ২]);",
+        "দেখাও গণনা;",
+    ]);
+    let mut mock_io: MockIO = MockIO::new();
+    mock_io.expect_println("৩");
+    mock_io.expect_println("৩");
+    mock_io.expect_println("৪");
+    mock_io.expect_println("২");
+    if let Err(err) = run_assert_all_true(ast, mock_io) {
+        panic!("{:?}", err);
+    }
+}
+
+#[test]
+fn host_fn_call() {
+    let ast = src_to_ast(vec![
+        "দেখাও যোগ(২, ৩);",
+        "যোগ(২, \"ক\");",
+    ]);
+    let mut mock_io: MockIO = MockIO::new();
+    mock_io.expect_println("৫");
+    let mut interpreter = Interpreter::new(ast, &mut mock_io);
+    interpreter.register_host_fn("যোগ", Box::new(|args| {
+        match args.as_slice() {
+            [DataType::Num(a), DataType::Num(b)] => Ok(DataType::Num(a + b)),
+            _ => Err("যোগ() expects two numbers".to_string()),
+        }
+    }));
+    let result = interpreter.run();
+    assert_eq!(Err(PakhiErr::RuntimeError(2, "test.pakhi".to_string(),
+        "যোগ() expects two numbers".to_string())), result);
+    mock_io.assert_all_true();
+}
+
+#[test]
+fn built_in_fn_list_sort_by_key() {
+    let ast = src_to_ast(vec![
+        "ফাং নাম-কী(র) {",
+        "   ফেরত র[\"নাম\"];",
+        "} ফেরত;",
+        "ফাং বয়স-কী(র) {",
+        "   ফেরত র[\"বয়স\"];",
+        "} ফেরত;",
+        "নাম মানুষ = [@{\"নাম\" -> \"গ\", \"বয়স\" -> ৩০}, @{\"নাম\" -> \"ক\", \"বয়স\" -> ২০},",
+        "             @{\"নাম\" -> \"খ\", \"বয়স\" -> ৩০}];",
+        "নাম সাজানো = _লিস্ট-সাজাও-কী(মানুষ, নাম-কী);",
+        "দেখাও সাজানো[০][\"নাম\"];",
+        "দেখাও সাজানো[১][\"নাম\"];",
+        "দেখাও সাজানো[২][\"নাম\"];",
+        // equal keys keep their order
+        "সাজানো = _লিস্ট-সাজাও-কী(মানুষ, বয়স-কী);",
+        "দেখাও সাজানো[০][\"নাম\"];",
+        "দেখাও সাজানো[১][\"নাম\"];",
+        "দেখাও সাজানো[২][\"নাম\"];",
+        "দেখাও মানুষ[০][\"নাম\"];",
+    ]);
+    let mut mock_io: MockIO = MockIO::new();
+    mock_io.expect_println("ক");
+    mock_io.expect_println("খ");
+    mock_io.expect_println("গ");
+    mock_io.expect_println("ক");
+    mock_io.expect_println("গ");
+    mock_io.expect_println("খ");
+    mock_io.expect_println("গ");
+    if let Err(err) = run_assert_all_true(ast, mock_io) {
+        panic!("{:?}", err);
+    }
+}
+
+#[test]
+fn built_in_fn_list_sort_by_key_mixed_keys() {
+    let ast = src_to_ast(vec![
+        "ফাং কী(ক) {",
+        "   ফেরত ক;",
+        "} ফেরত;",
+        "_লিস্ট-সাজাও-কী([১, \"ক\"], কী);",
+    ]);
+    let mock_io: MockIO = MockIO::new();
+    let result = run_assert_all_true(ast, mock_io);
+    assert_eq!(Err(PakhiErr::RuntimeError(4, "test.pakhi".to_string(),
+        "_লিস্ট-সাজাও-কী() keys must be all numbers or all strings".to_string())), result);
+}
+
+#[test]
+fn built_in_fn_try_num() {
+    let ast = src_to_ast(vec![
+        "নাম ফল = _ট্রাই-সংখ্যা(\"৪২.৫\");",
+        "দেখাও ফল[\"সফল\"];",
+        "দেখাও ফল[\"মান\"];",
+        "ফল = _ট্রাই-সংখ্যা(\"৪২ক\");",
+        "দেখাও ফল[\"সফল\"];",
+        "দেখাও _টাইপ(ফল[\"মান\"]);",
+    ]);
+    let mut mock_io: MockIO = MockIO::new();
+    mock_io.expect_println("সত্য");
+    mock_io.expect_println("৪২.৫");
+    mock_io.expect_println("মিথ্যা");
+    mock_io.expect_println("_শূন্য");
+    if let Err(err) = run_assert_all_true(ast, mock_io) {
+        panic!("{:?}", err);
+    }
+}
+
+#[test]
+fn return_expr_error_reported_at_return_line() {
+    let ast = src_to_ast(vec![
+        "ফাং গুণ(ক) {",
+        "   নাম খ = ক;",
+        "   ফেরত খ * \"গ\";",
+        "} ফেরত;",
+        "গুণ(২);",
+    ]);
+    let mock_io: MockIO = MockIO::new();
+    let result = run_assert_all_true(ast, mock_io);
+    assert_eq!(Err(PakhiErr::TypeError(3, "test.pakhi".to_string(),
+        "Type doesn't support operation".to_string())), result);
+}
+
+#[test]
+fn built_in_fn_list_get() {
+    let ast = src_to_ast(vec![
+        "নাম ক = [১০, ২০, ৩০];",
+        "দেখাও _লিস্ট-পাও(ক, ০);",
+        "দেখাও _লিস্ট-পাও(ক, ২);",
+        "দেখাও _টাইপ(_লিস্ট-পাও(ক, ৩));",
+        "দেখাও _টাইপ(_লিস্ট-পাও(ক, -১));",
+        "দেখাও _টাইপ(_লিস্ট-পাও([], ০));",
+    ]);
+    let mut mock_io: MockIO = MockIO::new();
+    mock_io.expect_println("১০");
+    mock_io.expect_println("৩০");
+    mock_io.expect_println("_শূন্য");
+    mock_io.expect_println("_শূন্য");
+    mock_io.expect_println("_শূন্য");
+    if let Err(err) = run_assert_all_true(ast, mock_io) {
+        panic!("{:?}", err);
+    }
+}
+
+#[test]
+fn built_in_fn_record_get() {
+    let ast = src_to_ast(vec![
+        "নাম ক = @{\"নাম\" -> \"সিফাত\"};",
+        "দেখাও _রেকর্ড-পাও(ক, \"নাম\", \"অজানা\");",
+        "দেখাও _রেকর্ড-পাও(ক, \"বয়স\", ০);",
+        "_রেকর্ড-পাও(ক, ১, ০);",
+    ]);
+    let mut mock_io: MockIO = MockIO::new();
+    mock_io.expect_println("সিফাত");
+    mock_io.expect_println("০");
+    let mut interpreter = Interpreter::new(ast, &mut mock_io);
+    let result = interpreter.run();
+    assert_eq!(Err(PakhiErr::RuntimeError(4, "test.pakhi".to_string(),
+        "_রেকর্ড-পাও() function's second argument must be string".to_string())), result);
+    mock_io.assert_all_true();
+}
+
+#[test]
+fn max_loop_iterations() {
+    let ast = src_to_ast(vec![
+        "নাম ই = ০;",
+        "লুপ {",
+        "   যদি ই == ৫ {",
+        "       থামাও;",
+        "   }",
+        "   ই = ই + ১;",
+        "} আবার;",
+        "দেখাও ই;",
+        "লুপ {",
+        "   দেখাও ই;",
+        "} আবার;",
+    ]);
+    let mut mock_io: MockIO = MockIO::new();
+    mock_io.expect_println("৫");
+    mock_io.expect_println("৫");
+    mock_io.expect_println("৫");
+    mock_io.expect_println("৫");
+    mock_io.expect_println("৫");
+    mock_io.expect_println("৫");
+    mock_io.expect_println("৫");
+    mock_io.expect_println("৫");
+    mock_io.expect_println("৫");
+    mock_io.expect_println("৫");
+    mock_io.expect_println("৫");
+    let mut interpreter = Interpreter::new(ast, &mut mock_io);
+    interpreter.set_max_loop_iterations(Some(10));
+    let result = interpreter.run();
+    assert_eq!(Err(PakhiErr::RuntimeError(9, "test.pakhi".to_string(),
+        "Loop exceeded maximum iteration count 10".to_string())), result);
+    mock_io.assert_all_true();
+}
+
+#[test]
+fn built_in_fn_string_reverse() {
+    let ast = src_to_ast(vec![
+        "দেখাও _স্ট্রিং-উল্টা(\"abc বাংলা\");",
+        "দেখাও _স্ট্রিং-উল্টা(\"\");",
+        "দেখাও _লিস্ট-লেন(_স্ট্রিং-স্প্লিট(_স্ট্রিং-উল্টা(\"কখগ\"), \"\"));",
+    ]);
+    let mut mock_io: MockIO = MockIO::new();
+    mock_io.expect_println("ালংাব cba");
+    mock_io.expect_println("");
+    mock_io.expect_println("৩");
+    if let Err(err) = run_assert_all_true(ast, mock_io) {
+        panic!("{:?}", err);
+    }
+}
+
+#[test]
+fn built_in_fn_string_count() {
+    let ast = src_to_ast(vec![
+        "দেখাও _স্ট্রিং-গণনা(\"আম জাম আম কাঁঠাল আম\", \"আম\");",
+        "দেখাও _স্ট্রিং-গণনা(\"আআআআ\", \"আআ\");",
+        "দেখাও _স্ট্রিং-গণনা(\"আম জাম\", \"লিচু\");",
+        "_স্ট্রিং-গণনা(\"আম\", \"\");",
+    ]);
+    let mut mock_io: MockIO = MockIO::new();
+    mock_io.expect_println("৩");
+    mock_io.expect_println("২");
+    mock_io.expect_println("০");
+    let mut interpreter = Interpreter::new(ast, &mut mock_io);
+    let result = interpreter.run();
+    assert_eq!(Err(PakhiErr::RuntimeError(4, "test.pakhi".to_string(),
+        "_স্ট্রিং-গণনা() function's second argument can't be empty string".to_string())), result);
+    mock_io.assert_all_true();
+}
+
+#[test]
+fn record_print_after_gc_reuses_slots() {
+    let ast = src_to_ast(vec![
+        "নাম রাখা = @{\"খ\" -> [১, ২], \"ক\" -> @{\"গ\" -> \"ঘ\"}};",
+        "নাম ই = ০;",
+        "লুপ {",
+        "   যদি ই == ২০ {",
+        "       থামাও;",
+        "   }",
+        "   নাম ফেলা = @{\"ক\" -> [ই], \"খ\" -> @{}};",
+        "   ই = ই + ১;",
+        "} আবার;",
+        "নাম নতুন = @{\"খ\" -> ২, \"ক\" -> ১};",
+        "নাম তথ্য = _মেমোরি-তথ্য();",
+        "দেখাও তথ্য[\"মুক্ত-তালিকা\"] > ০;",
+        "দেখাও রাখা;",
+        "দেখাও নতুন;",
+    ]);
+    let mut mock_io: MockIO = MockIO::new();
+    mock_io.expect_println("সত্য");
+    for piece in vec!["@{", "\"ক\":", "@{", "\"গ\":", "ঘ", ",", "}", ",", "\"খ\":", "[", "১", ", ", "২", "]", ","] {
+        mock_io.expect_print(piece);
+    }
+    mock_io.expect_println("}");
+    for piece in ["@{", "\"ক\":", "১", ",", "\"খ\":", "২", ","] {
+        mock_io.expect_print(piece);
+    }
+    mock_io.expect_println("}");
+    let mut interpreter = Interpreter::new(ast, &mut mock_io);
+    interpreter.set_gc_threshold(5);
+    if let Err(err) = interpreter.run() {
+        panic!("{:?}", err);
+    }
+    mock_io.assert_all_true();
+}
+
+#[test]
+fn gc_threshold_disables_collection() {
+    let ast = src_to_ast(vec![
+        "নাম ক = ০;",
+        "লুপ {",
+        "   নাম ফেলা = [ক, [ক]];",
+        "   ক = ক + ১;",
+        "   যদি ক == ১০০ {",
+        "       থামাও;",
+        "   }",
+        "} আবার;",
+        "নাম রাখা = [১, ২];",
+        "নাম তথ্য = _মেমোরি-তথ্য();",
+        "দেখাও তথ্য[\"মুক্ত-তালিকা\"];",
+    ]);
+    let mut mock_io: MockIO = MockIO::new();
+    // automatic collection is disabled, so no list was freed
+    mock_io.expect_println("০");
+    let mut interpreter = Interpreter::new(ast, &mut mock_io);
+    interpreter.set_gc_threshold(usize::MAX);
+    if let Err(err) = interpreter.run() {
+        panic!("{:?}", err);
+    }
+    mock_io.assert_all_true();
+}
+
+#[test]
+fn gc_inside_function_call() {
+    let ast = src_to_ast(vec![
+        "ফাং বানাও(বার) {",
+        "   নাম ই = ০;",
+        "   নাম রাখা = [];",
+        "   লুপ {",
+        "       যদি ই == বার {",
+        "           থামাও;",
+        "       }",
+        "       নাম ফেলা = [ই, @{\"মান\" -> [ই]}];",
+        "       _লিস্ট-পুশ(রাখা, ই);",
+        "       ই = ই + ১;",
+        "   } আবার;",
+        "   নাম তথ্য = _মেমোরি-তথ্য();",
+        "   ফেরত [রাখা, তথ্য[\"মুক্ত-তালিকা\"] > ০];",
+        "} ফেরত;",
+        "ফাং প্রথম(ক, খ) {",
+        "   ফেরত ক;",
+        "} ফেরত;",
+        // list literal is only a temporary while বানাও allocates, it must not be collected
+        "নাম ফল = প্রথম([১, ২, ৩], বানাও(৫০০));",
+        "দেখাও ফল;",
+        "নাম বানানো = বানাও(৫০০);",
+        "দেখাও _লিস্ট-লেন(বানানো[০]);",
+        "দেখাও বানানো[০][৪৯৯];",
+        "দেখাও বানানো[১];",
+    ]);
+    let mut mock_io: MockIO = MockIO::new();
+    for piece in ["[", "১", ", ", "২", ", ", "৩"] {
+        mock_io.expect_print(piece);
+    }
+    mock_io.expect_println("]");
+    mock_io.expect_println("৫০০");
+    mock_io.expect_println("৪৯৯");
+    mock_io.expect_println("সত্য");
+    let mut interpreter = Interpreter::new(ast, &mut mock_io);
+    interpreter.set_gc_threshold(50);
+    if let Err(err) = interpreter.run() {
+        panic!("{:?}", err);
+    }
+    mock_io.assert_all_true();
+}
+
+fn value_semantics_src() -> Vec<Stmt> {
+    src_to_ast(vec![
+        "নাম ক = [১, [২, ৩]];",
+        "নাম খ = ক;",
+        "খ[০] = ১০;",
+        "খ[১][০] = ২০;",
+        "দেখাও ক[০];",
+        "দেখাও ক[১][০];",
+        "নাম গ = @{\"চ\" -> ৪};",
+        "নাম ঘ = গ;",
+        "ঘ[\"চ\"] = ৪০;",
+        "দেখাও গ[\"চ\"];",
+    ])
+}
+
+#[test]
+fn reference_semantics_by_default() {
+    let mut mock_io: MockIO = MockIO::new();
+    mock_io.expect_println("১০");
+    mock_io.expect_println("২০");
+    mock_io.expect_println("৪০");
+    if let Err(err) = run_assert_all_true(value_semantics_src(), mock_io) {
+        panic!("{:?}", err);
+    }
+}
+
+#[test]
+fn value_semantics() {
+    let mut mock_io: MockIO = MockIO::new();
+    mock_io.expect_println("১");
+    mock_io.expect_println("২");
+    mock_io.expect_println("৪");
+    let mut interpreter = Interpreter::new(value_semantics_src(), &mut mock_io);
+    interpreter.set_value_semantics(true);
+    if let Err(err) = interpreter.run() {
+        panic!("{:?}", err);
+    }
+    mock_io.assert_all_true();
+}
+
+#[test]
+fn value_semantics_copies_cyclic_list() {
+    let ast = src_to_ast(vec![
+        "নাম ক = [১];",
+        "_লিস্ট-পুশ(ক, ক);",
+        "নাম খ = ক;",
+        "খ[১][০] = ১০;",
+        "দেখাও ক[০];",
+        "দেখাও খ[০];",
+    ]);
+    let mut mock_io: MockIO = MockIO::new();
+    mock_io.expect_println("১");
+    mock_io.expect_println("১০");
+    let mut interpreter = Interpreter::new(ast, &mut mock_io);
+    interpreter.set_value_semantics(true);
+    if let Err(err) = interpreter.run() {
+        panic!("{:?}", err);
+    }
+    mock_io.assert_all_true();
+}
+
+#[test]
+fn multi_dim_assign_out_of_range_intermediate() {
+    let ast = src_to_ast(vec![
+        "নাম ক = [[১, ২], [৩]];",
+        "ক[১][০] = ৩০;",
+        "দেখাও ক[১][০];",
+        "ক[৫][০] = ১;",
+    ]);
+    let mut mock_io: MockIO = MockIO::new();
+    mock_io.expect_println("৩০");
+    let mut interpreter = Interpreter::new(ast, &mut mock_io);
+    let result = interpreter.run();
+    assert_eq!(Err(PakhiErr::RuntimeError(4, "test.pakhi".to_string(),
+        "List index out of range at [৫]".to_string())), result);
+    mock_io.assert_all_true();
+}
+
+#[test]
+fn multi_dim_assign_out_of_range_last() {
+    let ast = src_to_ast(vec![
+        "নাম ক = [[১, ২], [৩]];",
+        "ক[১][৫] = ১;",
+    ]);
+    let mock_io: MockIO = MockIO::new();
+    let result = run_assert_all_true(ast, mock_io);
+    assert_eq!(Err(PakhiErr::RuntimeError(2, "test.pakhi".to_string(),
+        "List index out of range at [১][৫]".to_string())), result);
+}
+
+#[test]
+fn single_dim_assign_out_of_range() {
+    let ast = src_to_ast(vec![
+        "নাম ক = [১, ২, ৩];",
+        "ক[২] = ৩০;",
+        "দেখাও ক[২];",
+        "ক[১০] = ৫;",
+    ]);
+    let mut mock_io: MockIO = MockIO::new();
+    mock_io.expect_println("৩০");
+    let mut interpreter = Interpreter::new(ast, &mut mock_io);
+    let result = interpreter.run();
+    assert_eq!(Err(PakhiErr::RuntimeError(4, "test.pakhi".to_string(),
+        "List index out of range at [১০]".to_string())), result);
+    mock_io.assert_all_true();
+}
+
+#[test]
+fn single_dim_assign_negative_index() {
+    let ast = src_to_ast(vec![
+        "নাম ক = [১, ২, ৩];",
+        "ক[-১] = ৫;",
+    ]);
+    let mock_io: MockIO = MockIO::new();
+    let result = run_assert_all_true(ast, mock_io);
+    assert_eq!(Err(PakhiErr::RuntimeError(2, "test.pakhi".to_string(),
+        "List index must be a whole number at [-১]".to_string())), result);
+}
+
+#[test]
+fn multi_dim_assign_fractional_index() {
+    let ast = src_to_ast(vec![
+        "নাম ক = [[১, ২], [৩]];",
+        "ক[১][০.৫] = ৫;",
+    ]);
+    let mock_io: MockIO = MockIO::new();
+    let result = run_assert_all_true(ast, mock_io);
+    assert_eq!(Err(PakhiErr::RuntimeError(2, "test.pakhi".to_string(),
+        "List index must be a whole number at [১][০.৫]".to_string())), result);
+}
+
+#[test]
+fn multi_dim_assign_through_non_list() {
+    let ast = src_to_ast(vec![
+        "নাম ক = [১, [২]];",
+        "ক[০][০][১] = ১;",
+    ]);
+    let mock_io: MockIO = MockIO::new();
+    let result = run_assert_all_true(ast, mock_io);
+    assert_eq!(Err(PakhiErr::RuntimeError(2, "test.pakhi".to_string(),
+        "Only list and record datatype can be indexed at [০][০]".to_string())), result);
+}
+
+#[test]
+fn multi_dim_assign_missing_record_key() {
+    let ast = src_to_ast(vec![
+        "নাম ক = @{\"ক\" -> @{\"খ\" -> ১}};",
+        "ক[\"ক\"][\"গ\"] = ২;",
+        "দেখাও ক[\"ক\"][\"গ\"];",
+        "ক[\"চ\"][\"গ\"] = ২;",
+    ]);
+    let mut mock_io: MockIO = MockIO::new();
+    mock_io.expect_println("২");
+    let mut interpreter = Interpreter::new(ast, &mut mock_io);
+    let result = interpreter.run();
+    assert_eq!(Err(PakhiErr::RuntimeError(4, "test.pakhi".to_string(),
+        "Record key not found at [\"চ\"]".to_string())), result);
+    mock_io.assert_all_true();
+}
+
+#[test]
+fn built_in_fn_string_split_lines() {
+    let ast = src_to_ast(vec![
+        "নাম লাইন = _স্ট্রিং-স্প্লিট-লাইন(\"এক\r\nদুই\nতিন\r\n\");",
+        "দেখাও _লিস্ট-লেন(লাইন);",
+        "দেখাও লাইন[০] == \"এক\";",
+        "দেখাও লাইন[১] == \"দুই\";",
+        "দেখাও লাইন[২] == \"তিন\";",
+        "দেখাও লাইন[৩] == \"\";",
+    ]);
+    let mut mock_io: MockIO = MockIO::new();
+    mock_io.expect_println("৪");
+    mock_io.expect_println("সত্য");
+    mock_io.expect_println("সত্য");
+    mock_io.expect_println("সত্য");
+    mock_io.expect_println("সত্য");
+    if let Err(err) = run_assert_all_true(ast, mock_io) {
+        panic!("{:?}", err);
+    }
+}
+
+#[test]
+fn built_in_fn_record_size() {
+    let ast = src_to_ast(vec![
+        "নাম ক = @{};",
+        "দেখাও _রেকর্ড-আকার(ক);",
+        "ক[\"নাম\"] = \"সিফাত\";",
+        "ক[\"বয়স\"] = ৪২;",
+        "দেখাও _রেকর্ড-আকার(ক);",
+        "_রেকর্ড-আকার([১, ২]);",
+    ]);
+    let mut mock_io: MockIO = MockIO::new();
+    mock_io.expect_println("০");
+    mock_io.expect_println("২");
+    let mut interpreter = Interpreter::new(ast, &mut mock_io);
+    let result = interpreter.run();
+    assert_eq!(Err(PakhiErr::RuntimeError(6, "test.pakhi".to_string(),
+        "_রেকর্ড-আকার() function's argument must be record".to_string())), result);
+    mock_io.assert_all_true();
+}
+
+#[test]
+fn built_in_fn_freeze() {
+    let ast = src_to_ast(vec![
+        "নাম কনফিগ = _ফ্রিজ(@{\"পোর্ট\" -> ৮০, \"হোস্ট\" -> [\"ক\", \"খ\"]});",
+        "দেখাও কনফিগ[\"পোর্ট\"];",
+        "দেখাও কনফিগ[\"হোস্ট\"][১];",
+        "নাম হোস্ট = কনফিগ[\"হোস্ট\"];",
+        "_লিস্ট-পুশ(হোস্ট, \"গ\");",
+    ]);
+    let mut mock_io: MockIO = MockIO::new();
+    mock_io.expect_println("৮০");
+    mock_io.expect_println("খ");
+    let mut interpreter = Interpreter::new(ast, &mut mock_io);
+    let result = interpreter.run();
+    assert_eq!(Err(PakhiErr::RuntimeError(5, "test.pakhi".to_string(),
+        "Frozen list can't be modified".to_string())), result);
+    mock_io.assert_all_true();
+}
+
+#[test]
+fn frozen_record_index_assignment() {
+    let ast = src_to_ast(vec![
+        "নাম ক = @{\"খ\" -> @{\"গ\" -> ১}};",
+        "ক[\"খ\"][\"গ\"] = ২;",
+        "_ফ্রিজ(ক);",
+        "ক[\"খ\"][\"গ\"] = ৩;",
+    ]);
+    let mock_io: MockIO = MockIO::new();
+    let result = run_assert_all_true(ast, mock_io);
+    assert_eq!(Err(PakhiErr::RuntimeError(4, "test.pakhi".to_string(),
+        "Frozen record can't be modified".to_string())), result);
+}
+
+#[test]
+fn frozen_list_index_assignment() {
+    let ast = src_to_ast(vec![
+        "নাম ক = _ফ্রিজ([১, ২]);",
+        "ক[০] = ৩;",
+    ]);
+    let mock_io: MockIO = MockIO::new();
+    let result = run_assert_all_true(ast, mock_io);
+    assert_eq!(Err(PakhiErr::RuntimeError(2, "test.pakhi".to_string(),
+        "Frozen list can't be modified".to_string())), result);
+}
+
+#[test]
+fn built_in_fn_try_index() {
+    let ast = src_to_ast(vec![
+        "নাম তথ্য = @{\"মানুষ\" -> [@{\"নাম\" -> \"সিফাত\", \"ফোন\" -> [\"০১৭\"]}]};",
+        "দেখাও _ট্রাই-ইনডেক্স(তথ্য, [\"মানুষ\", ০, \"নাম\"]);",
+        "দেখাও _ট্রাই-ইনডেক্স(তথ্য, [\"মানুষ\", ০, \"ফোন\", ০]);",
+        "দেখাও _টাইপ(_ট্রাই-ইনডেক্স(তথ্য, [\"মানুষ\", ৫, \"নাম\"]));",
+        "দেখাও _টাইপ(_ট্রাই-ইনডেক্স(তথ্য, [\"মানুষ\", \"নাম\"]));",
+        "দেখাও _টাইপ(_ট্রাই-ইনডেক্স(তথ্য, [\"মানুষ\", ০, \"নাম\", ০]));",
+        "দেখাও _ট্রাই-ইনডেক্স(৫, []);",
+    ]);
+    let mut mock_io: MockIO = MockIO::new();
+    mock_io.expect_println("সিফাত");
+    mock_io.expect_println("০১৭");
+    mock_io.expect_println("_শূন্য");
+    mock_io.expect_println("_শূন্য");
+    mock_io.expect_println("_শূন্য");
+    mock_io.expect_println("৫");
+    if let Err(err) = run_assert_all_true(ast, mock_io) {
+        panic!("{:?}", err);
+    }
+}
+
+#[test]
+fn built_in_fn_default() {
+    let ast = src_to_ast(vec![
+        "নাম র = @{\"ক\" -> ১};",
+        "নাম শূন্য = _ট্রাই-ইনডেক্স(র, [\"খ\"]);",
+        "দেখাও _ডিফল্ট(শূন্য, \"বিকল্প\");",
+        "দেখাও _ডিফল্ট(০, ১০);",
+        "দেখাও _ডিফল্ট(মিথ্যা, সত্য);",
+        "দেখাও _ডিফল্ট(র[\"ক\"], ৫);",
+        "দেখাও _টাইপ(_ডিফল্ট(শূন্য, শূন্য));",
+    ]);
+    let mut mock_io: MockIO = MockIO::new();
+    for expected in ["বিকল্প", "০", "মিথ্যা", "১", "_শূন্য"] {
+        mock_io.expect_println(expected);
+    }
+    if let Err(err) = run_assert_all_true(ast, mock_io) {
+        panic!("{:?}", err);
+    }
+}
+
+#[test]
+fn reassign_undeclared_var() {
+    let ast = src_to_ast(vec![
+        "নাম ক = ১;",
+        "যদি সত্য {",
+        "   খ = ৫;",
+        "}",
+    ]);
+    let mock_io: MockIO = MockIO::new();
+    let result = run_assert_all_true(ast, mock_io);
+    assert_eq!(Err(PakhiErr::RuntimeError(3, "test.pakhi".to_string(),
+        "Variable wasn't declared খ".to_string())), result);
+}
+
+#[test]
+fn built_in_fn_read_all_lines() {
+    let ast = src_to_ast(vec![
+        "নাম লাইন = _রিড-লাইন-সব();",
+        "দেখাও _লিস্ট-লেন(লাইন);",
+        "দেখাও লাইন[০];",
+        "দেখাও লাইন[১];",
+        "দেখাও লাইন[২];",
+    ]);
+    let mut mock_io: MockIO = MockIO::new();
+    mock_io.push_input_line("১ ২");
+    mock_io.push_input_line("  তিন  ");
+    mock_io.push_input_line("শেষ");
+    mock_io.expect_println("৩");
+    mock_io.expect_println("১ ২");
+    mock_io.expect_println("তিন");
+    mock_io.expect_println("শেষ");
+    if let Err(err) = run_assert_all_true(ast, mock_io) {
+        panic!("{:?}", err);
+    }
+}
+
+#[test]
+fn built_in_fn_list_preallocate() {
+    let ast = src_to_ast(vec![
+        "নাম ক = _লিস্ট-প্রিঅ্যালোকেট(১০০০);",
+        "দেখাও _লিস্ট-লেন(ক);",
+        "নাম ই = ০;",
+        "লুপ {",
+        "   যদি ই == ২০০০ {",
+        "       থামাও;",
+        "   }",
+        "   _লিস্ট-পুশ(ক, ই * ২);",
+        "   ই = ই + ১;",
+        "} আবার;",
+        "দেখাও _লিস্ট-লেন(ক);",
+        "দেখাও ক[০];",
+        "দেখাও ক[১৯৯৯];",
+        "_লিস্ট-প্রিঅ্যালোকেট(-১);",
+    ]);
+    let mut mock_io: MockIO = MockIO::new();
+    mock_io.expect_println("০");
+    mock_io.expect_println("২০০০");
+    mock_io.expect_println("০");
+    mock_io.expect_println("৩৯৯৮");
+    let mut interpreter = Interpreter::new(ast, &mut mock_io);
+    let result = interpreter.run();
+    assert_eq!(Err(PakhiErr::RuntimeError(14, "test.pakhi".to_string(),
+        "_লিস্ট-প্রিঅ্যালোকেট() size must be a non-negative whole number".to_string())), result);
+    mock_io.assert_all_true();
+}
+
+#[test]
+fn built_in_fn_inspect() {
+    let ast = src_to_ast(vec![
+        "নাম ক = [১, ২, ৩];",
+        "নাম খ = ক;",
+        "নাম গ = [১, ২, ৩];",
+        "দেখাও _ইনস্পেক্ট(ক) == _ইনস্পেক্ট(খ);",
+        "দেখাও _ইনস্পেক্ট(ক) == _ইনস্পেক্ট(গ);",
+        "দেখাও _ইনস্পেক্ট(৫);",
+    ]);
+    let mut mock_io: MockIO = MockIO::new();
+    mock_io.expect_println("সত্য");
+    mock_io.expect_println("মিথ্যা");
+    mock_io.expect_println("-১");
+    if let Err(err) = run_assert_all_true(ast, mock_io) {
+        panic!("{:?}", err);
+    }
+}
+
+#[test]
+fn built_in_fn_list_max_min_by_key() {
+    let ast = src_to_ast(vec![
+        "ফাং দৈর্ঘ্য(স) {",
+        "   ফেরত _লিস্ট-লেন(_স্ট্রিং-স্প্লিট(স, \"\"));",
+        "} ফেরত;",
+        "নাম শব্দ = [\"আম\", \"কাঁঠাল\", \"লিচু\", \"তরমুজ\", \"জাম\"];",
+        "দেখাও _লিস্ট-ম্যাক্স-দিয়ে(শব্দ, দৈর্ঘ্য);",
+        "দেখাও _লিস্ট-মিন-দিয়ে(শব্দ, দৈর্ঘ্য);",
+        "_লিস্ট-ম্যাক্স-দিয়ে([], দৈর্ঘ্য);",
+    ]);
+    let mut mock_io: MockIO = MockIO::new();
+    mock_io.expect_println("কাঁঠাল");
+    mock_io.expect_println("আম");
+    let mut interpreter = Interpreter::new(ast, &mut mock_io);
+    let result = interpreter.run();
+    assert_eq!(Err(PakhiErr::RuntimeError(7, "test.pakhi".to_string(),
+        "_লিস্ট-ম্যাক্স-দিয়ে() list is empty".to_string())), result);
+    mock_io.assert_all_true();
+}
@@ -1,4 +1,5 @@
 use pakhi::frontend::lexer::{tokenize, TokenKind};
+use pakhi::common::pakhi_error::PakhiErr;
 
 #[test]
 fn lexer_var_declare() {
@@ -38,4 +39,61 @@ fn lexer_comment_block() {
     assert_eq!(TokenKind::Num(0.0), tokens[4].kind);
     assert_eq!(TokenKind::Semicolon, tokens[5].kind);
     assert_eq!(TokenKind::Comment, tokens[6].kind);
-}
\ No newline at end of file
+}
+
+#[test]
+fn lexer_compound_assignment() {
+    let tokens = tokenize("ক += ১; ক -= ১; ক *= ১; ক /= ১; ক - ১; ক -> ১;".chars().collect::<Vec<char>>(),
+                          "test.pakhi".to_string()).unwrap();
+    assert_eq!(TokenKind::PlusEqual, tokens[1].kind);
+    assert_eq!(TokenKind::MinusEqual, tokens[5].kind);
+    assert_eq!(TokenKind::MultiplyEqual, tokens[9].kind);
+    assert_eq!(TokenKind::DivisionEqual, tokens[13].kind);
+    assert_eq!(TokenKind::Minus, tokens[17].kind);
+    assert_eq!(TokenKind::Map, tokens[21].kind);
+}
+
+#[test]
+fn lexer_interpolated_string() {
+    let tokens = tokenize(
+        r#""ক ${খ + ১} গ""#.chars().collect::<Vec<char>>(),
+        "test.pakhi".to_string()).unwrap();
+    assert_eq!(TokenKind::InterpolatedStringStart, tokens[0].kind);
+    assert_eq!(TokenKind::String(String::from("ক ")), tokens[1].kind);
+    assert_eq!(TokenKind::InterpolationStart, tokens[2].kind);
+    assert_eq!(TokenKind::Identifier, tokens[3].kind);
+    assert_eq!(TokenKind::Plus, tokens[4].kind);
+    assert_eq!(TokenKind::Num(1.0), tokens[5].kind);
+    assert_eq!(TokenKind::InterpolationEnd, tokens[6].kind);
+    assert_eq!(TokenKind::String(String::from(" গ")), tokens[7].kind);
+    assert_eq!(TokenKind::InterpolatedStringEnd, tokens[8].kind);
+}
+
+#[test]
+fn lexer_interpolated_string_escape() {
+    let tokens = tokenize(
+        r#""\"${খ}\"\n""#.chars().collect::<Vec<char>>(),
+        "test.pakhi".to_string()).unwrap();
+    assert_eq!(TokenKind::InterpolatedStringStart, tokens[0].kind);
+    assert_eq!(TokenKind::String(String::from("\"")), tokens[1].kind);
+    assert_eq!(TokenKind::InterpolationStart, tokens[2].kind);
+    assert_eq!(TokenKind::Identifier, tokens[3].kind);
+    assert_eq!(TokenKind::InterpolationEnd, tokens[4].kind);
+    assert_eq!(TokenKind::String(String::from("\"\n")), tokens[5].kind);
+    assert_eq!(TokenKind::InterpolatedStringEnd, tokens[6].kind);
+}
+
+#[test]
+fn lexer_unterminated_string() {
+    let result = tokenize("নাম ক = ১;\nদেখাও \"ক;".chars().collect::<Vec<char>>(), "test.pakhi".to_string());
+    assert_eq!(Err(PakhiErr::SyntaxError(2, "test.pakhi".to_string(), "Expected '\"' at the end of string".to_string())), result);
+
+    let result = tokenize("দেখাও \"ক ${খ}".chars().collect::<Vec<char>>(), "test.pakhi".to_string());
+    assert_eq!(Err(PakhiErr::SyntaxError(1, "test.pakhi".to_string(), "Expected '\"' at the end of string".to_string())), result);
+}
+
+#[test]
+fn lexer_unexpected_character() {
+    let result = tokenize("নাম ক = ১ \\ ২;".chars().collect::<Vec<char>>(), "test.pakhi".to_string());
+    assert_eq!(Err(PakhiErr::SyntaxError(1, "test.pakhi".to_string(), "Unexpected character '\\'".to_string())), result);
+}
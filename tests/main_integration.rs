@@ -1,4 +1,5 @@
-use pakhi::common::io::{MockIO, IO};
+use pakhi::common::io::{MockIO, RealIO, IO};
+use pakhi::common::pakhi_error::PakhiErr;
 use std::io::Write;
 use std::sync::{Arc, PoisonError};
 use std::sync::Mutex;
@@ -103,6 +104,40 @@ fn built_in_fn_write_file() {
     run_module("test.pakhi", mock_io);
 }
 
+#[test]
+fn built_in_fn_file_bytes_round_trip() {
+    let _m = MUTEX.lock().unwrap_or_else(PoisonError::into_inner);
+    create_file("test.pakhi", vec![
+        "নাম বাইট = _রিড-ফাইল-বাইট(_ডাইরেক্টরি + \"./test.bin\");",
+        "_রাইট-ফাইল-বাইট(_ডাইরেক্টরি + \"./copy.bin\", বাইট);",
+        "দেখাও _রিড-ফাইল-বাইট(_ডাইরেক্টরি + \"./copy.bin\");",
+    ]);
+    // not valid utf-8, would be corrupted by reading as text
+    std::fs::write(std::env::current_dir().unwrap().join("__tmp").join("test.bin"), [0u8, 255, 128, 10, 200]).unwrap();
+
+    let mut mock_io: MockIO = MockIO::new();
+    for piece in vec!["[", "০", ", ", "২৫৫", ", ", "১২৮", ", ", "১০", ", ", "২০০"] {
+        mock_io.expect_print(piece);
+    }
+    mock_io.expect_println("]");
+    run_module("test.pakhi", mock_io);
+}
+
+#[test]
+fn built_in_fn_write_file_bytes_out_of_range() {
+    let _m = MUTEX.lock().unwrap_or_else(PoisonError::into_inner);
+    create_file("test.pakhi", vec![
+        "_রাইট-ফাইল-বাইট(_ডাইরেক্টরি + \"./test.bin\", [১, ২৫৬]);",
+    ]);
+
+    let root_path = std::env::current_dir().unwrap().join("__tmp");
+    let module_path = root_path.join("test.pakhi").to_str().unwrap().to_string();
+    let result = pakhi::start_pakhi(module_path.clone(), &mut MockIO::new());
+    clean_test_tmp_dir();
+    assert_eq!(Err(PakhiErr::RuntimeError(1, module_path,
+        "_রাইট-ফাইল-বাইট() every byte must be a whole number from ০ to ২৫৫".to_string())), result);
+}
+
 #[test]
 fn built_in_fn_delete_file() {
     let _m = MUTEX.lock().unwrap_or_else(PoisonError::into_inner);
@@ -113,7 +148,8 @@ fn built_in_fn_delete_file() {
         "দেখাও _ডিলিট-ফাইল(_ডাইরেক্টরি + \"./test.txt\");",
     ]);
 
-    let mock_io: MockIO = MockIO::new();
+    let mut mock_io: MockIO = MockIO::new();
+    mock_io.expect_println("সত্য");
     run_module("test.pakhi", mock_io);
     assert!(!std::path::Path::new("./tmp/test.txt").exists());
 }
@@ -125,11 +161,82 @@ fn built_in_fn_read_dir() {
     create_file("test.txt", vec!["test passed"]);
     create_file("test.pakhi", vec![
         "নাম ডার = _রিড-ডাইরেক্টরি(_ডাইরেক্টরি + \"./\");",
-        "দেখাও ডার[০];"
+        "দেখাও _লিস্ট-লেন(ডার);",
+        // order of directory entries depends on file system
+        "দেখাও ডার[০] == \"test.txt\" | ডার[১] == \"test.txt\";"
+    ]);
+
+    let mut mock_io: MockIO = MockIO::new();
+    mock_io.expect_println("২");
+    mock_io.expect_println("সত্য");
+    run_module("test.pakhi", mock_io);
+}
+
+#[test]
+fn built_in_fn_walk_dir() {
+    let _m = MUTEX.lock().unwrap_or_else(PoisonError::into_inner);
+    create_file("test.pakhi", vec![
+        "নাম ফাইল = _ওয়াক-ডাইরেক্টরি(_ডাইরেক্টরি + \"./\");",
+        "দেখাও _লিস্ট-লেন(ফাইল);",
+        "দেখাও ফাইল[০];",
+        "দেখাও ফাইল[১];",
+        "দেখাও ফাইল[২];",
+        "দেখাও ফাইল[৩];",
+    ]);
+    create_file("top.txt", vec!["top"]);
+    let tmp_dir = std::env::current_dir().unwrap().join("__tmp");
+    std::fs::create_dir_all(tmp_dir.join("a/b")).unwrap();
+    std::fs::write(tmp_dir.join("a/one.txt"), "one").unwrap();
+    std::fs::write(tmp_dir.join("a/b/two.txt"), "two").unwrap();
+    // symlink pointing to parent directory must not make walking loop forever
+    #[cfg(unix)]
+    std::os::unix::fs::symlink(tmp_dir.join("a"), tmp_dir.join("a/b/loop")).unwrap();
+
+    let mut mock_io: MockIO = MockIO::new();
+    mock_io.expect_println("৪");
+    mock_io.expect_println("a/b/two.txt");
+    mock_io.expect_println("a/one.txt");
+    mock_io.expect_println("test.pakhi");
+    mock_io.expect_println("top.txt");
+    run_module("test.pakhi", mock_io);
+}
+
+#[test]
+fn built_in_fn_temp_file() {
+    let _m = MUTEX.lock().unwrap_or_else(PoisonError::into_inner);
+    create_file("test.pakhi", vec![
+        "নাম ক = _টেম্প-ফাইল();",
+        "নাম খ = _টেম্প-ফাইল(সত্য);",
+        "দেখাও ক == খ;",
+        "দেখাও _রিড-ফাইল(খ);",
+        "_রাইট-ফাইল(খ, \"scratch\");",
+        "দেখাও _রিড-ফাইল(খ);",
+        "_ডিলিট-ফাইল(খ);",
+    ]);
+
+    let mut mock_io: MockIO = MockIO::new();
+    mock_io.expect_println("মিথ্যা");
+    mock_io.expect_println("");
+    mock_io.expect_println("scratch");
+    run_module("test.pakhi", mock_io);
+}
+
+#[test]
+fn built_in_fn_change_dir() {
+    let _m = MUTEX.lock().unwrap_or_else(PoisonError::into_inner);
+    create_file("test.pakhi", vec![
+        "দেখাও _ডাইরেক্টরি-বদল(_ডাইরেক্টরি + \"./sub\");",
+        "দেখাও _রিড-ফাইল(\"test.txt\");",
+        // going back, so that test can clean tmp directory
+        "_ডাইরেক্টরি-বদল(_ডাইরেক্টরি + \"..\");",
     ]);
+    let tmp_dir = std::env::current_dir().unwrap().join("__tmp");
+    std::fs::create_dir_all(tmp_dir.join("sub")).unwrap();
+    std::fs::write(tmp_dir.join("sub/test.txt"), "inside sub").unwrap();
 
     let mut mock_io: MockIO = MockIO::new();
-    mock_io.expect_println("test.txt");
+    mock_io.expect_println("সত্য");
+    mock_io.expect_println("inside sub");
     run_module("test.pakhi", mock_io);
 }
 
@@ -144,7 +251,9 @@ fn built_in_fn_create_dir() {
     ]);
 
     let mut mock_io: MockIO = MockIO::new();
-    mock_io.expect_println("[test.txt]");
+    mock_io.expect_print("[");
+    mock_io.expect_print("test.txt");
+    mock_io.expect_println("]");
     run_module("test.pakhi", mock_io);
 }
 
@@ -182,4 +291,227 @@ fn built_in_fn_file_or_dir() {
     mock_io.expect_println("ফাইল");
     mock_io.expect_println("ডাইরেক্টরি");
     run_module("test.pakhi", mock_io);
-}
\ No newline at end of file
+}
+#[test]
+fn built_in_fn_json_parse_object() {
+    let _m = MUTEX.lock().unwrap_or_else(PoisonError::into_inner);
+    create_file("data.json", vec![
+        r#"{"নাম": "পাখি", "তথ্য": {"সংস্করণ": 10, "স্থিতিশীল": false}}"#,
+    ]);
+    create_file("test.pakhi", vec![
+        "নাম ক = _জেসন-পার্স(_রিড-ফাইল(_ডাইরেক্টরি + \"./data.json\"));",
+        "দেখাও ক[\"নাম\"];",
+        "দেখাও ক[\"তথ্য\"][\"সংস্করণ\"];",
+        "দেখাও ক[\"তথ্য\"][\"স্থিতিশীল\"];",
+    ]);
+
+    let mut mock_io: MockIO = MockIO::new();
+    mock_io.expect_println("পাখি");
+    mock_io.expect_println("১০");
+    mock_io.expect_println("মিথ্যা");
+    run_module("test.pakhi", mock_io);
+}
+
+#[test]
+fn built_in_fn_json_parse_safe_object() {
+    let _m = MUTEX.lock().unwrap_or_else(PoisonError::into_inner);
+    create_file("valid.json", vec![r#"{"মান": [1, 2]}"#]);
+    create_file("invalid.json", vec![r#"{"মান": [1, 2}"#]);
+    create_file("test.pakhi", vec![
+        "নাম ক = _জেসন-পার্স-নিরাপদ(_রিড-ফাইল(_ডাইরেক্টরি + \"./valid.json\"));",
+        "দেখাও ক[\"মান\"][১];",
+        "নাম খ = _জেসন-পার্স-নিরাপদ(_রিড-ফাইল(_ডাইরেক্টরি + \"./invalid.json\"));",
+        "দেখাও _টাইপ(খ);",
+    ]);
+
+    let mut mock_io: MockIO = MockIO::new();
+    mock_io.expect_println("২");
+    mock_io.expect_println("_শূন্য");
+    run_module("test.pakhi", mock_io);
+}
+
+#[test]
+fn built_in_fn_file_handle_read_lines() {
+    let _m = MUTEX.lock().unwrap_or_else(PoisonError::into_inner);
+    create_file("test.txt", vec![
+        "প্রথম",
+        "দ্বিতীয়",
+        "তৃতীয়",
+    ]);
+    create_file("test.pakhi", vec![
+        "নাম ফাইল = _ফাইল-খোলো(_ডাইরেক্টরি + \"./test.txt\", \"পড়া\");",
+        "নাম লাইনগুলো = [];",
+        "লুপ {",
+        "   নাম লাইন = _ফাইল-লাইন-পড়ো(ফাইল);",
+        "   যদি _টাইপ(লাইন) == \"_শূন্য\" {",
+        "       থামাও;",
+        "   }",
+        "   _লিস্ট-পুশ(লাইনগুলো, লাইন);",
+        "} আবার;",
+        "_ফাইল-বন্ধ(ফাইল);",
+        "যদি _লিস্ট-লেন(লাইনগুলো) != ৩ {",
+        "   _এরর(\"expected three lines\");",
+        "}",
+        "যদি লাইনগুলো[২] != \"তৃতীয়\" {",
+        "   _এরর(\"unexpected last line\");",
+        "}",
+        "দেখাও লাইনগুলো[০];",
+    ]);
+
+    let mut mock_io: MockIO = MockIO::new();
+    mock_io.expect_println("প্রথম");
+    run_module("test.pakhi", mock_io);
+}
+
+#[test]
+#[should_panic(expected="file was already closed")]
+fn built_in_fn_file_handle_read_after_close() {
+    let _m = MUTEX.lock().unwrap_or_else(PoisonError::into_inner);
+    create_file("test.txt", vec![
+        "প্রথম",
+    ]);
+    create_file("test.pakhi", vec![
+        "নাম ফাইল = _ফাইল-খোলো(_ডাইরেক্টরি + \"./test.txt\", \"পড়া\");",
+        "_ফাইল-বন্ধ(ফাইল);",
+        "_ফাইল-লাইন-পড়ো(ফাইল);",
+    ]);
+
+    let thread = std::thread::spawn(|| {
+        let mock_io: MockIO = MockIO::new();
+        run_module("test.pakhi", mock_io);
+    });
+    if let Err(e) = thread.join() {
+        clean_test_tmp_dir();
+        std::panic::resume_unwind(e);
+    }
+}
+
+#[test]
+#[should_panic(expected="refusing to delete empty path")]
+fn built_in_fn_delete_dir_empty_path() {
+    let _m = MUTEX.lock().unwrap_or_else(PoisonError::into_inner);
+    create_file("test.pakhi", vec![
+        "_ডিলিট-ডাইরেক্টরি(\"\");",
+    ]);
+
+    let thread = std::thread::spawn(|| {
+        let mock_io: MockIO = MockIO::new();
+        run_module("test.pakhi", mock_io);
+    });
+    if let Err(e) = thread.join() {
+        clean_test_tmp_dir();
+        std::panic::resume_unwind(e);
+    }
+}
+
+#[test]
+fn built_in_fn_delete_empty_dir() {
+    let _m = MUTEX.lock().unwrap_or_else(PoisonError::into_inner);
+    create_file("test.pakhi", vec![
+        "_নতুন-ডাইরেক্টরি(_ডাইরেক্টরি + \"./test\");",
+        "_ডিলিট-ডাইরেক্টরি-খালি(_ডাইরেক্টরি + \"./test\");",
+        "দেখাও _লিস্ট-লেন(_রিড-ডাইরেক্টরি(_ডাইরেক্টরি));",
+    ]);
+
+    let mut mock_io: MockIO = MockIO::new();
+    mock_io.expect_println("১");
+    run_module("test.pakhi", mock_io);
+}
+
+#[test]
+#[should_panic]
+fn built_in_fn_delete_empty_dir_not_empty() {
+    let _m = MUTEX.lock().unwrap_or_else(PoisonError::into_inner);
+    create_file("test.pakhi", vec![
+        "_নতুন-ডাইরেক্টরি(_ডাইরেক্টরি + \"./test\");",
+        "_রাইট-ফাইল(_ডাইরেক্টরি + \"./test/test.txt\", \"test\");",
+        "_ডিলিট-ডাইরেক্টরি-খালি(_ডাইরেক্টরি + \"./test\");",
+    ]);
+
+    let thread = std::thread::spawn(|| {
+        let mock_io: MockIO = MockIO::new();
+        run_module("test.pakhi", mock_io);
+    });
+    if thread.join().is_err() {
+        clean_test_tmp_dir();
+        panic!()
+    }
+}
+
+#[test]
+fn built_in_fn_read_dir_detailed() {
+    let _m = MUTEX.lock().unwrap_or_else(PoisonError::into_inner);
+    create_file("test.pakhi", vec![
+        "_নতুন-ডাইরেক্টরি(_ডাইরেক্টরি + \"./test/sub\");",
+        "_রাইট-ফাইল(_ডাইরেক্টরি + \"./test/test.txt\", \"12345\");",
+        "নাম সব = _রিড-ডাইরেক্টরি-বিস্তারিত(_ডাইরেক্টরি + \"./test\");",
+        "যদি _লিস্ট-লেন(সব) != ২ {",
+        "   _এরর(\"expected two entries\");",
+        "}",
+        "নাম ফাইল = সব[০];",
+        "নাম ডাইরেক্টরি = সব[১];",
+        "যদি ফাইল[\"ধরন\"] != \"ফাইল\" {",
+        "   ফাইল = সব[১];",
+        "   ডাইরেক্টরি = সব[০];",
+        "}",
+        "যদি ফাইল[\"নাম\"] != \"test.txt\" | ফাইল[\"সাইজ\"] != ৫ {",
+        "   _এরর(\"unexpected file entry\");",
+        "}",
+        "যদি ডাইরেক্টরি[\"নাম\"] != \"sub\" | ডাইরেক্টরি[\"ধরন\"] != \"ডাইরেক্টরি\" {",
+        "   _এরর(\"unexpected directory entry\");",
+        "}",
+    ]);
+
+    let thread = std::thread::spawn(|| {
+        let mock_io: MockIO = MockIO::new();
+        run_module("test.pakhi", mock_io);
+    });
+    if let Err(e) = thread.join() {
+        clean_test_tmp_dir();
+        std::panic::resume_unwind(e);
+    }
+}
+
+#[test]
+fn strict_option_rejects_redeclaration() {
+    let _m = MUTEX.lock().unwrap_or_else(PoisonError::into_inner);
+    create_file("test.pakhi", vec![
+        "নাম ক = ১;",
+        "নাম ক = ২;",
+    ]);
+    let module_path = std::env::current_dir().unwrap().join("__tmp").join("test.pakhi");
+    let module_path = module_path.to_str().unwrap().to_string();
+
+    let mut mock_io: MockIO = MockIO::new();
+    let lenient_result = pakhi::start_pakhi(module_path.clone(), &mut mock_io);
+    let strict_result = pakhi::start_pakhi_with_options(module_path.clone(), &mut mock_io,
+                                                        &pakhi::Options { strict: true });
+    clean_test_tmp_dir();
+
+    assert_eq!(Ok(()), lenient_result);
+    assert_eq!(Err(PakhiErr::RuntimeError(2, module_path,
+        "Variable was already declared in this scope ক".to_string())), strict_result);
+}
+
+#[test]
+fn real_io_panic_handler() {
+    let _m = MUTEX.lock().unwrap_or_else(PoisonError::into_inner);
+    create_file("test.pakhi", vec![
+        "নাম ক = ১ / ০;",
+    ]);
+    let module_path = std::env::current_dir().unwrap().join("__tmp").join("test.pakhi");
+    let module_path = module_path.to_str().unwrap().to_string();
+
+    let received: std::rc::Rc<std::cell::RefCell<Vec<PakhiErr>>> = Default::default();
+    let handler_received = received.clone();
+    let mut real_io = RealIO::with_panic_handler(Box::new(move |err| handler_received.borrow_mut().push(err)));
+    let result = pakhi::start_pakhi_with_options(module_path.clone(), &mut real_io, &pakhi::Options::default());
+    clean_test_tmp_dir();
+    let expected = PakhiErr::RuntimeError(1, module_path, "Division by zero".to_string());
+    assert_eq!(Err(expected.clone()), result);
+    // reported same way as main does, reaching here means process didn't exit
+    if let Err(err) = result {
+        real_io.panic(err);
+    }
+    assert_eq!(vec![expected], *received.borrow());
+}
@@ -1,6 +1,7 @@
-use pakhi::frontend::{lexer, parser};
-use pakhi::frontend::parser::{Stmt, Primary, Expr, Binary, Unary, Assignment, AssignmentKind, And, Or, parse};
+use pakhi::frontend::{lexer, parser, warning};
+use pakhi::frontend::parser::{Stmt, Primary, Expr, Binary, Unary, Assignment, AssignmentKind, And, Or, ForEach, parse};
 use pakhi::frontend::lexer::{TokenKind, Token};
+use pakhi::common::pakhi_error::{PakhiErr, PakhiWarning};
 use pakhi::frontend::parser::AssignmentKind::FirstAssignment;
 use pakhi::frontend::lexer::TokenKind::{Identifier, Plus};
 use pakhi::frontend::parser::Primary::{NamelessRecord, Num};
@@ -340,4 +341,185 @@ fn parse_test_namesless_record_literal() {
         },
         Err(e) => panic!("err: {:?}", e),
     }
-}
\ No newline at end of file
+}
+#[test]
+fn parse_test_list_literal_missing_comma() {
+    let tokens = lexer::tokenize("দেখাও [১ ২];".chars().collect(),
+                                 "test.pakhi".to_string()).unwrap();
+    let parse_result = parse(String::from("test.pakhi"), tokens);
+    assert_eq!(Err(PakhiErr::SyntaxError(1, "test.pakhi".to_string(),
+                                         "Expected ',' or ']' after list element".to_string())), parse_result);
+}
+
+#[test]
+fn parse_test_range_outside_slice_assignment() {
+    for src in ["নাম ক = [১..৩];", "দেখাও [১, ২..৩];", "ক[১][০..২] = [১];", "ক[০..২] += [১];"] {
+        let tokens = lexer::tokenize(src.chars().collect(), "test.pakhi".to_string()).unwrap();
+        let parse_result = parse(String::from("test.pakhi"), tokens);
+        match parse_result {
+            Err(PakhiErr::SyntaxError(1, _, _)) => {},
+            _ => panic!("{} must be a syntax error, got {:?}", src, parse_result),
+        }
+    }
+}
+
+#[test]
+fn parse_test_list_literal_trailing_comma() {
+    let tokens = lexer::tokenize("দেখাও [১, ২,];".chars().collect(),
+                                 "test.pakhi".to_string()).unwrap();
+    let parse_result = parse(String::from("test.pakhi"), tokens);
+    match parse_result {
+        Ok(ast) => {
+            let expected_ast = Stmt::Print(Expr::Primary(Primary::List(vec![
+                Expr::Primary(Primary::Num(1.0), 1, "test.pakhi".to_string()),
+                Expr::Primary(Primary::Num(2.0), 1, "test.pakhi".to_string()),
+            ]), 1, "test.pakhi".to_string()), 1, "test.pakhi".to_string());
+            assert_eq!(expected_ast, ast[0]);
+        },
+        Err(e) => panic!("err: {:?}", e),
+    }
+}
+
+#[test]
+fn parse_test_record_literal_missing_comma() {
+    let tokens = lexer::tokenize("দেখাও @{\"ক\" -> ১ \"খ\" -> ২};".chars().collect(),
+                                 "test.pakhi".to_string()).unwrap();
+    let parse_result = parse(String::from("test.pakhi"), tokens);
+    assert_eq!(Err(PakhiErr::SyntaxError(1, "test.pakhi".to_string(),
+                                         "Expected ',' or '}' after record value".to_string())), parse_result);
+}
+
+
+fn src_to_warnings(src_lines: Vec<&str>) -> Vec<PakhiWarning> {
+    let tokens = lexer::tokenize(src_lines.join("\n").chars().collect(),
+                                 "test.pakhi".to_string()).unwrap();
+    let ast = parse(String::from("test.pakhi"), tokens).unwrap();
+    warning::check(&ast)
+}
+
+#[test]
+fn warning_test_unreachable_after_return() {
+    let warnings = src_to_warnings(vec![
+        "ফাং দ্বিগুণ(ক) {",
+        "   ফেরত ক * ২;",
+        "   দেখাও ক;",
+        "} ফেরত;",
+        "দেখাও দ্বিগুণ(২);",
+    ]);
+    assert_eq!(vec![PakhiWarning {
+        line: 3,
+        file_path: "test.pakhi".to_string(),
+        message: "Statement after ফেরত is unreachable".to_string(),
+    }], warnings);
+}
+
+#[test]
+fn warning_test_unused_variable() {
+    let warnings = src_to_warnings(vec![
+        "নাম ক = ১;",
+        "নাম খ = ২;",
+        "নাম গ = [১];",
+        "গ[০] = খ;",
+    ]);
+    assert_eq!(vec![PakhiWarning {
+        line: 1,
+        file_path: "test.pakhi".to_string(),
+        message: "Variable ক declared but never used".to_string(),
+    }], warnings);
+}
+
+#[test]
+fn warning_test_no_warnings() {
+    let warnings = src_to_warnings(vec![
+        "নাম ক = ১;",
+        "ফাং পরীক্ষা(খ) {",
+        "   যদি খ > ক {",
+        "       ফেরত সত্য;",
+        "   }",
+        "   ফেরত মিথ্যা;",
+        "} ফেরত;",
+        "দেখাও পরীক্ষা(২);",
+    ]);
+    assert_eq!(Vec::<PakhiWarning>::new(), warnings);
+}
+
+#[test]
+fn parse_test_for_each() {
+    let tokens = lexer::tokenize("ফরইচ ক -> খ {\n} আবার;".chars().collect(),
+                                 "test.pakhi".to_string()).unwrap();
+    let ast = parse(String::from("test.pakhi"), tokens).unwrap();
+    let expected_ast = Stmt::ForEach(ForEach {
+        iterable: Expr::Primary(Primary::Var(Token {
+            kind: Identifier,
+            lexeme: "ক".chars().collect(),
+            line: 1,
+            src_file_path: "test.pakhi".to_string(),
+        }), 1, "test.pakhi".to_string()),
+        var_name: Token {
+            kind: Identifier,
+            lexeme: "খ".chars().collect(),
+            line: 1,
+            src_file_path: "test.pakhi".to_string(),
+        },
+    }, 1, "test.pakhi".to_string());
+    assert_eq!(expected_ast, ast[0]);
+    assert_eq!(Stmt::BlockStart(1, "test.pakhi".to_string()), ast[1]);
+    assert_eq!(Stmt::Continue(2, "test.pakhi".to_string()), ast[3]);
+
+    let tokens = lexer::tokenize("ফরইচ ক খ {\n} আবার;".chars().collect(),
+                                 "test.pakhi".to_string()).unwrap();
+    assert_eq!(Err(PakhiErr::SyntaxError(1, "test.pakhi".to_string(),
+        "Expected '->' after ফরইচ list or string".to_string())), parse(String::from("test.pakhi"), tokens));
+}
+
+#[test]
+fn parse_test_count_loop() {
+    let tokens = lexer::tokenize("গুনলুপ ই = ০..৩ {\n    দেখাও ই;\n} আবার;".chars().collect(),
+                                 "test.pakhi".to_string()).unwrap();
+    let ast = parse(String::from("test.pakhi"), tokens).unwrap();
+    // desugared to a লুপ which stops when hidden counter reaches end
+    let kinds: Vec<&str> = ast.iter().map(|stmt| match stmt {
+        Stmt::BlockStart(_, _) => "{",
+        Stmt::BlockEnd(_, _) => "}",
+        Stmt::Assignment(_, _, _) => "=",
+        Stmt::Loop(_, _) => "loop",
+        Stmt::If(_, _, _) => "if",
+        Stmt::Break(_, _) => "break",
+        Stmt::Continue(_, _) => "continue",
+        Stmt::Print(_, _, _) => "print",
+        Stmt::EOS(_, _) => "eos",
+        _ => "other",
+    }).collect();
+    assert_eq!(vec!["{", "=", "=", "loop", "{", "if", "{", "break", "}", "=", "=", "print", "}", "continue", "}", "eos"],
+               kinds);
+
+    let tokens = lexer::tokenize("গুনলুপ ই = ০ {\n} আবার;".chars().collect(),
+                                 "test.pakhi".to_string()).unwrap();
+    assert_eq!(Err(PakhiErr::SyntaxError(1, "test.pakhi".to_string(),
+        "Expected '..' between গুনলুপ start and end".to_string())), parse(String::from("test.pakhi"), tokens));
+}
+
+#[test]
+fn parse_test_while_loop() {
+    let tokens = lexer::tokenize("যতক্ষণ ক < ৩ {\n    দেখাও ক;\n} আবার;".chars().collect(),
+                                 "test.pakhi".to_string()).unwrap();
+    let ast = parse(String::from("test.pakhi"), tokens).unwrap();
+    // desugared to a লুপ which breaks when condition becomes false
+    let kinds: Vec<&str> = ast.iter().map(|stmt| match stmt {
+        Stmt::BlockStart(_, _) => "{",
+        Stmt::BlockEnd(_, _) => "}",
+        Stmt::Loop(_, _) => "loop",
+        Stmt::If(_, _, _) => "if",
+        Stmt::Break(_, _) => "break",
+        Stmt::Continue(_, _) => "continue",
+        Stmt::Print(_, _, _) => "print",
+        Stmt::EOS(_, _) => "eos",
+        _ => "other",
+    }).collect();
+    assert_eq!(vec!["loop", "{", "if", "{", "break", "}", "print", "}", "continue", "eos"], kinds);
+
+    let tokens = lexer::tokenize("যতক্ষণ ক < ৩\n} আবার;".chars().collect(),
+                                 "test.pakhi".to_string()).unwrap();
+    assert_eq!(Err(PakhiErr::SyntaxError(2, "test.pakhi".to_string(),
+        "Expected '{' after যতক্ষণ condition".to_string())), parse(String::from("test.pakhi"), tokens));
+}